@@ -0,0 +1,291 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input,
+    parenthesized,
+    spanned::Spanned,
+    punctuated::Punctuated,
+    Data,
+    DeriveInput,
+    Field,
+    Fields,
+    Ident,
+    LitStr,
+    Token,
+    Type,
+};
+
+/// Derive ```anthill_di::Constructor``` for struct
+///
+/// Every field resolved from context, field attribute change resolve way:
+/// * ```#[inject]``` (or no attribute) - resolve by field type, ```Option<T>``` field is ```None``` if service not registered
+/// * ```#[inject(named = "name")]``` - resolve named service by field type
+/// * ```#[inject(default)]``` - ```Default::default()```, nothing resolved
+/// * ```#[inject(ctx)]``` - store ```DependencyContext```
+/// * ```#[inject(construct)]``` - resolve by field type, not registered component registered on demand (```DependencyContext::resolve_or_construct```)
+///
+/// Async ctor generated with ```async-mode``` feature, otherwise sync ctor
+///# Example
+///---
+/// ```ignore
+/// #[derive(Constructor)]
+/// struct SomeComponent {
+///     #[inject]
+///     transient: Box<dyn SomeService>,
+///     optional: Option<Box<dyn SomePlugin>>,
+///     #[inject(named = "primary")]
+///     named: Arc<dyn SomeOtherService>,
+///     #[inject(default)]
+///     counter: u32,
+///     #[inject(ctx)]
+///     ctx: DependencyContext,
+///     #[inject(construct)]
+///     leaf: LeafComponent,
+/// }
+/// ```
+#[proc_macro_derive(Constructor, attributes(inject))]
+pub fn derive_constructor(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand_constructor(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+enum Inject {
+    Type,
+    Named(LitStr),
+    Default,
+    Ctx,
+    Construct,
+}
+
+fn expand_constructor(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => return Err(syn::Error::new(input.span(), "Constructor can be derived only for struct")),
+    };
+
+    let component = match fields {
+        Fields::Named(fields) => {
+            let fields = fields.named.iter()
+                .map(|field| {
+                    let ident = &field.ident;
+                    let value = field_value(field)?;
+                    Ok(quote! { #ident: #value })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            quote! { Self { #(#fields),* } }
+        },
+        Fields::Unnamed(fields) => {
+            let fields = fields.unnamed.iter()
+                .map(field_value)
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            quote! { Self ( #(#fields),* ) }
+        },
+        Fields::Unit => quote! { Self },
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    if cfg!(feature = "async-mode") {
+        Ok(quote! {
+            #[::anthill_di::async_trait_with_sync::async_trait(Sync)]
+            impl #impl_generics ::anthill_di::Constructor for #ident #ty_generics #where_clause {
+                #[allow(unused_variables)]
+                async fn ctor(ctx: ::anthill_di::DependencyContext) -> ::anthill_di::types::BuildDependencyResult<Self> {
+                    Ok(#component)
+                }
+            }
+        })
+    } else {
+        Ok(quote! {
+            impl #impl_generics ::anthill_di::Constructor for #ident #ty_generics #where_clause {
+                #[allow(unused_variables)]
+                fn ctor(ctx: ::anthill_di::DependencyContext) -> ::anthill_di::types::BuildDependencyResult<Self> {
+                    Ok(#component)
+                }
+            }
+        })
+    }
+}
+
+fn field_value(field: &Field) -> syn::Result<TokenStream2> {
+    let await_token = if cfg!(feature = "async-mode") { quote! { .await } } else { quote! {} };
+
+    Ok(match parse_inject(field)? {
+        Inject::Type if is_option(&field.ty) => quote! { ctx.try_resolve() #await_token ? },
+        Inject::Type => quote! { ctx.resolve() #await_token ? },
+        Inject::Named(name) => quote! { ctx.resolve_named(#name) #await_token ? },
+        Inject::Default => quote! { ::core::default::Default::default() },
+        Inject::Ctx => quote! { ctx.clone() },
+        Inject::Construct => quote! { ctx.resolve_or_construct() #await_token ? },
+    })
+}
+
+// Detect Option by last path segment, so Option, std::option::Option, core::option::Option are supported
+fn is_option(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+
+    type_path.qself.is_none() && type_path.path.segments.last()
+        .is_some_and(|segment| segment.ident == "Option" && matches!(segment.arguments, syn::PathArguments::AngleBracketed(_)))
+}
+
+fn parse_inject(field: &Field) -> syn::Result<Inject> {
+    let mut inject = Inject::Type;
+    let mut inject_found = false;
+
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("inject")) {
+        if inject_found {
+            return Err(syn::Error::new(attr.span(), "duplicated inject attribute"));
+        }
+
+        inject_found = true;
+
+        // #[inject] without args
+        if matches!(attr.meta, syn::Meta::Path(_)) {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("named") {
+                inject = Inject::Named(meta.value()?.parse()?);
+                return Ok(());
+            }
+
+            if meta.path.is_ident("default") {
+                inject = Inject::Default;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("ctx") {
+                inject = Inject::Ctx;
+                return Ok(());
+            }
+
+            if meta.path.is_ident("construct") {
+                inject = Inject::Construct;
+                return Ok(());
+            }
+
+            Err(meta.error("unsupported inject attribute, expected one of: named = \"...\", default, ctx, construct"))
+        })?;
+    }
+
+    Ok(inject)
+}
+
+/// Submit component registration, registered with ```DependencyContext::register_discovered```
+///
+/// Component must implement ```anthill_di::Constructor```
+/// * ```lifecycle``` - ```Transient``` (by default), ```Singleton``` or ```ContextDependent```
+/// * ```services``` - services to map component
+///# Example
+///---
+/// ```ignore
+/// #[component(lifecycle = Singleton, services(dyn Cache, dyn Health))]
+/// #[derive(Constructor)]
+/// struct SomeComponent {}
+/// ```
+#[proc_macro_attribute]
+pub fn component(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut component_args = ComponentArgs::default();
+    let args_parser = syn::meta::parser(|meta| component_args.parse(meta));
+    parse_macro_input!(args with args_parser);
+
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand_component(component_args, input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+#[derive(Default)]
+struct ComponentArgs {
+    life_cycle: Option<Ident>,
+    services: Vec<Type>,
+}
+
+impl ComponentArgs {
+    fn parse(&mut self, meta: syn::meta::ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("lifecycle") {
+            let life_cycle: Ident = meta.value()?.parse()?;
+
+            if !["Transient", "Singleton", "ContextDependent"].contains(&life_cycle.to_string().as_str()) {
+                return Err(syn::Error::new(life_cycle.span(), "unsupported lifecycle, expected one of: Transient, Singleton, ContextDependent"));
+            }
+
+            self.life_cycle = Some(life_cycle);
+            return Ok(());
+        }
+
+        if meta.path.is_ident("services") {
+            let content;
+            parenthesized!(content in meta.input);
+            self.services.extend(Punctuated::<Type, Token![,]>::parse_terminated(&content)?);
+            return Ok(());
+        }
+
+        Err(meta.error("unsupported component attribute, expected one of: lifecycle = ..., services(...)"))
+    }
+}
+
+fn expand_component(args: ComponentArgs, input: DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(input.generics.span(), "generic component can't be discovered"));
+    }
+
+    let ident = &input.ident;
+    let life_cycle = args.life_cycle.unwrap_or_else(|| Ident::new("Transient", ident.span()));
+    let services = &args.services;
+
+    let register = if cfg!(feature = "async-mode") {
+        quote! {
+            fn register(ctx: ::anthill_di::DependencyContext) -> ::core::pin::Pin<Box<dyn ::core::future::Future<Output = ::anthill_di::types::RegisterDiscoveredResult<()>> + Send>> {
+                Box::pin(async move {
+                    ctx.register_type::<#ident>(::anthill_di::LifeCycle::#life_cycle).await
+                        .map_err(|err| ::anthill_di::types::RegisterDiscoveredError::AddDependencyError { err })?
+                        #(
+                            .map_as::<#services>().await
+                            .map_err(|err| ::anthill_di::types::RegisterDiscoveredError::MapComponentError { err })?
+                        )*;
+
+                    Ok(())
+                })
+            }
+        }
+    } else {
+        quote! {
+            fn register(ctx: ::anthill_di::DependencyContext) -> ::anthill_di::types::RegisterDiscoveredResult<()> {
+                ctx.register_type::<#ident>(::anthill_di::LifeCycle::#life_cycle)
+                    .map_err(|err| ::anthill_di::types::RegisterDiscoveredError::AddDependencyError { err })?
+                    #(
+                        .map_as::<#services>()
+                        .map_err(|err| ::anthill_di::types::RegisterDiscoveredError::MapComponentError { err })?
+                    )*;
+
+                Ok(())
+            }
+        }
+    };
+
+    Ok(quote! {
+        #input
+
+        const _: () = {
+            #register
+
+            ::anthill_di::inventory::submit! {
+                ::anthill_di::DiscoveredComponent::new(register)
+            }
+        };
+    })
+}
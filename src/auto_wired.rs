@@ -0,0 +1,32 @@
+use std::sync::{Arc, Weak};
+
+use crate::{
+    Constructor,
+    LifeCycle,
+};
+
+/// Service, which component can be registered on demand by ```resolve_or_construct```
+///
+/// Component life cycle chosen by service type:
+/// * ```T``` - ```Transient```
+/// * ```Arc<T>``` - ```Singleton```
+/// * ```Weak<T>``` - ```ContextDependent```
+pub trait AutoWired where Self: Sync + Send + 'static {
+    type Component: Constructor + Sync + Send + 'static;
+    const LIFE_CYCLE: LifeCycle;
+}
+
+impl<T: Constructor + Sync + Send + 'static> AutoWired for T {
+    type Component = T;
+    const LIFE_CYCLE: LifeCycle = LifeCycle::Transient;
+}
+
+impl<T: Constructor + Sync + Send + 'static> AutoWired for Arc<T> {
+    type Component = T;
+    const LIFE_CYCLE: LifeCycle = LifeCycle::Singleton;
+}
+
+impl<T: Constructor + Sync + Send + 'static> AutoWired for Weak<T> {
+    type Component = T;
+    const LIFE_CYCLE: LifeCycle = LifeCycle::ContextDependent;
+}
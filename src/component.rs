@@ -1,6 +1,7 @@
 use std::{
     fmt::Debug,
-    any::Any
+    any::Any,
+    sync::Arc,
 };
 
 use crate::{
@@ -37,6 +38,17 @@ impl Component {
 #[cfg(not(feature = "async-mode"))]
 pub trait ITypeConstructor where Self: Sync + Send + Debug {
     fn ctor(&self, ctx: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+
+    /// Args type, required by ctor. None if component created without args
+    fn args_type_info(&self) -> Option<TypeInfo> { None }
+
+    /// Registered shared instance, singleton builder cache it instead of ctor call
+    fn shared_instance(&self) -> Option<Arc<dyn Any + Sync + Send>> { None }
+
+    /// Called only if args type checked with ```args_type_info```
+    fn ctor_with_args(&self, _ctx: DependencyContext, _args: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        unreachable!("ctor with args called for component without args")
+    }
 }
 
 #[cfg(feature = "async-mode")]
@@ -45,4 +57,19 @@ pub trait ITypeConstructor where Self: Sync + Send + Debug {
     async fn ctor(&self, ctx: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
     #[cfg(feature = "blocking")]
     fn blocking_ctor(&self, ctx: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+
+    /// Args type, required by ctor. None if component created without args
+    fn args_type_info(&self) -> Option<TypeInfo> { None }
+
+    /// Registered shared instance, singleton builder cache it instead of ctor call
+    fn shared_instance(&self) -> Option<Arc<dyn Any + Sync + Send>> { None }
+
+    /// Called only if args type checked with ```args_type_info```
+    async fn ctor_with_args(&self, _ctx: DependencyContext, _args: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        unreachable!("ctor with args called for component without args")
+    }
+    #[cfg(feature = "blocking")]
+    fn blocking_ctor_with_args(&self, _ctx: DependencyContext, _args: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        unreachable!("ctor with args called for component without args")
+    }
 }
\ No newline at end of file
@@ -44,4 +44,45 @@ impl <T: Constructor + Sized + 'static> Constructor for std::sync::RwLock<T> {
     async fn ctor(ctx: DependencyContext) -> BuildDependencyResult<Self> {
         Ok(std::sync::RwLock::new(T::ctor(ctx).await?))
     }
-}
\ No newline at end of file
+}
+
+/// Component constructor with args, passed on resolve
+///# Example
+///---
+/// ```ignore
+/// struct SomeComponent { tenant_id: u64 }
+///
+/// #[async_trait_with_sync::async_trait(Sync)]
+/// impl ConstructorWith<u64> for SomeComponent {
+///     async fn ctor(_ctx: DependencyContext, tenant_id: u64) -> BuildDependencyResult<Self> {
+///         Ok(Self { tenant_id })
+///     }
+/// }
+///
+/// let component = root_context.resolve_with::<SomeComponent, u64>(1).await.unwrap();
+/// ```
+#[cfg(not(feature = "async-mode"))]
+pub trait ConstructorWith<TArgs: Sync + Send + 'static> where Self: Sized + 'static {
+    fn ctor(ctx: DependencyContext, args: TArgs) -> BuildDependencyResult<Self>;
+}
+
+/// Component constructor with args, passed on resolve
+///# Example
+///---
+/// ```ignore
+/// struct SomeComponent { tenant_id: u64 }
+///
+/// #[async_trait_with_sync::async_trait(Sync)]
+/// impl ConstructorWith<u64> for SomeComponent {
+///     async fn ctor(_ctx: DependencyContext, tenant_id: u64) -> BuildDependencyResult<Self> {
+///         Ok(Self { tenant_id })
+///     }
+/// }
+///
+/// let component = root_context.resolve_with::<SomeComponent, u64>(1).await.unwrap();
+/// ```
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+pub trait ConstructorWith<TArgs: Sync + Send + 'static> where Self: Sized + 'static {
+    async fn ctor(ctx: DependencyContext, args: TArgs) -> BuildDependencyResult<Self>;
+}
@@ -0,0 +1,46 @@
+use std::{
+    any::Any,
+    sync::Arc,
+};
+
+use crate::{
+    DependencyContext,
+    ITypeConstructor,
+    types::BuildDependencyResult,
+};
+
+// Every ctor call return clone of registered Arc, so instance can be requested any times
+pub (crate) struct ComponentFromArc<TService: ?Sized + Sync + Send + 'static> {
+    instance: Arc<TService>,
+}
+
+impl<TService: ?Sized + Sync + Send + 'static> std::fmt::Debug for ComponentFromArc<TService> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComponentFromArc")
+            .finish()
+    }
+}
+
+impl<TService: ?Sized + Sync + Send + 'static> ComponentFromArc<TService> {
+    pub (crate) fn new(instance: Arc<TService>) -> Self { Self { instance } }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl<TService: ?Sized + Sync + Send + 'static> ITypeConstructor for ComponentFromArc<TService> {
+    fn ctor(&self, _: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        Ok(Box::new(self.instance.clone()))
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl<TService: ?Sized + Sync + Send + 'static> ITypeConstructor for ComponentFromArc<TService> {
+    async fn ctor(&self, _: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        Ok(Box::new(self.instance.clone()))
+    }
+
+    #[cfg(feature = "blocking")]
+    fn blocking_ctor(&self, _: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        Ok(Box::new(self.instance.clone()))
+    }
+}
@@ -0,0 +1,59 @@
+use std::{
+    any::Any,
+    fmt::Debug,
+    marker::PhantomData,
+};
+
+use crate::{
+    types::BuildDependencyResult,
+    DependencyContext,
+    InjectAsyncFn,
+    component::ITypeConstructor
+};
+
+use derive_new::new;
+#[cfg(feature = "blocking")]
+use tokio::runtime::Builder;
+
+#[derive(new)]
+pub (crate) struct ComponentFromAsyncFn<TComponent, TArgs, TFunction>
+where
+    TComponent: Sync + Send + 'static,
+    TArgs: 'static,
+    TFunction: InjectAsyncFn<TArgs, TComponent> + 'static,
+{
+    function: TFunction,
+    #[new(default)]
+    pd: PhantomData<fn() -> (TComponent, TArgs)>,
+}
+
+impl<TComponent, TArgs, TFunction> Debug for ComponentFromAsyncFn<TComponent, TArgs, TFunction>
+where
+    TComponent: Sync + Send + 'static,
+    TArgs: 'static,
+    TFunction: InjectAsyncFn<TArgs, TComponent> + 'static,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComponentFromAsyncFn")
+            .finish()
+    }
+}
+
+#[async_trait_with_sync::async_trait(Sync)]
+impl<TComponent, TArgs, TFunction> ITypeConstructor for ComponentFromAsyncFn<TComponent, TArgs, TFunction>
+where
+    TComponent: Sync + Send + 'static,
+    TArgs: 'static,
+    TFunction: InjectAsyncFn<TArgs, TComponent> + 'static,
+{
+    async fn ctor(&self, ctx: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        Ok(Box::new(self.function.call(&ctx).await?))
+    }
+
+    #[cfg(feature = "blocking")]
+    fn blocking_ctor(&self, ctx: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let rt = Builder::new_current_thread().enable_all().build().unwrap();
+        let build_result = rt.block_on(async move { self.function.call(&ctx).await })?;
+        Ok(Box::new(build_result))
+    }
+}
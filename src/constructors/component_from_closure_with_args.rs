@@ -0,0 +1,89 @@
+use std::{
+    fmt::Debug,
+    any::Any,
+    marker::PhantomData,
+};
+
+use derive_new::new;
+
+use crate::{
+    types::{
+        BuildDependencyError,
+        BuildDependencyResult,
+        TypeInfo,
+    },
+    DependencyContext,
+    ITypeConstructor
+};
+
+trait ClosureWithArgs<TComponent: Sync + Send + 'static, TArgs: Sync + Send + 'static> = Fn(DependencyContext, TArgs) -> BuildDependencyResult<TComponent> + Sync + Send;
+
+#[derive(new)]
+pub (crate) struct ComponentFromClosureWithArgs<TComponent: Sync + Send + 'static, TArgs: Sync + Send + 'static> {
+    closure: Box<dyn ClosureWithArgs<TComponent, TArgs>>,
+    #[new(default)]
+    args_phantom_data: PhantomData<TArgs>,
+}
+
+impl<TComponent: Sync + Send + 'static, TArgs: Sync + Send + 'static> Debug for ComponentFromClosureWithArgs<TComponent, TArgs> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComponentFromClosureWithArgs")
+            .field("args_type_info", &TypeInfo::from_type::<TArgs>())
+            .finish()
+    }
+}
+
+impl<TComponent: Sync + Send + 'static, TArgs: Sync + Send + 'static> ComponentFromClosureWithArgs<TComponent, TArgs> {
+    fn args_required_error() -> BuildDependencyError {
+        BuildDependencyError::ArgsTypeMismatch {
+            component_type_info: TypeInfo::from_type::<TComponent>(),
+            expected_args_type_info: Some(TypeInfo::from_type::<TArgs>()),
+            found_args_type_info: None,
+        }
+    }
+
+    fn downcast_args(args: Box<dyn Any + Sync + Send>) -> TArgs {
+        Box::into_inner(args.downcast::<TArgs>()
+            .unwrap_or_else(|_| panic!("Args type checked before ctor call, expected args type_info:[{:?}]", TypeInfo::from_type::<TArgs>())))
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl<TComponent: Sync + Send + 'static, TArgs: Sync + Send + 'static> ITypeConstructor for ComponentFromClosureWithArgs<TComponent, TArgs> {
+    fn ctor(&self, _ctx: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        Err(Self::args_required_error())
+    }
+
+    fn args_type_info(&self) -> Option<TypeInfo> { Some(TypeInfo::from_type::<TArgs>()) }
+
+    fn ctor_with_args(&self, ctx: DependencyContext, args: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let build_result = (self.closure)(ctx, Self::downcast_args(args))?;
+        Ok(Box::new(build_result))
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl<TComponent: Sync + Send + 'static, TArgs: Sync + Send + 'static> ITypeConstructor for ComponentFromClosureWithArgs<TComponent, TArgs> {
+    async fn ctor(&self, _ctx: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        Err(Self::args_required_error())
+    }
+
+    #[cfg(feature = "blocking")]
+    fn blocking_ctor(&self, _ctx: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        Err(Self::args_required_error())
+    }
+
+    fn args_type_info(&self) -> Option<TypeInfo> { Some(TypeInfo::from_type::<TArgs>()) }
+
+    async fn ctor_with_args(&self, ctx: DependencyContext, args: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let build_result = (self.closure)(ctx, Self::downcast_args(args))?;
+        Ok(Box::new(build_result))
+    }
+
+    #[cfg(feature = "blocking")]
+    fn blocking_ctor_with_args(&self, ctx: DependencyContext, args: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let build_result = (self.closure)(ctx, Self::downcast_args(args))?;
+        Ok(Box::new(build_result))
+    }
+}
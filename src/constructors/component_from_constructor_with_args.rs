@@ -0,0 +1,91 @@
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    any::Any
+};
+
+use crate::{
+    DependencyContext,
+    ITypeConstructor,
+    ConstructorWith,
+    types::{
+        BuildDependencyError,
+        BuildDependencyResult,
+        TypeInfo,
+    },
+};
+
+use derive_new::new;
+#[cfg(feature = "blocking")]
+use tokio::runtime::Builder;
+
+#[derive(new)]
+pub (crate) struct ComponentFromConstructorWithArgs<TComponent: ConstructorWith<TArgs> + Sync + Send, TArgs: Sync + Send + 'static> {
+    component_phantom_data: PhantomData<TComponent>,
+    args_phantom_data: PhantomData<TArgs>,
+}
+
+impl<TComponent: ConstructorWith<TArgs> + Sync + Send, TArgs: Sync + Send + 'static> Debug for ComponentFromConstructorWithArgs<TComponent, TArgs> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComponentFromConstructorWithArgs")
+            .field("args_type_info", &TypeInfo::from_type::<TArgs>())
+            .finish()
+    }
+}
+
+impl<TComponent: ConstructorWith<TArgs> + Sync + Send, TArgs: Sync + Send + 'static> ComponentFromConstructorWithArgs<TComponent, TArgs> {
+    fn args_required_error() -> BuildDependencyError {
+        BuildDependencyError::ArgsTypeMismatch {
+            component_type_info: TypeInfo::from_type::<TComponent>(),
+            expected_args_type_info: Some(TypeInfo::from_type::<TArgs>()),
+            found_args_type_info: None,
+        }
+    }
+
+    fn downcast_args(args: Box<dyn Any + Sync + Send>) -> TArgs {
+        Box::into_inner(args.downcast::<TArgs>()
+            .unwrap_or_else(|_| panic!("Args type checked before ctor call, expected args type_info:[{:?}]", TypeInfo::from_type::<TArgs>())))
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl<TComponent: ConstructorWith<TArgs> + Sync + Send, TArgs: Sync + Send + 'static> ITypeConstructor for ComponentFromConstructorWithArgs<TComponent, TArgs> {
+    fn ctor(&self, _ctx: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        Err(Self::args_required_error())
+    }
+
+    fn args_type_info(&self) -> Option<TypeInfo> { Some(TypeInfo::from_type::<TArgs>()) }
+
+    fn ctor_with_args(&self, ctx: DependencyContext, args: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let new_component = TComponent::ctor(ctx, Self::downcast_args(args))?;
+        Ok(Box::new(new_component))
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl<TComponent: ConstructorWith<TArgs> + Sync + Send, TArgs: Sync + Send + 'static> ITypeConstructor for ComponentFromConstructorWithArgs<TComponent, TArgs> {
+    async fn ctor(&self, _ctx: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        Err(Self::args_required_error())
+    }
+
+    #[cfg(feature = "blocking")]
+    fn blocking_ctor(&self, _ctx: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        Err(Self::args_required_error())
+    }
+
+    fn args_type_info(&self) -> Option<TypeInfo> { Some(TypeInfo::from_type::<TArgs>()) }
+
+    async fn ctor_with_args(&self, ctx: DependencyContext, args: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let new_component = TComponent::ctor(ctx, Self::downcast_args(args)).await?;
+        Ok(Box::new(new_component))
+    }
+
+    #[cfg(feature = "blocking")]
+    fn blocking_ctor_with_args(&self, ctx: DependencyContext, args: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let args = Self::downcast_args(args);
+        let rt = Builder::new_current_thread().enable_all().build().unwrap();
+        let new_component = rt.block_on(async move { TComponent::ctor(ctx, args).await })?;
+        Ok(Box::new(new_component))
+    }
+}
@@ -0,0 +1,43 @@
+use std::any::Any;
+
+use crate::{
+    DependencyContext,
+    ITypeConstructor,
+    types::BuildDependencyResult,
+};
+
+// Every ctor call return clone of template
+pub (crate) struct ComponentFromPrototype<TComponent: Clone + Sync + Send + 'static> {
+    template: TComponent,
+}
+
+impl<TComponent: Clone + Sync + Send + 'static> std::fmt::Debug for ComponentFromPrototype<TComponent> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComponentFromPrototype")
+            .finish()
+    }
+}
+
+impl<TComponent: Clone + Sync + Send + 'static> ComponentFromPrototype<TComponent> {
+    pub (crate) fn new(template: TComponent) -> Self { Self { template } }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl<TComponent: Clone + Sync + Send + 'static> ITypeConstructor for ComponentFromPrototype<TComponent> {
+    fn ctor(&self, _: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        Ok(Box::new(self.template.clone()))
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl<TComponent: Clone + Sync + Send + 'static> ITypeConstructor for ComponentFromPrototype<TComponent> {
+    async fn ctor(&self, _: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        Ok(Box::new(self.template.clone()))
+    }
+
+    #[cfg(feature = "blocking")]
+    fn blocking_ctor(&self, _: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        Ok(Box::new(self.template.clone()))
+    }
+}
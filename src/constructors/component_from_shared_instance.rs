@@ -0,0 +1,54 @@
+use std::{
+    any::Any,
+    sync::Arc,
+};
+
+use crate::{
+    DependencyContext,
+    ITypeConstructor,
+    types::BuildDependencyResult,
+};
+
+// Shared instance cached by singleton builder on first resolve, ctor return clone of registered Arc
+pub (crate) struct ComponentFromSharedInstance<TComponent: Sync + Send + 'static> {
+    instance: Arc<TComponent>,
+}
+
+impl<TComponent: Sync + Send + 'static> std::fmt::Debug for ComponentFromSharedInstance<TComponent> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComponentFromSharedInstance")
+            .finish()
+    }
+}
+
+impl<TComponent: Sync + Send + 'static> ComponentFromSharedInstance<TComponent> {
+    pub (crate) fn new(instance: Arc<TComponent>) -> Self { Self { instance } }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl<TComponent: Sync + Send + 'static> ITypeConstructor for ComponentFromSharedInstance<TComponent> {
+    fn ctor(&self, _: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        Ok(Box::new(self.instance.clone()))
+    }
+
+    fn shared_instance(&self) -> Option<Arc<dyn Any + Sync + Send>> {
+        Some(self.instance.clone())
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl<TComponent: Sync + Send + 'static> ITypeConstructor for ComponentFromSharedInstance<TComponent> {
+    async fn ctor(&self, _: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        Ok(Box::new(self.instance.clone()))
+    }
+
+    #[cfg(feature = "blocking")]
+    fn blocking_ctor(&self, _: DependencyContext) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        Ok(Box::new(self.instance.clone()))
+    }
+
+    fn shared_instance(&self) -> Option<Arc<dyn Any + Sync + Send>> {
+        Some(self.instance.clone())
+    }
+}
@@ -10,4 +10,27 @@ mod component_from_closure;
 pub (crate) use component_from_closure::*;
 
 mod component_from_instance;
-pub (crate) use component_from_instance::*;
\ No newline at end of file
+pub (crate) use component_from_instance::*;
+
+mod component_from_arc;
+pub (crate) use component_from_arc::*;
+
+mod component_from_shared_instance;
+pub (crate) use component_from_shared_instance::*;
+
+mod component_from_prototype;
+pub (crate) use component_from_prototype::*;
+
+mod component_from_closure_with_args;
+pub (crate) use component_from_closure_with_args::*;
+
+mod component_from_constructor_with_args;
+pub (crate) use component_from_constructor_with_args::*;
+
+mod component_from_fn;
+pub (crate) use component_from_fn::*;
+
+#[cfg(feature = "async-mode")]
+mod component_from_async_fn;
+#[cfg(feature = "async-mode")]
+pub (crate) use component_from_async_fn::*;
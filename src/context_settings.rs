@@ -0,0 +1,11 @@
+use crate::LifeCycle;
+
+/// Root context settings, shared with all child contexts
+#[derive(Debug, Clone, Default)]
+pub struct ContextSettings {
+    /// Return ```BuildDependencyError::Ambiguous``` from ```resolve```, if service has several components and component can't be chosen by default mapping
+    pub strict_resolve: bool,
+    /// Only life cycle of components, registered on demand by ```resolve_or_construct```. If not set, any life cycle allowed    
+    /// Life cycle chosen by service type: ```T``` - Transient, ```Arc<T>``` - Singleton, ```Weak<T>``` - ContextDependent, other service types return ```AddDependencyError::LifeCycleMismatch```
+    pub auto_wired_life_cycle: Option<LifeCycle>,
+}
@@ -0,0 +1,123 @@
+use std::{
+    any::TypeId,
+    collections::HashMap,
+    marker::{PhantomData, Unsize},
+    sync::{Arc, Weak},
+};
+
+use crate::{
+    core_context::CoreContext,
+    types::TypeInfo,
+};
+
+// Component, which satisfy service for parent component
+#[derive(Debug, Clone)]
+pub (crate) struct ContextualBinding {
+    pub (crate) parent_type_info: TypeInfo,
+    pub (crate) service_type_info: TypeInfo,
+    // Used in debug output
+    #[allow(dead_code)]
+    pub (crate) component_type_info: TypeInfo,
+    pub (crate) cycled_component_id: TypeId,
+}
+
+impl ContextualBinding {
+    fn new<TParent: 'static, TService: ?Sized + 'static, TComponent: 'static, TCycledComponent: 'static>() -> Self {
+        Self {
+            parent_type_info: TypeInfo::from_type::<TParent>(),
+            service_type_info: TypeInfo::from_type::<TService>(),
+            component_type_info: TypeInfo::from_type::<TComponent>(),
+            cycled_component_id: TypeId::of::<TCycledComponent>(),
+        }
+    }
+
+    // Service resolved as Box/Arc/Weak depending on component life cycle, so bind all of them
+    fn for_all_life_cycles<TParent: 'static, TService: ?Sized + 'static, TComponent: 'static>() -> [Self; 3] {
+        [
+            Self::new::<TParent, Box<TService>, TComponent, TComponent>(),
+            Self::new::<TParent, Arc<TService>, TComponent, Arc<TComponent>>(),
+            Self::new::<TParent, Weak<TService>, TComponent, Weak<TComponent>>(),
+        ]
+    }
+}
+
+// Parent component type and resolved service type to binding
+pub (crate) type ContextualBindings = HashMap<(TypeId, TypeId), ContextualBinding>;
+
+pub struct ContextualBindingBuilder<TParent: Sync + Send + 'static> {
+    core_context: Arc<CoreContext>,
+    pd: PhantomData<TParent>,
+}
+
+/// Choose component, which satisfy service, when it injected into parent component
+impl<TParent: Sync + Send + 'static> ContextualBindingBuilder<TParent> {
+    pub (crate) fn new(core_context: Arc<CoreContext>) -> Self {
+        Self {
+            core_context,
+            pd: PhantomData,
+        }
+    }
+
+    /// Resolve service from parent ctor as component mapping
+    ///
+    /// Component must be mapped as service, binding only choose it from service mappings
+    ///
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.when_injected_into::<BillingService>()
+    ///     .map::<dyn Logger, AuditLogger>().await;
+    /// ```
+    #[cfg(feature = "async-mode")]
+    pub async fn map<TService: ?Sized + Sync + Send + 'static, TComponent: Sync + Send + 'static + Unsize<TService>>(self) -> Self {
+        let mut contextual_bindings_write_guard = self.core_context.contextual_bindings.write().await;
+        add_bindings::<TParent, TService, TComponent>(&mut contextual_bindings_write_guard);
+        drop(contextual_bindings_write_guard);
+
+        self
+    }
+
+    /// Resolve service from parent ctor as component mapping (blocking version)
+    ///
+    /// Component must be mapped as service, binding only choose it from service mappings
+    ///
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.when_injected_into::<BillingService>()
+    ///     .blocking_map::<dyn Logger, AuditLogger>();
+    /// ```
+    #[cfg(feature = "blocking")]
+    pub fn blocking_map<TService: ?Sized + Sync + Send + 'static, TComponent: Sync + Send + 'static + Unsize<TService>>(self) -> Self {
+        std::thread::spawn(move || {
+            add_bindings::<TParent, TService, TComponent>(&mut self.core_context.contextual_bindings.blocking_write());
+            self
+        }).join().unwrap()
+    }
+
+    /// Resolve service from parent ctor as component mapping
+    ///
+    /// Component must be mapped as service, binding only choose it from service mappings
+    ///
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.when_injected_into::<BillingService>()
+    ///     .map::<dyn Logger, AuditLogger>();
+    /// ```
+    #[cfg(not(feature = "async-mode"))]
+    pub fn map<TService: ?Sized + Sync + Send + 'static, TComponent: Sync + Send + 'static + Unsize<TService>>(self) -> Self {
+        add_bindings::<TParent, TService, TComponent>(&mut self.core_context.contextual_bindings.write().unwrap());
+        self
+    }
+}
+
+// Repeated binding for same parent and service replace previous
+fn add_bindings<TParent: 'static, TService: ?Sized + 'static, TComponent: 'static>(contextual_bindings: &mut ContextualBindings) {
+    for binding in ContextualBinding::for_all_life_cycles::<TParent, TService, TComponent>() {
+        contextual_bindings.insert((binding.parent_type_info.type_id, binding.service_type_info.type_id), binding);
+    }
+}
@@ -1,8 +1,8 @@
 use std::{
     collections::{HashMap, VecDeque},
-    any::{TypeId, type_name},
+    any::{Any, TypeId, type_name},
     sync::{Arc, Weak},
-    fmt::Debug, marker::Unsize
+    fmt::Debug, marker::Unsize, ops::CoerceUnsized
 };
 
 #[cfg(feature = "loop-check")]
@@ -12,8 +12,16 @@ use crate::{
     Component,
     service::{
         CycledComponentServiceCollection,
+        CycledComponentServicePair,
+        ServiceMappingOptions,
+        IServiceAlias,
+        ServiceAlias,
+        downcast_alias_result,
+    },
+    cycled_components::{
+        ComponentCycledComponentCollection,
+        ComponentCycledComponentPair,
     },
-    cycled_components::ComponentCycledComponentCollection,
     GlobalContext,
     LifeCycle,
     types::{
@@ -26,12 +34,28 @@ use crate::{
         MapComponentResult,
         DeleteComponentResult,
         DeleteComponentError,
+        InstallModuleResult,
+        InstallModuleError,
+        UninstallModuleResult,
+        UninstallModuleError,
         AnthillRwLock,
     },
     ServiceMappingBuilder,
+    DependencyContext,
     DependencyContextId,
     LocalContext,
-    component::ITypeConstructor
+    ContextSettings,
+    InstalledModule,
+    DependencyModule,
+    IServiceDecorator,
+    ResolveInfo,
+    ResolveInterceptor,
+    ServiceInfo,
+    ServiceMetadata,
+    ContextualBindings,
+    ResolutionSource,
+    SourceResolution,
+    component::ITypeConstructor,
 };
 
 #[derive(Default)]
@@ -43,10 +67,29 @@ pub struct CoreContext where Self: Sync + Send {
     pub (crate) global_context: Arc<AnthillRwLock<GlobalContext>>,
     #[cfg(feature = "loop-check")]
     pub (crate) links: AnthillRwLock<HashMap<TypeId, DependencyLink>>,
+
+    pub (crate) modules: AnthillRwLock<HashMap<TypeId, InstalledModule>>,
+
+    // Service decorators in registration order
+    pub (crate) decorators: AnthillRwLock<HashMap<TypeId, Vec<Arc<dyn IServiceDecorator>>>>,
+
+    // Component ctor interceptors in registration order
+    pub (crate) interceptors: AnthillRwLock<Vec<Arc<dyn ResolveInterceptor>>>,
+
+    // Alias service type to alias, which resolve target service
+    pub (crate) aliases: AnthillRwLock<HashMap<TypeId, Arc<dyn IServiceAlias>>>,
+
+    // Components, which satisfy service for parent component
+    pub (crate) contextual_bindings: AnthillRwLock<ContextualBindings>,
+
+    // Fallback for not registered services in adding order
+    pub (crate) resolution_sources: AnthillRwLock<Vec<Arc<dyn ResolutionSource>>>,
+
+    pub (crate) settings: ContextSettings,
 }
 
 impl CoreContext {
-    pub fn new() -> Self { 
+    pub fn new(settings: ContextSettings) -> Self { 
         Self {
             components: Default::default(),
             component_cycled_components_collection: Default::default(),
@@ -54,6 +97,13 @@ impl CoreContext {
             global_context: Default::default(),
             #[cfg(feature = "loop-check")]
             links: Default::default(),
+            modules: Default::default(),
+            decorators: Default::default(),
+            interceptors: Default::default(),
+            aliases: Default::default(),
+            contextual_bindings: Default::default(),
+            resolution_sources: Default::default(),
+            settings,
         }
     }
 }
@@ -69,6 +119,13 @@ impl Debug for CoreContext {
         debug_struct.field("links", &self.links.try_read().unwrap());
 
         debug_struct.field("global_context", &self.global_context.try_read().unwrap())
+            .field("modules", &self.modules.try_read().unwrap())
+            .field("decorators", &self.decorators.try_read().unwrap())
+            .field("interceptors_count", &self.interceptors.try_read().unwrap().len())
+            .field("aliases", &self.aliases.try_read().unwrap())
+            .field("contextual_bindings", &self.contextual_bindings.try_read().unwrap().values().collect::<Vec<_>>())
+            .field("resolution_sources_count", &self.resolution_sources.try_read().unwrap().len())
+            .field("settings", &self.settings)
             .finish()
     }
 }
@@ -83,7 +140,9 @@ impl CoreContext {
         // Проверяем наличие зависимости, если нет добавляем
         let mut components_guard = self.components.write().await;
         
-        if components_guard.contains_key(&component.component_type_info.type_id) {
+        // Other component can own same cycled component, like transient Arc<T> and singleton T
+        if components_guard.contains_key(&component.component_type_info.type_id)
+            || self.component_cycled_components_collection.read().await.get_by_cycled_component_id(&cycled_component_type_id::<TComponent>(&life_cycle)).is_some() {
             return Err(AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<TComponent>() });
         }
    
@@ -98,8 +157,8 @@ impl CoreContext {
         // Пустой маппинг сомпонента
         match life_cycle {
             LifeCycle::Transient => self.cycled_component_service_collection.write().await.add_mapping_as_self::<TComponent>(),
-            LifeCycle::Singleton => self.cycled_component_service_collection.write().await.add_mapping_as_self::<Arc<TComponent>>(),
-            LifeCycle::ContextDependent => self.cycled_component_service_collection.write().await.add_mapping_as_self::<Weak<TComponent>>(),
+            LifeCycle::Singleton => self.cycled_component_service_collection.write().await.add_mapping_as_shared_self::<Arc<TComponent>>(),
+            LifeCycle::ContextDependent => self.cycled_component_service_collection.write().await.add_mapping_as_shared_self::<Weak<TComponent>>(),
         }
         //---------------------------
 
@@ -114,466 +173,1868 @@ impl CoreContext {
         Ok(ServiceMappingBuilder::new(self.clone()))
     }
 
-    pub (crate) async fn resolve<'a, TService: Sync + Send + 'static>(self: &Arc<Self>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService>{//std::pin::Pin<Box<dyn std::future::Future<Output = BuildDependencyResult<TService>> + Send + Sync + 'a>> {
-        let service_id = TypeId::of::<TService>();
+    // Replace component ctor, or register component if not exist. Return builder and flag, component replaced
+    // Get cycled_component_service_collection write guard at first, like in delete, to block resolve while component replaced
+    pub (crate) async fn register_or_replace<TComponent: Sync + Send + 'static>(self: &Arc<Self>, ctor: Box<dyn ITypeConstructor>, life_cycle: LifeCycle) -> AddDependencyResult<(ServiceMappingBuilder<TComponent>, bool)> {
+        // Replaced component is not owned by installed module anymore, modules guard taken at first like in delete_module
+        let mut modules_write_guard = self.modules.write().await;
+        let mut cycled_component_service_collection_write_guard = self.cycled_component_service_collection.write().await;
+        let mut component_cycled_components_collection_write_guard = self.component_cycled_components_collection.write().await;
 
-        let component_service_pair = self.cycled_component_service_collection.read().await.get_nth_by_service_type::<TService>(0)
-            .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
-    
-        let component_cycled_component_pair = self.component_cycled_components_collection.read().await
-            .get_by_cycled_component_id(&component_service_pair.cycled_component_id)
-            .expect(&format!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"));
-        
-        #[cfg(feature = "loop-check")]
-        if let DependencyContextId::TypeId(type_info) = &id {
-            // Link created on dependency add, we need take link for dependency, not cycled dependency or service
-            check_link(self.clone(), &component_cycled_component_pair.component_type_info, type_info).await?;
+        let component_id = TypeId::of::<TComponent>();
+
+        // Other component can own same cycled component, like transient Arc<T> and singleton T
+        if component_cycled_components_collection_write_guard.get_by_cycled_component_id(&cycled_component_type_id::<TComponent>(&life_cycle))
+            .is_some_and(|component_cycled_component_pair| component_cycled_component_pair.component_type_info.type_id != component_id) {
+            return Err(AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<TComponent>() });
         }
-    
-        let cycled_component = component_cycled_component_pair.converter.build(self.clone(), local_context).await?;
-        let service: Box<TService> = component_service_pair.converter.build(cycled_component)
-            .downcast::<TService>()
-            .expect(&format!("Invalid service cast expected service_id:[{service_id:?}] service_name:[{service_name}], found [{component_cycled_component_pair:?}]", service_name = type_name::<TService>().to_string()));
 
-        Ok(Box::into_inner(service))
-    }
+        let component = Component::new::<TComponent>(life_cycle, ctor);
 
-    pub (crate) async fn resolve_by_type_id<TService: Sync + Send + 'static>(self: &Arc<Self>, component_type_id: TypeId, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
-        let service_id = TypeId::of::<TService>();
+        let old_component = self.components.write().await.insert(component_id, Arc::new(component));
 
-        let component_service_pair = self.cycled_component_service_collection.read().await.get_all_by_service_type_with_cycled_component_id::<TService>(component_type_id)
-            .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
+        let Some(old_component) = old_component else {
+            #[cfg(feature = "loop-check")]
+            self.links.write().await.insert(component_id, DependencyLink::new());
 
-        let component_cycled_component_pair = self.component_cycled_components_collection.read().await
-            .get_by_cycled_component_id(&component_service_pair.cycled_component_id)
-            .expect(&format!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"));
+            add_life_cycle::<TComponent>(&mut cycled_component_service_collection_write_guard, &mut component_cycled_components_collection_write_guard, &life_cycle);
+
+            return Ok((ServiceMappingBuilder::new(self.clone()), false));
+        };
+
+        for installed_module in modules_write_guard.values_mut() {
+            installed_module.components.retain(|component_type_info| component_type_info.type_id != component_id);
+        }
 
+        // New ctor can have other dependencies
         #[cfg(feature = "loop-check")]
-        if let DependencyContextId::TypeId(type_info) = &id {
-            // Link created on dependency add, we need take link for dependency, not cycled dependency or service
-            check_link(self.clone(), &component_cycled_component_pair.component_type_info, type_info).await?;
+        reset_child_links(&mut *self.links.write().await, &component_id);
+
+        if old_component.life_cycle_type == LifeCycle::Singleton {
+            self.global_context.write().await.remove_singleton(&TypeId::of::<Arc<TComponent>>());
         }
 
-        let cycled_component = component_cycled_component_pair.converter.build(self.clone(), local_context).await?;
-        let service: Box<TService> = component_service_pair.converter.build(cycled_component)
-            .downcast::<TService>()
-            .expect(&format!("Invalid service cast expected service_id:[{service_id:?}] service_name:[{service_name}], found [{component_cycled_component_pair:?}]", service_name = type_name::<TService>().to_string()));
+        // Service mappings depend on life cycle, so mappings can be kept only with same life cycle
+        if old_component.life_cycle_type != life_cycle {
+            let cycled_component_builder = component_cycled_components_collection_write_guard.delete_by_component::<TComponent>()
+                .unwrap_or_else(|| panic!("Component exist but component cycled component pair not found:[{:?}]", TypeInfo::from_type::<TComponent>()));
+
+            _ = cycled_component_service_collection_write_guard.delete_cycled_component(&cycled_component_builder.cycled_component_type_info.type_id);
+
+            add_life_cycle::<TComponent>(&mut cycled_component_service_collection_write_guard, &mut component_cycled_components_collection_write_guard, &life_cycle);
+        }
 
-        return Ok(Box::into_inner(service));
+        Ok((ServiceMappingBuilder::new(self.clone()), true))
     }
 
-    pub (crate) async fn resolve_collection<TService: Sync + Send + 'static>(self: &Arc<Self>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Vec<TService>> {
-        let service_id = TypeId::of::<TService>();
+    pub (crate) async fn resolve<'a, TService: Sync + Send + 'static>(self: &Arc<Self>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService>{//std::pin::Pin<Box<dyn std::future::Future<Output = BuildDependencyResult<TService>> + Send + Sync + 'a>> {
+        if let Some(alias) = self.get_alias::<TService>().await {
+            return alias.resolve(self, id, local_context).await.map(downcast_alias_result::<TService>);
+        }
 
-        let component_service_pairs = self.cycled_component_service_collection.read().await.get_all_by_service_type::<TService>()
-            .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
+        if let Some(cycled_component_id) = self.get_contextual_binding::<TService>(&id).await {
+            return self.resolve_by_type_id::<TService>(cycled_component_id, id, local_context).await;
+        }
 
-        let mut result = Vec::new();
-        for component_service_pair in component_service_pairs.iter() {
-            let component_cycled_component_pair = self.component_cycled_components_collection.read().await
-                .get_by_cycled_component_id(&component_service_pair.cycled_component_id)
-                .expect(&format!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"));
+        let Some(component_service_pairs) = self.cycled_component_service_collection.read().await.get_default_candidates_by_service_type::<TService>() else {
+            return self.resolve_from_sources::<TService>(&id, local_context).await?
+                .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() });
+        };
 
-            #[cfg(feature = "loop-check")]
-            if let DependencyContextId::TypeId(type_info) = &id {
-                // Link created on dependency add, we need take link for dependency, not cycled dependency or service
-                check_link(self.clone(), &component_cycled_component_pair.component_type_info, type_info).await?;
-            }
+        let component_service_pair = self.select_candidate::<TService>(component_service_pairs).await?;
 
-            let cycled_component = component_cycled_component_pair.converter.build(self.clone(), local_context.clone()).await?;
-            let service: Box<TService> = component_service_pair.converter.build(cycled_component)
-                .downcast::<TService>()
-                .expect(&format!("Invalid service cast expected service_id:[{service_id:?}] service_name:[{service_name}], found [{component_cycled_component_pair:?}]", service_name = type_name::<TService>().to_string()));
+        self.build_service::<TService>(component_service_pair, &id, local_context).await
+    }
+
+    // Without strict resolve take first registered candidate, otherwise candidate must be single
+    async fn select_candidate<TService: 'static>(&self, mut component_service_pairs: Vec<Arc<CycledComponentServicePair>>) -> BuildDependencyResult<Arc<CycledComponentServicePair>> {
+        if self.settings.strict_resolve && component_service_pairs.len() > 1 {
+            let component_cycled_components_collection_read_guard = self.component_cycled_components_collection.read().await;
 
-            result.push(Box::into_inner(service));
+            let candidates = component_service_pairs.iter()
+                .map(|component_service_pair| component_cycled_components_collection_read_guard.get_by_cycled_component_id(&component_service_pair.cycled_component_id)
+                    .expect(&format!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"))
+                    .component_type_info.clone()
+                )
+                .collect();
+
+            return Err(BuildDependencyError::Ambiguous { service: TypeInfo::from_type::<TService>(), candidates });
         }
 
-       return Ok(result);
+        Ok(component_service_pairs.swap_remove(0))
     }
 
-    pub async fn map_component<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static>(self: &Arc<Self>) -> MapComponentResult<Arc<Self>> where TComponent: Unsize<TService> {
-        let component_id = TypeId::of::<TComponent>();
+    // Not registered service return None, but construction errors propagated
+    pub (crate) async fn try_resolve<TService: Sync + Send + 'static>(self: &Arc<Self>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Option<TService>> {
+        if let Some(alias) = self.get_alias::<TService>().await {
+            return alias.try_resolve(self, id, local_context).await.map(downcast_alias_result::<Option<TService>>);
+        }
 
-        let components_read_guard = self.components.read().await;
-        let component = components_read_guard.get(&component_id);
+        if let Some(cycled_component_id) = self.get_contextual_binding::<TService>(&id).await {
+            let Some(component_service_pair) = self.cycled_component_service_collection.read().await.get_all_by_service_type_with_cycled_component_id::<TService>(cycled_component_id) else {
+                return Ok(None);
+            };
 
-        if component.is_none() {
-            return Err(MapComponentError::ComponentNotFound{
-                component_type_info: TypeInfo::from_type::<TComponent>(),
-                service_type_info: TypeInfo::from_type::<TService>(),
-            });
+            return self.build_service::<TService>(component_service_pair, &id, local_context).await.map(Some);
         }
 
-        let component = component.unwrap();
+        let Some(component_service_pairs) = self.cycled_component_service_collection.read().await.get_default_candidates_by_service_type::<TService>() else {
+            return self.resolve_from_sources::<TService>(&id, local_context).await;
+        };
 
-        match component.life_cycle_type {
-            LifeCycle::Transient => self.cycled_component_service_collection.write().await.add_mapping_as_transient::<TComponent, TService>(),
-            LifeCycle::Singleton => self.cycled_component_service_collection.write().await.add_mapping_as_singleton::<TComponent, TService>(),
-            LifeCycle::ContextDependent => self.cycled_component_service_collection.write().await.add_mapping_as_context_dependent::<TComponent, TService>(),
+        let component_service_pair = self.select_candidate::<TService>(component_service_pairs).await?;
+
+        self.build_service::<TService>(component_service_pair, &id, local_context).await.map(Some)
+    }
+
+    // Cycled component bound to service for parent component
+    #[inline(always)]
+    async fn get_contextual_binding<TService: 'static>(&self, id: &DependencyContextId) -> Option<TypeId> {
+        let DependencyContextId::TypeId(parent_type_info, _) = id else {
+            return None;
         };
 
-        Ok(self.clone())
+        self.contextual_bindings.read().await.get(&(parent_type_info.type_id, TypeId::of::<TService>())).map(|binding| binding.cycled_component_id)
     }
 
-    pub async fn delete_component<TComponent: 'static>(&self) -> DeleteComponentResult<()> {
-        // Get cycled_component_service_collection write guard at first, because resolve service start from cycled_component_service_collection read. Here we block resolve before delete
-        // I dont make top RwLock, because in resolve time we can add new dependency from ctr, that mean write lock and then deadlock, because top level RwLock will be in read lock
-        let mut cycled_component_service_collection_write_guard = self.cycled_component_service_collection.write().await;
+    pub (crate) async fn resolve_named<TService: Sync + Send + 'static>(self: &Arc<Self>, name: &str, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
+        let component_service_pair = self.cycled_component_service_collection.read().await.get_by_service_type_and_name::<TService>(name)
+            .ok_or(BuildDependencyError::NamedNotFound{ type_info: TypeInfo::from_type::<TService>(), name: name.to_string() })?;
 
-        // We check life cycle & check component existence in one time
-        let life_cycle = self.components.read().await.get(&TypeId::of::<TComponent>())
-            .ok_or(DeleteComponentError::ComponentNotFound { component_type_info: TypeInfo::from_type::<TComponent>() })?.life_cycle_type;
+        self.build_service::<TService>(component_service_pair, &id, local_context).await
+    }
 
-        if ![LifeCycle::Singleton, LifeCycle::Transient].contains(&life_cycle) {
-            return Err(DeleteComponentError::NotSupportedLifeCycle { component_type_info: TypeInfo::from_type::<TComponent>(), life_cycle })
+    pub (crate) async fn resolve_by_type_id<TService: Sync + Send + 'static>(self: &Arc<Self>, component_type_id: TypeId, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
+        // Contextual binding allow only bound component for parent
+        if self.get_contextual_binding::<TService>(&id).await.is_some_and(|cycled_component_id| cycled_component_id != component_type_id) {
+            return Err(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() });
         }
 
-        let component = self.components.write().await
-            .remove(&TypeId::of::<TComponent>())
-            .unwrap();
+        let component_service_pair = self.cycled_component_service_collection.read().await.get_all_by_service_type_with_cycled_component_id::<TService>(component_type_id)
+            .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
 
-        #[cfg(feature = "loop-check")]
-        let _ = self.links.write().await.remove(&TypeId::of::<TComponent>()).unwrap();
+        self.build_service::<TService>(component_service_pair, &id, local_context).await
+    }
 
-        let cycled_component_builder = self.component_cycled_components_collection.write().await
-            .delete_by_component::<TComponent>()
-            .unwrap();
+    pub (crate) async fn resolve_collection<TService: Sync + Send + 'static>(self: &Arc<Self>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Vec<TService>> {
+        if let Some(alias) = self.get_alias::<TService>().await {
+            return alias.resolve_collection(self, id, local_context).await.map(downcast_alias_result::<Vec<TService>>);
+        }
 
-        match component.life_cycle_type {
-            // Singleton mayby not exist, because not requested
-            LifeCycle::Singleton => _ = self.global_context.write().await.singletons
-                    .remove(&cycled_component_builder.cycled_component_type_info.type_id),
-            LifeCycle::ContextDependent => panic!("Failed life cycle check before delete, life cycle:[{life_cycle:?}]"),
-            _ => {}
+        // Contextual binding leave single component in collection
+        if let Some(cycled_component_id) = self.get_contextual_binding::<TService>(&id).await {
+            return self.resolve_by_type_id::<TService>(cycled_component_id, id, local_context).await.map(|service| vec![service]);
         }
 
-        _ = cycled_component_service_collection_write_guard.delete_cycled_component(&cycled_component_builder.cycled_component_type_info.type_id)
-            .unwrap();
-        
-        Ok(())
-    }
+        // Not registered service can be produced by resolution source, as single service collection
+        let Some(component_service_pairs) = self.cycled_component_service_collection.read().await.get_all_by_service_type::<TService>() else {
+            return self.resolve_from_sources::<TService>(&id, local_context).await?
+                .map(|service| vec![service])
+                .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() });
+        };
 
-    #[inline(always)]
-    pub (crate) async fn is_service_exist(&self, service_type_id: TypeId) -> bool {
-        self.cycled_component_service_collection.read().await.is_service_exist(&service_type_id)
-    }
+        let mut result = Vec::new();
+        for component_service_pair in component_service_pairs.into_iter() {
+            result.push(self.build_service::<TService>(component_service_pair, &id, local_context.clone()).await?);
+        }
 
-    #[inline(always)]
-    pub (crate) async fn is_component_exist(&self, component_type_id: TypeId) -> bool {
-        self.components.read().await.contains_key(&component_type_id)
+       return Ok(result);
     }
-}
 
-#[cfg(feature = "blocking")]
-impl CoreContext {
-    pub (crate) fn blocking_register<TComponent: Sync + Send + 'static>(self: &Arc<Self>, ctor: Box<dyn ITypeConstructor>, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
-        let component = Component::new::<TComponent>(life_cycle.clone(), ctor);
+    pub (crate) async fn resolve_collection_where<TService: Sync + Send + 'static, TFilter: Fn(&ServiceMetadata) -> bool + Sync + Send>(self: &Arc<Self>, filter: TFilter, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Vec<TService>> {
+        if let Some(alias) = self.get_alias::<TService>().await {
+            return alias.resolve_collection_where(self, &filter, id, local_context).await.map(downcast_alias_result::<Vec<TService>>);
+        }
 
-        let component_id = component.component_type_info.type_id.clone();
+        // Contextual binding leave single component in collection, if it match filter
+        if let Some(cycled_component_id) = self.get_contextual_binding::<TService>(&id).await {
+            let component_service_pair = self.cycled_component_service_collection.read().await.get_all_by_service_type_with_cycled_component_id::<TService>(cycled_component_id)
+                .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
 
-        // Проверяем наличие зависимости, если нет добавляем
-        let mut components_guard = self.components.blocking_write();
-        
-        if components_guard.contains_key(&component.component_type_info.type_id) {
-            return Err(AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<TComponent>() });
+            if !filter(&component_service_pair.metadata) {
+                return Ok(Vec::new());
+            }
+
+            return self.build_service::<TService>(component_service_pair, &id, local_context).await.map(|service| vec![service]);
         }
-   
-        components_guard.insert(component_id.clone(), Arc::new(component));
-        //---------------------------
 
-        // Создаем ячейку свзяей без связей //TODO: в линках брать реальный ид
-        #[cfg(feature = "loop-check")]
-        self.links.blocking_write().insert(component_id, DependencyLink::new());
-        //---------------------------
+        let component_service_pairs = self.cycled_component_service_collection.read().await.get_all_by_service_type::<TService>()
+            .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
 
-        // Пустой маппинг сомпонента
-        match life_cycle {
-            LifeCycle::Transient => self.cycled_component_service_collection.blocking_write().add_mapping_as_self::<TComponent>(),
-            LifeCycle::Singleton => self.cycled_component_service_collection.blocking_write().add_mapping_as_self::<Arc<TComponent>>(),
-            LifeCycle::ContextDependent => self.cycled_component_service_collection.blocking_write().add_mapping_as_self::<Weak<TComponent>>(),
+        let mut result = Vec::new();
+        for component_service_pair in component_service_pairs.into_iter().filter(|component_service_pair| filter(&component_service_pair.metadata)) {
+            result.push(self.build_service::<TService>(component_service_pair, &id, local_context.clone()).await?);
         }
-        //---------------------------
 
-        // Регистрируем обработчик лайфтайма компонента
-        match life_cycle {
-            LifeCycle::Transient => self.component_cycled_components_collection.blocking_write().add_transient_cycle_builder::<TComponent>(),
-            LifeCycle::Singleton => self.component_cycled_components_collection.blocking_write().add_singleton_cycle_builder::<TComponent>(),
-            LifeCycle::ContextDependent => self.component_cycled_components_collection.blocking_write().add_context_dependent_cycle_builder::<TComponent>(),
+        Ok(result)
+    }
+
+    // Same order as resolve_collection, but without construction
+    pub (crate) async fn get_collection_metadata<TService: 'static>(&self) -> Vec<ServiceInfo> {
+        let Some(component_service_pairs) = self.cycled_component_service_collection.read().await.get_all_by_service_type::<TService>() else {
+            return Vec::new();
         };
-        //---------------------------
 
-        Ok(ServiceMappingBuilder::new(self.clone()))
+        let component_cycled_components_collection_read_guard = self.component_cycled_components_collection.read().await;
+
+        component_service_pairs.iter()
+            .map(|component_service_pair| ServiceInfo {
+                component_type_info: component_cycled_components_collection_read_guard.get_by_cycled_component_id(&component_service_pair.cycled_component_id)
+                    .unwrap_or_else(|| panic!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"))
+                    .component_type_info.clone(),
+                metadata: component_service_pair.metadata.clone(),
+            })
+            .collect()
     }
 
-    pub (crate) fn blocking_resolve<'a, TService: Sync + Send + 'static>(self: &Arc<Self>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService>{//std::pin::Pin<Box<dyn std::future::Future<Output = BuildDependencyResult<TService>> + Send + Sync + 'a>> {
+    // Common part of all resolve ways, build component with life cycle and convert it to service
+    async fn build_service<TService: Sync + Send + 'static>(self: &Arc<Self>, component_service_pair: Arc<CycledComponentServicePair>, id: &DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
         let service_id = TypeId::of::<TService>();
 
-        let component_service_pair = self.cycled_component_service_collection.blocking_read().get_nth_by_service_type::<TService>(0)
-            .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
-    
-        let component_cycled_component_pair = self.component_cycled_components_collection.blocking_read()
+        let component_cycled_component_pair = self.component_cycled_components_collection.read().await
             .get_by_cycled_component_id(&component_service_pair.cycled_component_id)
             .expect(&format!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"));
-        
+
         #[cfg(feature = "loop-check")]
-        if let DependencyContextId::TypeId(type_info) = &id {
+        if let DependencyContextId::TypeId(type_info, _) = id {
             // Link created on dependency add, we need take link for dependency, not cycled dependency or service
-            blocking_check_link(self.clone(), &component_cycled_component_pair.component_type_info, type_info)?;
+            check_link(self.clone(), &component_cycled_component_pair.component_type_info, type_info).await?;
         }
-    
-        let cycled_component = component_cycled_component_pair.converter.blocking_build(self.clone(), local_context)?;
+
+        let cycled_component = component_cycled_component_pair.converter.build(self.clone(), local_context.clone(), id).await?;
         let service: Box<TService> = component_service_pair.converter.build(cycled_component)
             .downcast::<TService>()
             .expect(&format!("Invalid service cast expected service_id:[{service_id:?}] service_name:[{service_name}], found [{component_cycled_component_pair:?}]", service_name = type_name::<TService>().to_string()));
 
-        Ok(Box::into_inner(service))
+        self.decorate::<TService>(Box::into_inner(service), &component_service_pair, &component_cycled_component_pair, id, local_context).await
     }
 
-    pub (crate) fn blocking_resolve_by_type_id<TService: Sync + Send + 'static>(self: &Arc<Self>, component_type_id: TypeId, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
-        let service_id = TypeId::of::<TService>();
+    pub (crate) async fn resolve_with<TService: Sync + Send + 'static, TArgs: Sync + Send + 'static>(self: &Arc<Self>, args: TArgs, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
+        self.resolve_with_boxed_args::<TService>(Box::new(args), TypeInfo::from_type::<TArgs>(), id, local_context).await
+    }
 
-        let component_service_pair = self.cycled_component_service_collection.blocking_read().get_all_by_service_type_with_cycled_component_id::<TService>(component_type_id)
-            .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
+    // Args boxed, so alias can pass them to target service
+    pub (crate) async fn resolve_with_boxed_args<TService: Sync + Send + 'static>(self: &Arc<Self>, args: Box<dyn Any + Sync + Send>, args_type_info: TypeInfo, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
+        if let Some(alias) = self.get_alias::<TService>().await {
+            return alias.resolve_with(self, args, args_type_info, id, local_context).await.map(downcast_alias_result::<TService>);
+        }
 
-        let component_cycled_component_pair = self.component_cycled_components_collection.blocking_read()
+        let component_service_pair = match self.get_contextual_binding::<TService>(&id).await {
+            Some(cycled_component_id) => self.cycled_component_service_collection.read().await.get_all_by_service_type_with_cycled_component_id::<TService>(cycled_component_id)
+                .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?,
+            None => {
+                let component_service_pairs = self.cycled_component_service_collection.read().await.get_default_candidates_by_service_type::<TService>()
+                    .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
+
+                self.select_candidate::<TService>(component_service_pairs).await?
+            },
+        };
+
+        let component_cycled_component_pair = self.component_cycled_components_collection.read().await
             .get_by_cycled_component_id(&component_service_pair.cycled_component_id)
-            .expect(&format!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"));
+            .unwrap_or_else(|| panic!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"));
+
+        let component = self.components.read().await.get(&component_cycled_component_pair.component_type_info.type_id)
+            .unwrap_or_else(|| panic!("Component cycled component pair exist but component not found:[{component_cycled_component_pair:?}]"))
+            .clone();
+
+        let expected_args_type_info = component.ctor.args_type_info();
+
+        if expected_args_type_info.as_ref() != Some(&args_type_info) {
+            return Err(BuildDependencyError::ArgsTypeMismatch {
+                component_type_info: component.component_type_info.clone(),
+                expected_args_type_info,
+                found_args_type_info: Some(args_type_info),
+            });
+        }
 
         #[cfg(feature = "loop-check")]
-        if let DependencyContextId::TypeId(type_info) = &id {
-            // Link created on dependency add, we need take link for dependency, not cycled dependency or service
-            blocking_check_link(self.clone(), &component_cycled_component_pair.component_type_info, type_info)?;
+        if let DependencyContextId::TypeId(type_info, _) = &id {
+            check_link(self.clone(), &component.component_type_info, type_info).await?;
         }
 
-        let cycled_component = component_cycled_component_pair.converter.blocking_build(self.clone(), local_context)?;
+        // Component with args registered only as transient, so built component is cycled component
+        let dependency_context = DependencyContext::new_dependency(DependencyContextId::child(&id, component.component_type_info.clone()), self.clone(), local_context.clone());
+        let cycled_component = self.construct_component(&component, dependency_context, Some(args), &id).await?;
+
         let service: Box<TService> = component_service_pair.converter.build(cycled_component)
             .downcast::<TService>()
-            .expect(&format!("Invalid service cast expected service_id:[{service_id:?}] service_name:[{service_name}], found [{component_cycled_component_pair:?}]", service_name = type_name::<TService>().to_string()));
+            .unwrap_or_else(|_| panic!("Invalid service cast expected service_name:[{}], found [{component_cycled_component_pair:?}]", type_name::<TService>()));
 
-        return Ok(Box::into_inner(service));
+        self.decorate::<TService>(Box::into_inner(service), &component_service_pair, &component_cycled_component_pair, &id, local_context).await
     }
 
-    pub (crate) fn blocking_resolve_collection<TService: Sync + Send + 'static>(self: &Arc<Self>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Vec<TService>> {
-        let service_id = TypeId::of::<TService>();
+    pub (crate) async fn add_alias<TAlias: Sync + Send + 'static, TTarget: Sync + Send + 'static + CoerceUnsized<TAlias>>(&self) -> AddDependencyResult<()> {
+        let mut aliases_write_guard = self.aliases.write().await;
 
-        let component_service_pairs = self.cycled_component_service_collection.blocking_read().get_all_by_service_type::<TService>()
-            .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
+        if aliases_write_guard.contains_key(&TypeId::of::<TAlias>()) {
+            return Err(AddDependencyError::AliasExist { alias_type_info: TypeInfo::from_type::<TAlias>() });
+        }
 
-        let mut result = Vec::new();
-        for component_service_pair in component_service_pairs.iter() {
-            let component_cycled_component_pair = self.component_cycled_components_collection.blocking_read()
-                .get_by_cycled_component_id(&component_service_pair.cycled_component_id)
-                .expect(&format!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"));
+        // Alias chain can't return to alias, because coercion is possible only from subtype to supertype
+        aliases_write_guard.insert(TypeId::of::<TAlias>(), Arc::new(ServiceAlias::<TAlias, TTarget>::new()));
 
-            #[cfg(feature = "loop-check")]
-            if let DependencyContextId::TypeId(type_info) = &id {
-                // Link created on dependency add, we need take link for dependency, not cycled dependency or service
-                blocking_check_link(self.clone(), &component_cycled_component_pair.component_type_info, type_info)?;
-            }
+        Ok(())
+    }
 
-            let cycled_component = component_cycled_component_pair.converter.blocking_build(self.clone(), local_context.clone())?;
-            let service: Box<TService> = component_service_pair.converter.build(cycled_component)
-                .downcast::<TService>()
-                .expect(&format!("Invalid service cast expected service_id:[{service_id:?}] service_name:[{service_name}], found [{component_cycled_component_pair:?}]", service_name = type_name::<TService>().to_string()));
+    #[inline(always)]
+    async fn get_alias<TService: 'static>(&self) -> Option<Arc<dyn IServiceAlias>> {
+        self.aliases.read().await.get(&TypeId::of::<TService>()).cloned()
+    }
 
-            result.push(Box::into_inner(service));
-        }
+    pub (crate) async fn add_decorator<TService: Sync + Send + 'static>(&self, decorator: Arc<dyn IServiceDecorator>) {
+        self.decorators.write().await.entry(TypeId::of::<TService>()).or_default().push(decorator);
 
-       return Ok(result);
+        // Decorated singletons dropped, so new decorator applied on next resolve
+        self.global_context.write().await.decorated_singletons.retain(|(_, service_id), _| *service_id != TypeId::of::<TService>());
     }
 
-    pub fn blocking_map_component<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static>(self: &Arc<Self>) -> MapComponentResult<Arc<Self>> where TComponent: Unsize<TService> {
-        let component_id = TypeId::of::<TComponent>();
+    // Wrap built service with decorators, registered for service, in registration order
+    // Service of singleton and context dependent component decorated once and cached with component instance
+    async fn decorate<TService: Sync + Send + 'static>(self: &Arc<Self>, service: TService, component_service_pair: &CycledComponentServicePair, component_cycled_component_pair: &ComponentCycledComponentPair, id: &DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
+        let Some(decorators) = self.decorators.read().await.get(&TypeId::of::<TService>()).cloned() else {
+            return Ok(service);
+        };
 
-        let components_read_guard = self.components.blocking_read();
-        let component = components_read_guard.get(&component_id);
+        let decorated_service_key = (component_service_pair.cycled_component_id, component_service_pair.service_id);
 
-        if component.is_none() {
-            return Err(MapComponentError::ComponentNotFound{
-                component_type_info: TypeInfo::from_type::<TComponent>(),
-                service_type_info: TypeInfo::from_type::<TService>(),
-            });
+        let decorated_service_ref = match component_cycled_component_pair.life_cycle {
+            LifeCycle::Transient => {
+                let decorated_service = self.apply_decorators(&decorators, Box::new(service), &component_cycled_component_pair.component_type_info, id, local_context).await?;
+                return downcast_decorated_service::<TService>(decorated_service);
+            },
+            LifeCycle::Singleton => self.global_context.write().await.decorated_singletons.entry(decorated_service_key).or_default().clone(),
+            LifeCycle::ContextDependent => local_context.decorated_services.write().await.entry(decorated_service_key).or_default().clone(),
+        };
+
+        if let Some(decorated_service) = decorated_service_ref.read().await.as_ref() {
+            return clone_decorated_service::<TService>(component_service_pair, &**decorated_service);
         }
 
-        let component = component.unwrap();
+        // Decorators run without cache lock, so decorator can resolve services, which use the same cache
+        let decorated_service = self.apply_decorators(&decorators, Box::new(service), &component_cycled_component_pair.component_type_info, id, local_context).await?;
 
-        match component.life_cycle_type {
-            LifeCycle::Transient => self.cycled_component_service_collection.blocking_write().add_mapping_as_transient::<TComponent, TService>(),
-            LifeCycle::Singleton => self.cycled_component_service_collection.blocking_write().add_mapping_as_singleton::<TComponent, TService>(),
-            LifeCycle::ContextDependent => self.cycled_component_service_collection.blocking_write().add_mapping_as_context_dependent::<TComponent, TService>(),
-        };
+        if !decorated_service.is::<TService>() {
+            return Err(BuildDependencyError::InvalidDecoratedService { service_type_info: TypeInfo::from_type::<TService>() });
+        }
 
-        Ok(self.clone())
+        // Service, decorated by another resolve meanwhile, kept, so shared service decorated only once
+        let mut decorated_service_write_guard = decorated_service_ref.write().await;
+        let decorated_service = decorated_service_write_guard.get_or_insert(decorated_service);
+
+        clone_decorated_service::<TService>(component_service_pair, &**decorated_service)
     }
 
-    pub fn blocking_delete_component<TComponent: 'static>(&self) -> DeleteComponentResult<()> {
-        // Get cycled_component_service_collection write guard at first, because resolve service start from cycled_component_service_collection read. Here we block resolve before delete
-        // I dont make top RwLock, because in resolve time we can add new dependency from ctr, that mean write lock and then deadlock, because top level RwLock will be in read lock
-        let mut cycled_component_service_collection_write_guard = self.cycled_component_service_collection.blocking_write();
+    async fn apply_decorators(self: &Arc<Self>, decorators: &[Arc<dyn IServiceDecorator>], service: Box<dyn Any + Sync + Send>, component_type_info: &TypeInfo, id: &DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let mut service = service;
 
-        // We check life cycle & check component existence in one time
-        let life_cycle = self.components.blocking_read().get(&TypeId::of::<TComponent>())
-            .ok_or(DeleteComponentError::ComponentNotFound { component_type_info: TypeInfo::from_type::<TComponent>() })?.life_cycle_type;
+        for decorator in decorators.iter() {
+            let dependency_context = DependencyContext::new_dependency(DependencyContextId::child(id, component_type_info.clone()), self.clone(), local_context.clone());
+            service = decorator.decorate(dependency_context, service).await?;
+        }
 
-        if ![LifeCycle::Singleton, LifeCycle::Transient].contains(&life_cycle) {
-            return Err(DeleteComponentError::NotSupportedLifeCycle { component_type_info: TypeInfo::from_type::<TComponent>(), life_cycle })
+        Ok(service)
+    }
+
+    pub (crate) async fn add_resolution_source(&self, resolution_source: Arc<dyn ResolutionSource>) {
+        self.resolution_sources.write().await.push(resolution_source);
+    }
+
+    // Ask resolution sources in adding order, first source, which produce service, win
+    async fn resolve_from_sources<TService: Sync + Send + 'static>(self: &Arc<Self>, id: &DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Option<TService>> {
+        let resolution_sources = self.resolution_sources.read().await.clone();
+
+        if resolution_sources.is_empty() {
+            return Ok(None);
         }
 
-        let component = self.components.blocking_write()
-            .remove(&TypeId::of::<TComponent>())
-            .unwrap();
+        let service_type_info = TypeInfo::from_type::<TService>();
 
-        #[cfg(feature = "loop-check")]
-        let _ = self.links.blocking_write().remove(&TypeId::of::<TComponent>()).unwrap();
+        for resolution_source in resolution_sources.iter() {
+            let dependency_context = DependencyContext::new_dependency(id.clone(), self.clone(), local_context.clone());
+            let source_resolution = resolution_source.resolve(&dependency_context, &service_type_info).await?;
 
-        let cycled_component_builder = self.component_cycled_components_collection.blocking_write()
-            .delete_by_component::<TComponent>()
-            .unwrap();
+            match source_resolution {
+                SourceResolution::NotFound => {},
+                SourceResolution::Instance(instance) => return instance.downcast::<TService>()
+                    .map(|service| Some(Box::into_inner(service)))
+                    .map_err(|_| BuildDependencyError::InvalidSourceInstance { service_type_info }),
+                SourceResolution::Registered => {
+                    // Source, which not registered service, is skipped
+                    let Some(component_service_pairs) = self.cycled_component_service_collection.read().await.get_default_candidates_by_service_type::<TService>() else {
+                        continue;
+                    };
 
-        match component.life_cycle_type {
-            // Singleton mayby not exist, because not requested
-            LifeCycle::Singleton => _ = self.global_context.blocking_write().singletons
-                    .remove(&cycled_component_builder.cycled_component_type_info.type_id),
-            LifeCycle::ContextDependent => panic!("Failed life cycle check before delete, life cycle:[{life_cycle:?}]"),
-            _ => {}
+                    let component_service_pair = self.select_candidate::<TService>(component_service_pairs).await?;
+
+                    return self.build_service::<TService>(component_service_pair, id, local_context).await.map(Some);
+                },
+            }
         }
 
-        _ = cycled_component_service_collection_write_guard.delete_cycled_component(&cycled_component_builder.cycled_component_type_info.type_id)
-            .unwrap();
-        
-        Ok(())
+        Ok(None)
     }
 
-    #[inline(always)]
-    pub (crate) fn blocking_is_service_exist(&self, service_type_id: TypeId) -> bool {
-        self.cycled_component_service_collection.blocking_read().is_service_exist(&service_type_id)
+    pub (crate) async fn add_interceptor(&self, interceptor: Arc<dyn ResolveInterceptor>) {
+        self.interceptors.write().await.push(interceptor);
     }
 
-    #[inline(always)]
-    pub (crate) fn blocking_is_component_exist(&self, component_type_id: TypeId) -> bool {
-        self.components.blocking_read().contains_key(&component_type_id)
-    }
-}
+    // Call component ctor, wrapped with resolve interceptors
+    pub (crate) async fn construct_component(self: &Arc<Self>, component: &Component, dependency_context: DependencyContext, args: Option<Box<dyn Any + Sync + Send>>, parent: &DependencyContextId) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let interceptors = self.interceptors.read().await.clone();
 
-#[cfg(not(feature = "async-mode"))]
-impl CoreContext {
-    pub (crate) fn register<TComponent: Sync + Send + 'static>(self: &Arc<Self>, ctor: Box<dyn ITypeConstructor>, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
-        let component = Component::new::<TComponent>(life_cycle.clone(), ctor);
+        if interceptors.is_empty() {
+            return Self::call_ctor(component, dependency_context, args).await;
+        }
+
+        let resolve_info = ResolveInfo {
+            component_type_info: component.component_type_info.clone(),
+            life_cycle: component.life_cycle_type,
+            parent_type_info: match parent {
+                DependencyContextId::TypeId(parent_type_info, _) => Some(parent_type_info.clone()),
+                DependencyContextId::Root => None,
+            },
+        };
+
+        let mut intercepted_result = None;
+        // Only interceptors, which before_resolve called, see result
+        let mut called_interceptors_count = interceptors.len();
+
+        for (index, interceptor) in interceptors.iter().enumerate() {
+            match interceptor.before_resolve(&resolve_info).await {
+                Ok(None) => {},
+                Ok(Some(instance)) => { intercepted_result = Some(Ok(instance)); called_interceptors_count = index + 1; break; },
+                Err(err) => { intercepted_result = Some(Err(err)); called_interceptors_count = index + 1; break; },
+            }
+        }
+
+        let mut result = match intercepted_result {
+            Some(result) => result,
+            None => Self::call_ctor(component, dependency_context, args).await,
+        };
+
+        for interceptor in interceptors[..called_interceptors_count].iter().rev() {
+            result = interceptor.after_resolve(&resolve_info, result).await;
+        }
+
+        // Interceptor can return instance of any type, builder expect component type
+        let instance = result?;
+
+        if (*instance).type_id() != component.component_type_info.type_id {
+            return Err(BuildDependencyError::InvalidInterceptedInstance { component_type_info: component.component_type_info.clone() });
+        }
+
+        Ok(instance)
+    }
+
+    // Component with args constructed only by resolve_with
+    #[inline(always)]
+    async fn call_ctor(component: &Component, dependency_context: DependencyContext, args: Option<Box<dyn Any + Sync + Send>>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        match args {
+            Some(args) => component.ctor.ctor_with_args(dependency_context, args).await,
+            None => component.ctor.ctor(dependency_context).await,
+        }
+    }
+
+    pub (crate) async fn map_component<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static>(self: &Arc<Self>) -> MapComponentResult<Arc<CycledComponentServicePair>> where TComponent: Unsize<TService> {
+        let component_id = TypeId::of::<TComponent>();
+
+        let components_read_guard = self.components.read().await;
+        let component = components_read_guard.get(&component_id);
+
+        if component.is_none() {
+            return Err(MapComponentError::ComponentNotFound{
+                component_type_info: TypeInfo::from_type::<TComponent>(),
+                service_type_info: TypeInfo::from_type::<TService>(),
+            });
+        }
+
+        let component = component.unwrap();
+
+        let component_service_pair = match component.life_cycle_type {
+            LifeCycle::Transient => self.cycled_component_service_collection.write().await.add_mapping_as_transient::<TComponent, TService>(ServiceMappingOptions::default()),
+            LifeCycle::Singleton => self.cycled_component_service_collection.write().await.add_mapping_as_singleton::<TComponent, TService>(ServiceMappingOptions::default()),
+            LifeCycle::ContextDependent => self.cycled_component_service_collection.write().await.add_mapping_as_context_dependent::<TComponent, TService>(ServiceMappingOptions::default()),
+        };
+
+        Ok(component_service_pair)
+    }
+
+    pub async fn delete_component<TComponent: 'static>(&self) -> DeleteComponentResult<()> {
+        self.delete_component_by_type_info(&TypeInfo::from_type::<TComponent>()).await
+    }
+
+    pub (crate) async fn delete_component_by_type_info(&self, component_type_info: &TypeInfo) -> DeleteComponentResult<()> {
+        // Get cycled_component_service_collection write guard at first, because resolve service start from cycled_component_service_collection read. Here we block resolve before delete
+        // I dont make top RwLock, because in resolve time we can add new dependency from ctr, that mean write lock and then deadlock, because top level RwLock will be in read lock
+        let mut cycled_component_service_collection_write_guard = self.cycled_component_service_collection.write().await;
+
+        self.delete_component_with_guard(&mut cycled_component_service_collection_write_guard, component_type_info).await
+    }
+
+    // Delete component under cycled_component_service_collection write guard, taken by caller
+    async fn delete_component_with_guard(&self, cycled_component_service_collection_write_guard: &mut CycledComponentServiceCollection, component_type_info: &TypeInfo) -> DeleteComponentResult<()> {
+        // We check life cycle & check component existence in one time
+        let life_cycle = self.components.read().await.get(&component_type_info.type_id)
+            .ok_or(DeleteComponentError::ComponentNotFound { component_type_info: component_type_info.clone() })?.life_cycle_type;
+
+        if ![LifeCycle::Singleton, LifeCycle::Transient].contains(&life_cycle) {
+            return Err(DeleteComponentError::NotSupportedLifeCycle { component_type_info: component_type_info.clone(), life_cycle })
+        }
+
+        self.remove_component_with_guard(cycled_component_service_collection_write_guard, component_type_info).await
+    }
+
+    // Remove component of any life cycle, context dependent instances stay cached in local contexts
+    async fn remove_component_with_guard(&self, cycled_component_service_collection_write_guard: &mut CycledComponentServiceCollection, component_type_info: &TypeInfo) -> DeleteComponentResult<()> {
+        let component = self.components.write().await
+            .remove(&component_type_info.type_id)
+            .ok_or(DeleteComponentError::ComponentNotFound { component_type_info: component_type_info.clone() })?;
+
+        #[cfg(feature = "loop-check")]
+        let _ = self.links.write().await.remove(&component_type_info.type_id).unwrap();
+
+        let cycled_component_builder = self.component_cycled_components_collection.write().await
+            .delete_by_component_id(&component_type_info.type_id)
+            .unwrap();
+
+        // Singleton mayby not exist, because not requested
+        if component.life_cycle_type == LifeCycle::Singleton {
+            self.global_context.write().await.remove_singleton(&cycled_component_builder.cycled_component_type_info.type_id);
+        }
+
+        _ = cycled_component_service_collection_write_guard.delete_cycled_component(&cycled_component_builder.cycled_component_type_info.type_id)
+            .unwrap();
+        
+        Ok(())
+    }
+
+    // Components with any mapping tagged, deleted only if all of them support delete
+    pub (crate) async fn delete_components_with_tag(&self, tag: &str) -> DeleteComponentResult<Vec<TypeInfo>> {
+        // Same guard for check and delete, so tagged components can't be changed between them
+        let mut cycled_component_service_collection_write_guard = self.cycled_component_service_collection.write().await;
+
+        let mut component_type_infos: Vec<TypeInfo> = Vec::new();
+
+        {
+            let component_cycled_components_collection_read_guard = self.component_cycled_components_collection.read().await;
+
+            for component_service_pair in cycled_component_service_collection_write_guard.cycled_components_search_idx.values().flatten() {
+                if !component_service_pair.metadata.tag(tag) {
+                    continue;
+                }
+
+                let component_type_info = &component_cycled_components_collection_read_guard.get_by_cycled_component_id(&component_service_pair.cycled_component_id)
+                    .unwrap_or_else(|| panic!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"))
+                    .component_type_info;
+
+                if !component_type_infos.contains(component_type_info) {
+                    component_type_infos.push(component_type_info.clone());
+                }
+            }
+        }
+
+        {
+            let components_read_guard = self.components.read().await;
+
+            for component_type_info in component_type_infos.iter() {
+                let life_cycle = components_read_guard.get(&component_type_info.type_id)
+                    .ok_or(DeleteComponentError::ComponentNotFound { component_type_info: component_type_info.clone() })?.life_cycle_type;
+
+                if ![LifeCycle::Singleton, LifeCycle::Transient].contains(&life_cycle) {
+                    return Err(DeleteComponentError::NotSupportedLifeCycle { component_type_info: component_type_info.clone(), life_cycle })
+                }
+            }
+        }
+
+        for component_type_info in component_type_infos.iter() {
+            self.delete_component_with_guard(&mut cycled_component_service_collection_write_guard, component_type_info).await
+                .unwrap_or_else(|err| panic!("Component checked before delete, but delete failed:[{err:?}]"));
+        }
+
+        Ok(component_type_infos)
+    }
+
+    #[inline(always)]
+    pub (crate) async fn is_service_exist(&self, service_type_id: TypeId) -> bool {
+        // Alias exist, if alias target exist
+        let mut service_type_id = service_type_id;
+        while let Some(alias) = self.aliases.read().await.get(&service_type_id) {
+            service_type_id = alias.target_type_info().type_id;
+        }
+
+        self.cycled_component_service_collection.read().await.is_service_exist(&service_type_id)
+    }
+
+    #[inline(always)]
+    pub (crate) async fn is_named_service_exist(&self, service_type_id: TypeId, name: &str) -> bool {
+        self.cycled_component_service_collection.read().await.is_named_service_exist(&service_type_id, name)
+    }
+
+    #[inline(always)]
+    pub (crate) async fn is_component_exist(&self, component_type_id: TypeId) -> bool {
+        self.components.read().await.contains_key(&component_type_id)
+    }
+
+    #[inline(always)]
+    pub (crate) async fn is_module_installed(&self, module_type_id: TypeId) -> bool {
+        self.modules.read().await.contains_key(&module_type_id)
+    }
+
+    pub (crate) async fn check_module_install(&self, module_type_info: &TypeInfo, dependencies: &[TypeInfo]) -> InstallModuleResult<()> {
+        let modules_read_guard = self.modules.read().await;
+
+        if modules_read_guard.contains_key(&module_type_info.type_id) {
+            return Err(InstallModuleError::ModuleExist { module_type_info: module_type_info.clone() });
+        }
+
+        if let Some(dependency_type_info) = dependencies.iter().find(|dependency_type_info| !modules_read_guard.contains_key(&dependency_type_info.type_id)) {
+            return Err(InstallModuleError::DependencyNotInstalled { module_type_info: module_type_info.clone(), dependency_type_info: dependency_type_info.clone() });
+        }
+
+        Ok(())
+    }
+
+    pub (crate) async fn add_module(&self, module_type_info: &TypeInfo, installed_module: InstalledModule) -> InstallModuleResult<()> {
+        let mut modules_write_guard = self.modules.write().await;
+
+        // Same module can be installed in parallel
+        if modules_write_guard.contains_key(&module_type_info.type_id) {
+            return Err(InstallModuleError::ModuleExist { module_type_info: module_type_info.clone() });
+        }
+
+        // Dependency can be uninstalled while module installed
+        if let Some(dependency_type_info) = installed_module.dependencies.iter().find(|dependency_type_info| !modules_write_guard.contains_key(&dependency_type_info.type_id)) {
+            return Err(InstallModuleError::DependencyNotInstalled { module_type_info: module_type_info.clone(), dependency_type_info: dependency_type_info.clone() });
+        }
+
+        // Context dependent instances cached in local contexts, so module can't delete component on uninstall
+        {
+            let components_read_guard = self.components.read().await;
+
+            if let Some(component_type_info) = installed_module.components.iter().find(|component_type_info| components_read_guard.get(&component_type_info.type_id)
+                .is_some_and(|component| component.life_cycle_type == LifeCycle::ContextDependent)) {
+                return Err(InstallModuleError::NotSupportedLifeCycle { module_type_info: module_type_info.clone(), component_type_info: component_type_info.clone(), life_cycle: LifeCycle::ContextDependent });
+            }
+        }
+
+        modules_write_guard.insert(module_type_info.type_id, installed_module);
+
+        Ok(())
+    }
+
+    // Check module can be uninstalled and return module for uninstall hook
+    pub (crate) async fn get_module_for_uninstall(&self, module_type_info: &TypeInfo) -> UninstallModuleResult<Arc<dyn DependencyModule>> {
+        let modules_read_guard = self.modules.read().await;
+        let installed_module = check_module_uninstall(&modules_read_guard, module_type_info)?;
+
+        Ok(installed_module.module.clone())
+    }
+
+    pub (crate) async fn delete_module(&self, module_type_info: &TypeInfo) -> UninstallModuleResult<()> {
+        // Guards taken in register_or_replace order, so module components can't be replaced until deleted
+        let mut modules_write_guard = self.modules.write().await;
+        let mut cycled_component_service_collection_write_guard = self.cycled_component_service_collection.write().await;
+
+        // Modules can be changed by uninstall hook, so check again
+        check_module_uninstall(&modules_write_guard, module_type_info)?;
+
+        // Module removed before delete, so dependent module can't be installed meanwhile
+        let installed_module = modules_write_guard.remove(&module_type_info.type_id)
+            .unwrap_or_else(|| panic!("Module checked but not found:[{module_type_info:?}]"));
+
+        drop(modules_write_guard);
+
+        // Module components are never context dependent, so only manually deleted component can't be deleted
+        for component_type_info in installed_module.components.iter() {
+            _ = self.delete_component_with_guard(&mut cycled_component_service_collection_write_guard, component_type_info).await;
+        }
+
+        for component_service_pair in installed_module.mappings.iter() {
+            // Pair can be deleted with component
+            _ = cycled_component_service_collection_write_guard.delete_pair(component_service_pair);
+        }
+
+        Ok(())
+    }
+
+    // Rollback registrations of failed module install, context dependent components are removed too
+    pub (crate) async fn delete_module_registrations(&self, components: &[TypeInfo], mappings: &[Arc<CycledComponentServicePair>]) {
+        let mut cycled_component_service_collection_write_guard = self.cycled_component_service_collection.write().await;
+
+        for component_type_info in components.iter() {
+            _ = self.remove_component_with_guard(&mut cycled_component_service_collection_write_guard, component_type_info).await;
+        }
+
+        for component_service_pair in mappings.iter() {
+            // Pair can be deleted with component
+            _ = cycled_component_service_collection_write_guard.delete_pair(component_service_pair);
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl CoreContext {
+    pub (crate) fn blocking_register<TComponent: Sync + Send + 'static>(self: &Arc<Self>, ctor: Box<dyn ITypeConstructor>, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        let component = Component::new::<TComponent>(life_cycle.clone(), ctor);
+
+        let component_id = component.component_type_info.type_id.clone();
+
+        // Проверяем наличие зависимости, если нет добавляем
+        let mut components_guard = self.components.blocking_write();
+        
+        // Other component can own same cycled component, like transient Arc<T> and singleton T
+        if components_guard.contains_key(&component.component_type_info.type_id)
+            || self.component_cycled_components_collection.blocking_read().get_by_cycled_component_id(&cycled_component_type_id::<TComponent>(&life_cycle)).is_some() {
+            return Err(AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<TComponent>() });
+        }
+   
+        components_guard.insert(component_id.clone(), Arc::new(component));
+        //---------------------------
+
+        // Создаем ячейку свзяей без связей //TODO: в линках брать реальный ид
+        #[cfg(feature = "loop-check")]
+        self.links.blocking_write().insert(component_id, DependencyLink::new());
+        //---------------------------
+
+        // Пустой маппинг сомпонента
+        match life_cycle {
+            LifeCycle::Transient => self.cycled_component_service_collection.blocking_write().add_mapping_as_self::<TComponent>(),
+            LifeCycle::Singleton => self.cycled_component_service_collection.blocking_write().add_mapping_as_shared_self::<Arc<TComponent>>(),
+            LifeCycle::ContextDependent => self.cycled_component_service_collection.blocking_write().add_mapping_as_shared_self::<Weak<TComponent>>(),
+        }
+        //---------------------------
+
+        // Регистрируем обработчик лайфтайма компонента
+        match life_cycle {
+            LifeCycle::Transient => self.component_cycled_components_collection.blocking_write().add_transient_cycle_builder::<TComponent>(),
+            LifeCycle::Singleton => self.component_cycled_components_collection.blocking_write().add_singleton_cycle_builder::<TComponent>(),
+            LifeCycle::ContextDependent => self.component_cycled_components_collection.blocking_write().add_context_dependent_cycle_builder::<TComponent>(),
+        };
+        //---------------------------
+
+        Ok(ServiceMappingBuilder::new(self.clone()))
+    }
+
+    // Replace component ctor, or register component if not exist. Return builder and flag, component replaced
+    // Get cycled_component_service_collection write guard at first, like in delete, to block resolve while component replaced
+    pub (crate) fn blocking_register_or_replace<TComponent: Sync + Send + 'static>(self: &Arc<Self>, ctor: Box<dyn ITypeConstructor>, life_cycle: LifeCycle) -> AddDependencyResult<(ServiceMappingBuilder<TComponent>, bool)> {
+        // Replaced component is not owned by installed module anymore, modules guard taken at first like in delete_module
+        let mut modules_write_guard = self.modules.blocking_write();
+        let mut cycled_component_service_collection_write_guard = self.cycled_component_service_collection.blocking_write();
+        let mut component_cycled_components_collection_write_guard = self.component_cycled_components_collection.blocking_write();
+
+        let component_id = TypeId::of::<TComponent>();
+
+        // Other component can own same cycled component, like transient Arc<T> and singleton T
+        if component_cycled_components_collection_write_guard.get_by_cycled_component_id(&cycled_component_type_id::<TComponent>(&life_cycle))
+            .is_some_and(|component_cycled_component_pair| component_cycled_component_pair.component_type_info.type_id != component_id) {
+            return Err(AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<TComponent>() });
+        }
+
+        let component = Component::new::<TComponent>(life_cycle, ctor);
+
+        let old_component = self.components.blocking_write().insert(component_id, Arc::new(component));
+
+        let Some(old_component) = old_component else {
+            #[cfg(feature = "loop-check")]
+            self.links.blocking_write().insert(component_id, DependencyLink::new());
+
+            add_life_cycle::<TComponent>(&mut cycled_component_service_collection_write_guard, &mut component_cycled_components_collection_write_guard, &life_cycle);
+
+            return Ok((ServiceMappingBuilder::new(self.clone()), false));
+        };
+
+        for installed_module in modules_write_guard.values_mut() {
+            installed_module.components.retain(|component_type_info| component_type_info.type_id != component_id);
+        }
+
+        // New ctor can have other dependencies
+        #[cfg(feature = "loop-check")]
+        reset_child_links(&mut self.links.blocking_write(), &component_id);
+
+        if old_component.life_cycle_type == LifeCycle::Singleton {
+            self.global_context.blocking_write().remove_singleton(&TypeId::of::<Arc<TComponent>>());
+        }
+
+        // Service mappings depend on life cycle, so mappings can be kept only with same life cycle
+        if old_component.life_cycle_type != life_cycle {
+            let cycled_component_builder = component_cycled_components_collection_write_guard.delete_by_component::<TComponent>()
+                .unwrap_or_else(|| panic!("Component exist but component cycled component pair not found:[{:?}]", TypeInfo::from_type::<TComponent>()));
+
+            _ = cycled_component_service_collection_write_guard.delete_cycled_component(&cycled_component_builder.cycled_component_type_info.type_id);
+
+            add_life_cycle::<TComponent>(&mut cycled_component_service_collection_write_guard, &mut component_cycled_components_collection_write_guard, &life_cycle);
+        }
+
+        Ok((ServiceMappingBuilder::new(self.clone()), true))
+    }
+
+    pub (crate) fn blocking_resolve<'a, TService: Sync + Send + 'static>(self: &Arc<Self>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService>{//std::pin::Pin<Box<dyn std::future::Future<Output = BuildDependencyResult<TService>> + Send + Sync + 'a>> {
+        if let Some(alias) = self.blocking_get_alias::<TService>() {
+            return alias.blocking_resolve(self, id, local_context).map(downcast_alias_result::<TService>);
+        }
+
+        if let Some(cycled_component_id) = self.blocking_get_contextual_binding::<TService>(&id) {
+            return self.blocking_resolve_by_type_id::<TService>(cycled_component_id, id, local_context);
+        }
+
+        let Some(component_service_pairs) = self.cycled_component_service_collection.blocking_read().get_default_candidates_by_service_type::<TService>() else {
+            return self.blocking_resolve_from_sources::<TService>(&id, local_context)?
+                .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() });
+        };
+
+        let component_service_pair = self.blocking_select_candidate::<TService>(component_service_pairs)?;
+
+        self.blocking_build_service::<TService>(component_service_pair, &id, local_context)
+    }
+
+    // Without strict resolve take first registered candidate, otherwise candidate must be single
+    fn blocking_select_candidate<TService: 'static>(&self, mut component_service_pairs: Vec<Arc<CycledComponentServicePair>>) -> BuildDependencyResult<Arc<CycledComponentServicePair>> {
+        if self.settings.strict_resolve && component_service_pairs.len() > 1 {
+            let component_cycled_components_collection_read_guard = self.component_cycled_components_collection.blocking_read();
+
+            let candidates = component_service_pairs.iter()
+                .map(|component_service_pair| component_cycled_components_collection_read_guard.get_by_cycled_component_id(&component_service_pair.cycled_component_id)
+                    .expect(&format!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"))
+                    .component_type_info.clone()
+                )
+                .collect();
+
+            return Err(BuildDependencyError::Ambiguous { service: TypeInfo::from_type::<TService>(), candidates });
+        }
+
+        Ok(component_service_pairs.swap_remove(0))
+    }
+
+    // Not registered service return None, but construction errors propagated
+    pub (crate) fn blocking_try_resolve<TService: Sync + Send + 'static>(self: &Arc<Self>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Option<TService>> {
+        if let Some(alias) = self.blocking_get_alias::<TService>() {
+            return alias.blocking_try_resolve(self, id, local_context).map(downcast_alias_result::<Option<TService>>);
+        }
+
+        if let Some(cycled_component_id) = self.blocking_get_contextual_binding::<TService>(&id) {
+            let Some(component_service_pair) = self.cycled_component_service_collection.blocking_read().get_all_by_service_type_with_cycled_component_id::<TService>(cycled_component_id) else {
+                return Ok(None);
+            };
+
+            return self.blocking_build_service::<TService>(component_service_pair, &id, local_context).map(Some);
+        }
+
+        let Some(component_service_pairs) = self.cycled_component_service_collection.blocking_read().get_default_candidates_by_service_type::<TService>() else {
+            return self.blocking_resolve_from_sources::<TService>(&id, local_context);
+        };
+
+        let component_service_pair = self.blocking_select_candidate::<TService>(component_service_pairs)?;
+
+        self.blocking_build_service::<TService>(component_service_pair, &id, local_context).map(Some)
+    }
+
+    // Cycled component bound to service for parent component
+    #[inline(always)]
+    fn blocking_get_contextual_binding<TService: 'static>(&self, id: &DependencyContextId) -> Option<TypeId> {
+        let DependencyContextId::TypeId(parent_type_info, _) = id else {
+            return None;
+        };
+
+        self.contextual_bindings.blocking_read().get(&(parent_type_info.type_id, TypeId::of::<TService>())).map(|binding| binding.cycled_component_id)
+    }
+
+    pub (crate) fn blocking_resolve_named<TService: Sync + Send + 'static>(self: &Arc<Self>, name: &str, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
+        let component_service_pair = self.cycled_component_service_collection.blocking_read().get_by_service_type_and_name::<TService>(name)
+            .ok_or(BuildDependencyError::NamedNotFound{ type_info: TypeInfo::from_type::<TService>(), name: name.to_string() })?;
+
+        self.blocking_build_service::<TService>(component_service_pair, &id, local_context)
+    }
+
+    pub (crate) fn blocking_resolve_by_type_id<TService: Sync + Send + 'static>(self: &Arc<Self>, component_type_id: TypeId, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
+        // Contextual binding allow only bound component for parent
+        if self.blocking_get_contextual_binding::<TService>(&id).is_some_and(|cycled_component_id| cycled_component_id != component_type_id) {
+            return Err(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() });
+        }
+
+        let component_service_pair = self.cycled_component_service_collection.blocking_read().get_all_by_service_type_with_cycled_component_id::<TService>(component_type_id)
+            .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
+
+        self.blocking_build_service::<TService>(component_service_pair, &id, local_context)
+    }
+
+    pub (crate) fn blocking_resolve_collection<TService: Sync + Send + 'static>(self: &Arc<Self>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Vec<TService>> {
+        if let Some(alias) = self.blocking_get_alias::<TService>() {
+            return alias.blocking_resolve_collection(self, id, local_context).map(downcast_alias_result::<Vec<TService>>);
+        }
+
+        // Contextual binding leave single component in collection
+        if let Some(cycled_component_id) = self.blocking_get_contextual_binding::<TService>(&id) {
+            return self.blocking_resolve_by_type_id::<TService>(cycled_component_id, id, local_context).map(|service| vec![service]);
+        }
+
+        // Not registered service can be produced by resolution source, as single service collection
+        let Some(component_service_pairs) = self.cycled_component_service_collection.blocking_read().get_all_by_service_type::<TService>() else {
+            return self.blocking_resolve_from_sources::<TService>(&id, local_context)?
+                .map(|service| vec![service])
+                .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() });
+        };
+
+        let mut result = Vec::new();
+        for component_service_pair in component_service_pairs.into_iter() {
+            result.push(self.blocking_build_service::<TService>(component_service_pair, &id, local_context.clone())?);
+        }
+
+       return Ok(result);
+    }
+
+    pub (crate) fn blocking_resolve_collection_where<TService: Sync + Send + 'static, TFilter: Fn(&ServiceMetadata) -> bool + Sync + Send>(self: &Arc<Self>, filter: TFilter, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Vec<TService>> {
+        if let Some(alias) = self.blocking_get_alias::<TService>() {
+            return alias.blocking_resolve_collection_where(self, &filter, id, local_context).map(downcast_alias_result::<Vec<TService>>);
+        }
+
+        // Contextual binding leave single component in collection, if it match filter
+        if let Some(cycled_component_id) = self.blocking_get_contextual_binding::<TService>(&id) {
+            let component_service_pair = self.cycled_component_service_collection.blocking_read().get_all_by_service_type_with_cycled_component_id::<TService>(cycled_component_id)
+                .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
+
+            if !filter(&component_service_pair.metadata) {
+                return Ok(Vec::new());
+            }
+
+            return self.blocking_build_service::<TService>(component_service_pair, &id, local_context).map(|service| vec![service]);
+        }
+
+        let component_service_pairs = self.cycled_component_service_collection.blocking_read().get_all_by_service_type::<TService>()
+            .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
+
+        let mut result = Vec::new();
+        for component_service_pair in component_service_pairs.into_iter().filter(|component_service_pair| filter(&component_service_pair.metadata)) {
+            result.push(self.blocking_build_service::<TService>(component_service_pair, &id, local_context.clone())?);
+        }
+
+        Ok(result)
+    }
+
+    // Same order as resolve_collection, but without construction
+    pub (crate) fn blocking_get_collection_metadata<TService: 'static>(&self) -> Vec<ServiceInfo> {
+        let Some(component_service_pairs) = self.cycled_component_service_collection.blocking_read().get_all_by_service_type::<TService>() else {
+            return Vec::new();
+        };
+
+        let component_cycled_components_collection_read_guard = self.component_cycled_components_collection.blocking_read();
+
+        component_service_pairs.iter()
+            .map(|component_service_pair| ServiceInfo {
+                component_type_info: component_cycled_components_collection_read_guard.get_by_cycled_component_id(&component_service_pair.cycled_component_id)
+                    .unwrap_or_else(|| panic!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"))
+                    .component_type_info.clone(),
+                metadata: component_service_pair.metadata.clone(),
+            })
+            .collect()
+    }
+
+    // Common part of all resolve ways, build component with life cycle and convert it to service
+    fn blocking_build_service<TService: Sync + Send + 'static>(self: &Arc<Self>, component_service_pair: Arc<CycledComponentServicePair>, id: &DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
+        let service_id = TypeId::of::<TService>();
+
+        let component_cycled_component_pair = self.component_cycled_components_collection.blocking_read()
+            .get_by_cycled_component_id(&component_service_pair.cycled_component_id)
+            .expect(&format!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"));
+
+        #[cfg(feature = "loop-check")]
+        if let DependencyContextId::TypeId(type_info, _) = id {
+            // Link created on dependency add, we need take link for dependency, not cycled dependency or service
+            blocking_check_link(self.clone(), &component_cycled_component_pair.component_type_info, type_info)?;
+        }
+
+        let cycled_component = component_cycled_component_pair.converter.blocking_build(self.clone(), local_context.clone(), id)?;
+        let service: Box<TService> = component_service_pair.converter.build(cycled_component)
+            .downcast::<TService>()
+            .expect(&format!("Invalid service cast expected service_id:[{service_id:?}] service_name:[{service_name}], found [{component_cycled_component_pair:?}]", service_name = type_name::<TService>().to_string()));
+
+        self.blocking_decorate::<TService>(Box::into_inner(service), &component_service_pair, &component_cycled_component_pair, id, local_context)
+    }
+
+    pub (crate) fn blocking_resolve_with<TService: Sync + Send + 'static, TArgs: Sync + Send + 'static>(self: &Arc<Self>, args: TArgs, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
+        self.blocking_resolve_with_boxed_args::<TService>(Box::new(args), TypeInfo::from_type::<TArgs>(), id, local_context)
+    }
+
+    // Args boxed, so alias can pass them to target service
+    pub (crate) fn blocking_resolve_with_boxed_args<TService: Sync + Send + 'static>(self: &Arc<Self>, args: Box<dyn Any + Sync + Send>, args_type_info: TypeInfo, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
+        if let Some(alias) = self.blocking_get_alias::<TService>() {
+            return alias.blocking_resolve_with(self, args, args_type_info, id, local_context).map(downcast_alias_result::<TService>);
+        }
+
+        let component_service_pair = match self.blocking_get_contextual_binding::<TService>(&id) {
+            Some(cycled_component_id) => self.cycled_component_service_collection.blocking_read().get_all_by_service_type_with_cycled_component_id::<TService>(cycled_component_id)
+                .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?,
+            None => {
+                let component_service_pairs = self.cycled_component_service_collection.blocking_read().get_default_candidates_by_service_type::<TService>()
+                    .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
+
+                self.blocking_select_candidate::<TService>(component_service_pairs)?
+            },
+        };
+
+        let component_cycled_component_pair = self.component_cycled_components_collection.blocking_read()
+            .get_by_cycled_component_id(&component_service_pair.cycled_component_id)
+            .unwrap_or_else(|| panic!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"));
+
+        let component = self.components.blocking_read().get(&component_cycled_component_pair.component_type_info.type_id)
+            .unwrap_or_else(|| panic!("Component cycled component pair exist but component not found:[{component_cycled_component_pair:?}]"))
+            .clone();
+
+        let expected_args_type_info = component.ctor.args_type_info();
+
+        if expected_args_type_info.as_ref() != Some(&args_type_info) {
+            return Err(BuildDependencyError::ArgsTypeMismatch {
+                component_type_info: component.component_type_info.clone(),
+                expected_args_type_info,
+                found_args_type_info: Some(args_type_info),
+            });
+        }
+
+        #[cfg(feature = "loop-check")]
+        if let DependencyContextId::TypeId(type_info, _) = &id {
+            blocking_check_link(self.clone(), &component.component_type_info, type_info)?;
+        }
+
+        // Component with args registered only as transient, so built component is cycled component
+        let dependency_context = DependencyContext::new_dependency(DependencyContextId::child(&id, component.component_type_info.clone()), self.clone(), local_context.clone());
+        let cycled_component = self.blocking_construct_component(&component, dependency_context, Some(args), &id)?;
+
+        let service: Box<TService> = component_service_pair.converter.build(cycled_component)
+            .downcast::<TService>()
+            .unwrap_or_else(|_| panic!("Invalid service cast expected service_name:[{}], found [{component_cycled_component_pair:?}]", type_name::<TService>()));
+
+        self.blocking_decorate::<TService>(Box::into_inner(service), &component_service_pair, &component_cycled_component_pair, &id, local_context)
+    }
+
+    pub (crate) fn blocking_add_alias<TAlias: Sync + Send + 'static, TTarget: Sync + Send + 'static + CoerceUnsized<TAlias>>(&self) -> AddDependencyResult<()> {
+        let mut aliases_write_guard = self.aliases.blocking_write();
+
+        if aliases_write_guard.contains_key(&TypeId::of::<TAlias>()) {
+            return Err(AddDependencyError::AliasExist { alias_type_info: TypeInfo::from_type::<TAlias>() });
+        }
+
+        // Alias chain can't return to alias, because coercion is possible only from subtype to supertype
+        aliases_write_guard.insert(TypeId::of::<TAlias>(), Arc::new(ServiceAlias::<TAlias, TTarget>::new()));
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn blocking_get_alias<TService: 'static>(&self) -> Option<Arc<dyn IServiceAlias>> {
+        self.aliases.blocking_read().get(&TypeId::of::<TService>()).cloned()
+    }
+
+    pub (crate) fn blocking_add_decorator<TService: Sync + Send + 'static>(&self, decorator: Arc<dyn IServiceDecorator>) {
+        self.decorators.blocking_write().entry(TypeId::of::<TService>()).or_default().push(decorator);
+
+        // Decorated singletons dropped, so new decorator applied on next resolve
+        self.global_context.blocking_write().decorated_singletons.retain(|(_, service_id), _| *service_id != TypeId::of::<TService>());
+    }
+
+    // Wrap built service with decorators, registered for service, in registration order
+    // Service of singleton and context dependent component decorated once and cached with component instance
+    fn blocking_decorate<TService: Sync + Send + 'static>(self: &Arc<Self>, service: TService, component_service_pair: &CycledComponentServicePair, component_cycled_component_pair: &ComponentCycledComponentPair, id: &DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
+        let Some(decorators) = self.decorators.blocking_read().get(&TypeId::of::<TService>()).cloned() else {
+            return Ok(service);
+        };
+
+        let decorated_service_key = (component_service_pair.cycled_component_id, component_service_pair.service_id);
+
+        let decorated_service_ref = match component_cycled_component_pair.life_cycle {
+            LifeCycle::Transient => {
+                let decorated_service = self.blocking_apply_decorators(&decorators, Box::new(service), &component_cycled_component_pair.component_type_info, id, local_context)?;
+                return downcast_decorated_service::<TService>(decorated_service);
+            },
+            LifeCycle::Singleton => self.global_context.blocking_write().decorated_singletons.entry(decorated_service_key).or_default().clone(),
+            LifeCycle::ContextDependent => local_context.decorated_services.blocking_write().entry(decorated_service_key).or_default().clone(),
+        };
+
+        if let Some(decorated_service) = decorated_service_ref.blocking_read().as_ref() {
+            return clone_decorated_service::<TService>(component_service_pair, &**decorated_service);
+        }
+
+        // Decorators run without cache lock, so decorator can resolve services, which use the same cache
+        let decorated_service = self.blocking_apply_decorators(&decorators, Box::new(service), &component_cycled_component_pair.component_type_info, id, local_context)?;
+
+        if !decorated_service.is::<TService>() {
+            return Err(BuildDependencyError::InvalidDecoratedService { service_type_info: TypeInfo::from_type::<TService>() });
+        }
+
+        // Service, decorated by another resolve meanwhile, kept, so shared service decorated only once
+        let mut decorated_service_write_guard = decorated_service_ref.blocking_write();
+        let decorated_service = decorated_service_write_guard.get_or_insert(decorated_service);
+
+        clone_decorated_service::<TService>(component_service_pair, &**decorated_service)
+    }
+
+    fn blocking_apply_decorators(self: &Arc<Self>, decorators: &[Arc<dyn IServiceDecorator>], service: Box<dyn Any + Sync + Send>, component_type_info: &TypeInfo, id: &DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let mut service = service;
+
+        for decorator in decorators.iter() {
+            let dependency_context = DependencyContext::new_dependency(DependencyContextId::child(id, component_type_info.clone()), self.clone(), local_context.clone());
+            service = decorator.blocking_decorate(dependency_context, service)?;
+        }
+
+        Ok(service)
+    }
+
+    pub (crate) fn blocking_add_resolution_source(&self, resolution_source: Arc<dyn ResolutionSource>) {
+        self.resolution_sources.blocking_write().push(resolution_source);
+    }
+
+    // Ask resolution sources in adding order, first source, which produce service, win
+    fn blocking_resolve_from_sources<TService: Sync + Send + 'static>(self: &Arc<Self>, id: &DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Option<TService>> {
+        let resolution_sources = self.resolution_sources.blocking_read().clone();
+
+        if resolution_sources.is_empty() {
+            return Ok(None);
+        }
+
+        // Sources are async, so they called in own runtime
+        let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+
+        let service_type_info = TypeInfo::from_type::<TService>();
+
+        for resolution_source in resolution_sources.iter() {
+            let dependency_context = DependencyContext::new_dependency(id.clone(), self.clone(), local_context.clone());
+            let source_resolution = rt.block_on(resolution_source.resolve(&dependency_context, &service_type_info))?;
+
+            match source_resolution {
+                SourceResolution::NotFound => {},
+                SourceResolution::Instance(instance) => return instance.downcast::<TService>()
+                    .map(|service| Some(Box::into_inner(service)))
+                    .map_err(|_| BuildDependencyError::InvalidSourceInstance { service_type_info }),
+                SourceResolution::Registered => {
+                    // Source, which not registered service, is skipped
+                    let Some(component_service_pairs) = self.cycled_component_service_collection.blocking_read().get_default_candidates_by_service_type::<TService>() else {
+                        continue;
+                    };
+
+                    let component_service_pair = self.blocking_select_candidate::<TService>(component_service_pairs)?;
+
+                    return self.blocking_build_service::<TService>(component_service_pair, id, local_context).map(Some);
+                },
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub (crate) fn blocking_add_interceptor(&self, interceptor: Arc<dyn ResolveInterceptor>) {
+        self.interceptors.blocking_write().push(interceptor);
+    }
+
+    // Call component ctor, wrapped with resolve interceptors
+    pub (crate) fn blocking_construct_component(self: &Arc<Self>, component: &Component, dependency_context: DependencyContext, args: Option<Box<dyn Any + Sync + Send>>, parent: &DependencyContextId) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let interceptors = self.interceptors.blocking_read().clone();
+
+        if interceptors.is_empty() {
+            return Self::blocking_call_ctor(component, dependency_context, args);
+        }
+
+        // Interceptors are async, ctor called outside runtime, because blocking ctor can start own runtime
+        let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+
+        let resolve_info = ResolveInfo {
+            component_type_info: component.component_type_info.clone(),
+            life_cycle: component.life_cycle_type,
+            parent_type_info: match parent {
+                DependencyContextId::TypeId(parent_type_info, _) => Some(parent_type_info.clone()),
+                DependencyContextId::Root => None,
+            },
+        };
+
+        let mut intercepted_result = None;
+        // Only interceptors, which before_resolve called, see result
+        let mut called_interceptors_count = interceptors.len();
+
+        for (index, interceptor) in interceptors.iter().enumerate() {
+            match rt.block_on(interceptor.before_resolve(&resolve_info)) {
+                Ok(None) => {},
+                Ok(Some(instance)) => { intercepted_result = Some(Ok(instance)); called_interceptors_count = index + 1; break; },
+                Err(err) => { intercepted_result = Some(Err(err)); called_interceptors_count = index + 1; break; },
+            }
+        }
+
+        let mut result = match intercepted_result {
+            Some(result) => result,
+            None => Self::blocking_call_ctor(component, dependency_context, args),
+        };
+
+        for interceptor in interceptors[..called_interceptors_count].iter().rev() {
+            result = rt.block_on(interceptor.after_resolve(&resolve_info, result));
+        }
+
+        // Interceptor can return instance of any type, builder expect component type
+        let instance = result?;
+
+        if (*instance).type_id() != component.component_type_info.type_id {
+            return Err(BuildDependencyError::InvalidInterceptedInstance { component_type_info: component.component_type_info.clone() });
+        }
+
+        Ok(instance)
+    }
+
+    // Component with args constructed only by resolve_with
+    #[inline(always)]
+    fn blocking_call_ctor(component: &Component, dependency_context: DependencyContext, args: Option<Box<dyn Any + Sync + Send>>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        match args {
+            Some(args) => component.ctor.blocking_ctor_with_args(dependency_context, args),
+            None => component.ctor.blocking_ctor(dependency_context),
+        }
+    }
+
+    pub (crate) fn blocking_map_component<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static>(self: &Arc<Self>) -> MapComponentResult<Arc<CycledComponentServicePair>> where TComponent: Unsize<TService> {
+        let component_id = TypeId::of::<TComponent>();
+
+        let components_read_guard = self.components.blocking_read();
+        let component = components_read_guard.get(&component_id);
+
+        if component.is_none() {
+            return Err(MapComponentError::ComponentNotFound{
+                component_type_info: TypeInfo::from_type::<TComponent>(),
+                service_type_info: TypeInfo::from_type::<TService>(),
+            });
+        }
+
+        let component = component.unwrap();
+
+        let component_service_pair = match component.life_cycle_type {
+            LifeCycle::Transient => self.cycled_component_service_collection.blocking_write().add_mapping_as_transient::<TComponent, TService>(ServiceMappingOptions::default()),
+            LifeCycle::Singleton => self.cycled_component_service_collection.blocking_write().add_mapping_as_singleton::<TComponent, TService>(ServiceMappingOptions::default()),
+            LifeCycle::ContextDependent => self.cycled_component_service_collection.blocking_write().add_mapping_as_context_dependent::<TComponent, TService>(ServiceMappingOptions::default()),
+        };
+
+        Ok(component_service_pair)
+    }
+
+    pub fn blocking_delete_component<TComponent: 'static>(&self) -> DeleteComponentResult<()> {
+        self.blocking_delete_component_by_type_info(&TypeInfo::from_type::<TComponent>())
+    }
+
+    pub (crate) fn blocking_delete_component_by_type_info(&self, component_type_info: &TypeInfo) -> DeleteComponentResult<()> {
+        // Get cycled_component_service_collection write guard at first, because resolve service start from cycled_component_service_collection read. Here we block resolve before delete
+        // I dont make top RwLock, because in resolve time we can add new dependency from ctr, that mean write lock and then deadlock, because top level RwLock will be in read lock
+        let mut cycled_component_service_collection_write_guard = self.cycled_component_service_collection.blocking_write();
+
+        self.blocking_delete_component_with_guard(&mut cycled_component_service_collection_write_guard, component_type_info)
+    }
+
+    // Delete component under cycled_component_service_collection write guard, taken by caller
+    fn blocking_delete_component_with_guard(&self, cycled_component_service_collection_write_guard: &mut CycledComponentServiceCollection, component_type_info: &TypeInfo) -> DeleteComponentResult<()> {
+        // We check life cycle & check component existence in one time
+        let life_cycle = self.components.blocking_read().get(&component_type_info.type_id)
+            .ok_or(DeleteComponentError::ComponentNotFound { component_type_info: component_type_info.clone() })?.life_cycle_type;
+
+        if ![LifeCycle::Singleton, LifeCycle::Transient].contains(&life_cycle) {
+            return Err(DeleteComponentError::NotSupportedLifeCycle { component_type_info: component_type_info.clone(), life_cycle })
+        }
+
+        let component = self.components.blocking_write()
+            .remove(&component_type_info.type_id)
+            .unwrap();
+
+        #[cfg(feature = "loop-check")]
+        let _ = self.links.blocking_write().remove(&component_type_info.type_id).unwrap();
+
+        let cycled_component_builder = self.component_cycled_components_collection.blocking_write()
+            .delete_by_component_id(&component_type_info.type_id)
+            .unwrap();
+
+        match component.life_cycle_type {
+            // Singleton mayby not exist, because not requested
+            LifeCycle::Singleton => self.global_context.blocking_write().remove_singleton(&cycled_component_builder.cycled_component_type_info.type_id),
+            LifeCycle::ContextDependent => panic!("Failed life cycle check before delete, life cycle:[{life_cycle:?}]"),
+            _ => {}
+        }
+
+        _ = cycled_component_service_collection_write_guard.delete_cycled_component(&cycled_component_builder.cycled_component_type_info.type_id)
+            .unwrap();
+        
+        Ok(())
+    }
+
+    // Components with any mapping tagged, deleted only if all of them support delete
+    pub (crate) fn blocking_delete_components_with_tag(&self, tag: &str) -> DeleteComponentResult<Vec<TypeInfo>> {
+        // Same guard for check and delete, so tagged components can't be changed between them
+        let mut cycled_component_service_collection_write_guard = self.cycled_component_service_collection.blocking_write();
+
+        let mut component_type_infos: Vec<TypeInfo> = Vec::new();
+
+        {
+            let component_cycled_components_collection_read_guard = self.component_cycled_components_collection.blocking_read();
+
+            for component_service_pair in cycled_component_service_collection_write_guard.cycled_components_search_idx.values().flatten() {
+                if !component_service_pair.metadata.tag(tag) {
+                    continue;
+                }
+
+                let component_type_info = &component_cycled_components_collection_read_guard.get_by_cycled_component_id(&component_service_pair.cycled_component_id)
+                    .unwrap_or_else(|| panic!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"))
+                    .component_type_info;
+
+                if !component_type_infos.contains(component_type_info) {
+                    component_type_infos.push(component_type_info.clone());
+                }
+            }
+        }
+
+        {
+            let components_read_guard = self.components.blocking_read();
+
+            for component_type_info in component_type_infos.iter() {
+                let life_cycle = components_read_guard.get(&component_type_info.type_id)
+                    .ok_or(DeleteComponentError::ComponentNotFound { component_type_info: component_type_info.clone() })?.life_cycle_type;
+
+                if ![LifeCycle::Singleton, LifeCycle::Transient].contains(&life_cycle) {
+                    return Err(DeleteComponentError::NotSupportedLifeCycle { component_type_info: component_type_info.clone(), life_cycle })
+                }
+            }
+        }
+
+        for component_type_info in component_type_infos.iter() {
+            self.blocking_delete_component_with_guard(&mut cycled_component_service_collection_write_guard, component_type_info)
+                .unwrap_or_else(|err| panic!("Component checked before delete, but delete failed:[{err:?}]"));
+        }
+
+        Ok(component_type_infos)
+    }
+
+    #[inline(always)]
+    pub (crate) fn blocking_is_service_exist(&self, service_type_id: TypeId) -> bool {
+        // Alias exist, if alias target exist
+        let mut service_type_id = service_type_id;
+        while let Some(alias) = self.aliases.blocking_read().get(&service_type_id) {
+            service_type_id = alias.target_type_info().type_id;
+        }
+
+        self.cycled_component_service_collection.blocking_read().is_service_exist(&service_type_id)
+    }
+
+    #[inline(always)]
+    pub (crate) fn blocking_is_named_service_exist(&self, service_type_id: TypeId, name: &str) -> bool {
+        self.cycled_component_service_collection.blocking_read().is_named_service_exist(&service_type_id, name)
+    }
+
+    #[inline(always)]
+    pub (crate) fn blocking_is_component_exist(&self, component_type_id: TypeId) -> bool {
+        self.components.blocking_read().contains_key(&component_type_id)
+    }
+
+    #[inline(always)]
+    pub (crate) fn blocking_is_module_installed(&self, module_type_id: TypeId) -> bool {
+        self.modules.blocking_read().contains_key(&module_type_id)
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl CoreContext {
+    pub (crate) fn register<TComponent: Sync + Send + 'static>(self: &Arc<Self>, ctor: Box<dyn ITypeConstructor>, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        let component = Component::new::<TComponent>(life_cycle.clone(), ctor);
+
+        let component_id = component.component_type_info.type_id.clone();
+
+        // Проверяем наличие зависимости, если нет добавляем
+        let mut components_guard = self.components.write().unwrap();
+        
+        // Other component can own same cycled component, like transient Arc<T> and singleton T
+        if components_guard.contains_key(&component.component_type_info.type_id)
+            || self.component_cycled_components_collection.read().unwrap().get_by_cycled_component_id(&cycled_component_type_id::<TComponent>(&life_cycle)).is_some() {
+            return Err(AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<TComponent>() });
+        }
+   
+        components_guard.insert(component_id.clone(), Arc::new(component));
+        //---------------------------
+
+        // Создаем ячейку свзяей без связей //TODO: в линках брать реальный ид
+        #[cfg(feature = "loop-check")]
+        self.links.write().unwrap().insert(component_id, DependencyLink::new());
+        //---------------------------
+
+        // Пустой маппинг сомпонента
+        match life_cycle {
+            LifeCycle::Transient => self.cycled_component_service_collection.write().unwrap().add_mapping_as_self::<TComponent>(),
+            LifeCycle::Singleton => self.cycled_component_service_collection.write().unwrap().add_mapping_as_shared_self::<Arc<TComponent>>(),
+            LifeCycle::ContextDependent => self.cycled_component_service_collection.write().unwrap().add_mapping_as_shared_self::<Weak<TComponent>>(),
+        }
+        //---------------------------
+
+        // Регистрируем обработчик лайфтайма компонента
+        match life_cycle {
+            LifeCycle::Transient => self.component_cycled_components_collection.write().unwrap().add_transient_cycle_builder::<TComponent>(),
+            LifeCycle::Singleton => self.component_cycled_components_collection.write().unwrap().add_singleton_cycle_builder::<TComponent>(),
+            LifeCycle::ContextDependent => self.component_cycled_components_collection.write().unwrap().add_context_dependent_cycle_builder::<TComponent>(),
+        };
+        //---------------------------
+
+        Ok(ServiceMappingBuilder::new(self.clone()))
+    }
+
+    // Replace component ctor, or register component if not exist. Return builder and flag, component replaced
+    // Get cycled_component_service_collection write guard at first, like in delete, to block resolve while component replaced
+    pub (crate) fn register_or_replace<TComponent: Sync + Send + 'static>(self: &Arc<Self>, ctor: Box<dyn ITypeConstructor>, life_cycle: LifeCycle) -> AddDependencyResult<(ServiceMappingBuilder<TComponent>, bool)> {
+        // Replaced component is not owned by installed module anymore, modules guard taken at first like in delete_module
+        let mut modules_write_guard = self.modules.write().unwrap();
+        let mut cycled_component_service_collection_write_guard = self.cycled_component_service_collection.write().unwrap();
+        let mut component_cycled_components_collection_write_guard = self.component_cycled_components_collection.write().unwrap();
+
+        let component_id = TypeId::of::<TComponent>();
+
+        // Other component can own same cycled component, like transient Arc<T> and singleton T
+        if component_cycled_components_collection_write_guard.get_by_cycled_component_id(&cycled_component_type_id::<TComponent>(&life_cycle))
+            .is_some_and(|component_cycled_component_pair| component_cycled_component_pair.component_type_info.type_id != component_id) {
+            return Err(AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<TComponent>() });
+        }
+
+        let component = Component::new::<TComponent>(life_cycle, ctor);
+
+        let old_component = self.components.write().unwrap().insert(component_id, Arc::new(component));
+
+        let Some(old_component) = old_component else {
+            #[cfg(feature = "loop-check")]
+            self.links.write().unwrap().insert(component_id, DependencyLink::new());
+
+            add_life_cycle::<TComponent>(&mut cycled_component_service_collection_write_guard, &mut component_cycled_components_collection_write_guard, &life_cycle);
+
+            return Ok((ServiceMappingBuilder::new(self.clone()), false));
+        };
+
+        for installed_module in modules_write_guard.values_mut() {
+            installed_module.components.retain(|component_type_info| component_type_info.type_id != component_id);
+        }
+
+        // New ctor can have other dependencies
+        #[cfg(feature = "loop-check")]
+        reset_child_links(&mut *self.links.write().unwrap(), &component_id);
+
+        if old_component.life_cycle_type == LifeCycle::Singleton {
+            self.global_context.write().unwrap().remove_singleton(&TypeId::of::<Arc<TComponent>>());
+        }
+
+        // Service mappings depend on life cycle, so mappings can be kept only with same life cycle
+        if old_component.life_cycle_type != life_cycle {
+            let cycled_component_builder = component_cycled_components_collection_write_guard.delete_by_component::<TComponent>()
+                .unwrap_or_else(|| panic!("Component exist but component cycled component pair not found:[{:?}]", TypeInfo::from_type::<TComponent>()));
+
+            _ = cycled_component_service_collection_write_guard.delete_cycled_component(&cycled_component_builder.cycled_component_type_info.type_id);
+
+            add_life_cycle::<TComponent>(&mut cycled_component_service_collection_write_guard, &mut component_cycled_components_collection_write_guard, &life_cycle);
+        }
+
+        Ok((ServiceMappingBuilder::new(self.clone()), true))
+    }
+
+    pub (crate) fn resolve<'a, TService: Sync + Send + 'static>(self: &Arc<Self>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService>{//std::pin::Pin<Box<dyn std::future::Future<Output = BuildDependencyResult<TService>> + Send + Sync + 'a>> {
+        if let Some(alias) = self.get_alias::<TService>() {
+            return alias.resolve(self, id, local_context).map(downcast_alias_result::<TService>);
+        }
+
+        if let Some(cycled_component_id) = self.get_contextual_binding::<TService>(&id) {
+            return self.resolve_by_type_id::<TService>(cycled_component_id, id, local_context);
+        }
+
+        let Some(component_service_pairs) = self.cycled_component_service_collection.read().unwrap().get_default_candidates_by_service_type::<TService>() else {
+            return self.resolve_from_sources::<TService>(&id, local_context)?
+                .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() });
+        };
+
+        let component_service_pair = self.select_candidate::<TService>(component_service_pairs)?;
+
+        self.build_service::<TService>(component_service_pair, &id, local_context)
+    }
+
+    // Without strict resolve take first registered candidate, otherwise candidate must be single
+    fn select_candidate<TService: 'static>(&self, mut component_service_pairs: Vec<Arc<CycledComponentServicePair>>) -> BuildDependencyResult<Arc<CycledComponentServicePair>> {
+        if self.settings.strict_resolve && component_service_pairs.len() > 1 {
+            let component_cycled_components_collection_read_guard = self.component_cycled_components_collection.read().unwrap();
+
+            let candidates = component_service_pairs.iter()
+                .map(|component_service_pair| component_cycled_components_collection_read_guard.get_by_cycled_component_id(&component_service_pair.cycled_component_id)
+                    .expect(&format!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"))
+                    .component_type_info.clone()
+                )
+                .collect();
+
+            return Err(BuildDependencyError::Ambiguous { service: TypeInfo::from_type::<TService>(), candidates });
+        }
+
+        Ok(component_service_pairs.swap_remove(0))
+    }
+
+    // Not registered service return None, but construction errors propagated
+    pub (crate) fn try_resolve<TService: Sync + Send + 'static>(self: &Arc<Self>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Option<TService>> {
+        if let Some(alias) = self.get_alias::<TService>() {
+            return alias.try_resolve(self, id, local_context).map(downcast_alias_result::<Option<TService>>);
+        }
+
+        if let Some(cycled_component_id) = self.get_contextual_binding::<TService>(&id) {
+            let Some(component_service_pair) = self.cycled_component_service_collection.read().unwrap().get_all_by_service_type_with_cycled_component_id::<TService>(cycled_component_id) else {
+                return Ok(None);
+            };
+
+            return self.build_service::<TService>(component_service_pair, &id, local_context).map(Some);
+        }
+
+        let Some(component_service_pairs) = self.cycled_component_service_collection.read().unwrap().get_default_candidates_by_service_type::<TService>() else {
+            return self.resolve_from_sources::<TService>(&id, local_context);
+        };
+
+        let component_service_pair = self.select_candidate::<TService>(component_service_pairs)?;
+
+        self.build_service::<TService>(component_service_pair, &id, local_context).map(Some)
+    }
+
+    // Cycled component bound to service for parent component
+    #[inline(always)]
+    fn get_contextual_binding<TService: 'static>(&self, id: &DependencyContextId) -> Option<TypeId> {
+        let DependencyContextId::TypeId(parent_type_info, _) = id else {
+            return None;
+        };
+
+        self.contextual_bindings.read().unwrap().get(&(parent_type_info.type_id, TypeId::of::<TService>())).map(|binding| binding.cycled_component_id)
+    }
+
+    pub (crate) fn resolve_named<TService: Sync + Send + 'static>(self: &Arc<Self>, name: &str, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
+        let component_service_pair = self.cycled_component_service_collection.read().unwrap().get_by_service_type_and_name::<TService>(name)
+            .ok_or(BuildDependencyError::NamedNotFound{ type_info: TypeInfo::from_type::<TService>(), name: name.to_string() })?;
+
+        self.build_service::<TService>(component_service_pair, &id, local_context)
+    }
 
-        let component_id = component.component_type_info.type_id.clone();
+    pub (crate) fn resolve_by_type_id<TService: Sync + Send + 'static>(self: &Arc<Self>, component_type_id: TypeId, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
+        // Contextual binding allow only bound component for parent
+        if self.get_contextual_binding::<TService>(&id).is_some_and(|cycled_component_id| cycled_component_id != component_type_id) {
+            return Err(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() });
+        }
 
-        // Проверяем наличие зависимости, если нет добавляем
-        let mut components_guard = self.components.write().unwrap();
-        
-        if components_guard.contains_key(&component.component_type_info.type_id) {
-            return Err(AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<TComponent>() });
+        let component_service_pair = self.cycled_component_service_collection.read().unwrap().get_all_by_service_type_with_cycled_component_id::<TService>(component_type_id)
+            .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
+
+        self.build_service::<TService>(component_service_pair, &id, local_context)
+    }
+
+    pub (crate) fn resolve_collection<TService: Sync + Send + 'static>(self: &Arc<Self>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Vec<TService>> {
+        if let Some(alias) = self.get_alias::<TService>() {
+            return alias.resolve_collection(self, id, local_context).map(downcast_alias_result::<Vec<TService>>);
         }
-   
-        components_guard.insert(component_id.clone(), Arc::new(component));
-        //---------------------------
 
-        // Создаем ячейку свзяей без связей //TODO: в линках брать реальный ид
-        #[cfg(feature = "loop-check")]
-        self.links.write().unwrap().insert(component_id, DependencyLink::new());
-        //---------------------------
+        // Contextual binding leave single component in collection
+        if let Some(cycled_component_id) = self.get_contextual_binding::<TService>(&id) {
+            return self.resolve_by_type_id::<TService>(cycled_component_id, id, local_context).map(|service| vec![service]);
+        }
 
-        // Пустой маппинг сомпонента
-        match life_cycle {
-            LifeCycle::Transient => self.cycled_component_service_collection.write().unwrap().add_mapping_as_self::<TComponent>(),
-            LifeCycle::Singleton => self.cycled_component_service_collection.write().unwrap().add_mapping_as_self::<Arc<TComponent>>(),
-            LifeCycle::ContextDependent => self.cycled_component_service_collection.write().unwrap().add_mapping_as_self::<Weak<TComponent>>(),
+        // Not registered service can be produced by resolution source, as single service collection
+        let Some(component_service_pairs) = self.cycled_component_service_collection.read().unwrap().get_all_by_service_type::<TService>() else {
+            return self.resolve_from_sources::<TService>(&id, local_context)?
+                .map(|service| vec![service])
+                .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() });
+        };
+
+        let mut result = Vec::new();
+        for component_service_pair in component_service_pairs.into_iter() {
+            result.push(self.build_service::<TService>(component_service_pair, &id, local_context.clone())?);
         }
-        //---------------------------
 
-        // Регистрируем обработчик лайфтайма компонента
-        match life_cycle {
-            LifeCycle::Transient => self.component_cycled_components_collection.write().unwrap().add_transient_cycle_builder::<TComponent>(),
-            LifeCycle::Singleton => self.component_cycled_components_collection.write().unwrap().add_singleton_cycle_builder::<TComponent>(),
-            LifeCycle::ContextDependent => self.component_cycled_components_collection.write().unwrap().add_context_dependent_cycle_builder::<TComponent>(),
+       return Ok(result);
+    }
+
+    pub (crate) fn resolve_collection_where<TService: Sync + Send + 'static, TFilter: Fn(&ServiceMetadata) -> bool + Sync + Send>(self: &Arc<Self>, filter: TFilter, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Vec<TService>> {
+        if let Some(alias) = self.get_alias::<TService>() {
+            return alias.resolve_collection_where(self, &filter, id, local_context).map(downcast_alias_result::<Vec<TService>>);
+        }
+
+        // Contextual binding leave single component in collection, if it match filter
+        if let Some(cycled_component_id) = self.get_contextual_binding::<TService>(&id) {
+            let component_service_pair = self.cycled_component_service_collection.read().unwrap().get_all_by_service_type_with_cycled_component_id::<TService>(cycled_component_id)
+                .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
+
+            if !filter(&component_service_pair.metadata) {
+                return Ok(Vec::new());
+            }
+
+            return self.build_service::<TService>(component_service_pair, &id, local_context).map(|service| vec![service]);
+        }
+
+        let component_service_pairs = self.cycled_component_service_collection.read().unwrap().get_all_by_service_type::<TService>()
+            .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
+
+        let mut result = Vec::new();
+        for component_service_pair in component_service_pairs.into_iter().filter(|component_service_pair| filter(&component_service_pair.metadata)) {
+            result.push(self.build_service::<TService>(component_service_pair, &id, local_context.clone())?);
+        }
+
+        Ok(result)
+    }
+
+    // Same order as resolve_collection, but without construction
+    pub (crate) fn get_collection_metadata<TService: 'static>(&self) -> Vec<ServiceInfo> {
+        let Some(component_service_pairs) = self.cycled_component_service_collection.read().unwrap().get_all_by_service_type::<TService>() else {
+            return Vec::new();
         };
-        //---------------------------
 
-        Ok(ServiceMappingBuilder::new(self.clone()))
+        let component_cycled_components_collection_read_guard = self.component_cycled_components_collection.read().unwrap();
+
+        component_service_pairs.iter()
+            .map(|component_service_pair| ServiceInfo {
+                component_type_info: component_cycled_components_collection_read_guard.get_by_cycled_component_id(&component_service_pair.cycled_component_id)
+                    .unwrap_or_else(|| panic!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"))
+                    .component_type_info.clone(),
+                metadata: component_service_pair.metadata.clone(),
+            })
+            .collect()
     }
 
-    pub (crate) fn resolve<'a, TService: Sync + Send + 'static>(self: &Arc<Self>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService>{//std::pin::Pin<Box<dyn std::future::Future<Output = BuildDependencyResult<TService>> + Send + Sync + 'a>> {
+    // Common part of all resolve ways, build component with life cycle and convert it to service
+    fn build_service<TService: Sync + Send + 'static>(self: &Arc<Self>, component_service_pair: Arc<CycledComponentServicePair>, id: &DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
         let service_id = TypeId::of::<TService>();
 
-        let component_service_pair = self.cycled_component_service_collection.read().unwrap().get_nth_by_service_type::<TService>(0)
-            .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
-    
         let component_cycled_component_pair = self.component_cycled_components_collection.read().unwrap()
             .get_by_cycled_component_id(&component_service_pair.cycled_component_id)
             .expect(&format!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"));
-        
+
         #[cfg(feature = "loop-check")]
-        if let DependencyContextId::TypeId(type_info) = &id {
+        if let DependencyContextId::TypeId(type_info, _) = id {
             // Link created on dependency add, we need take link for dependency, not cycled dependency or service
             check_link(self.clone(), &component_cycled_component_pair.component_type_info, type_info)?;
         }
-    
-        let cycled_component = component_cycled_component_pair.converter.build(self.clone(), local_context)?;
+
+        let cycled_component = component_cycled_component_pair.converter.build(self.clone(), local_context.clone(), id)?;
         let service: Box<TService> = component_service_pair.converter.build(cycled_component)
             .downcast::<TService>()
             .expect(&format!("Invalid service cast expected service_id:[{service_id:?}] service_name:[{service_name}], found [{component_cycled_component_pair:?}]", service_name = type_name::<TService>().to_string()));
 
-        Ok(Box::into_inner(service))
+        self.decorate::<TService>(Box::into_inner(service), &component_service_pair, &component_cycled_component_pair, id, local_context)
     }
 
-    pub (crate) fn resolve_by_type_id<TService: Sync + Send + 'static>(self: &Arc<Self>, component_type_id: TypeId, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
-        let service_id = TypeId::of::<TService>();
+    pub (crate) fn resolve_with<TService: Sync + Send + 'static, TArgs: Sync + Send + 'static>(self: &Arc<Self>, args: TArgs, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
+        self.resolve_with_boxed_args::<TService>(Box::new(args), TypeInfo::from_type::<TArgs>(), id, local_context)
+    }
 
-        let component_service_pair = self.cycled_component_service_collection.read().unwrap().get_all_by_service_type_with_cycled_component_id::<TService>(component_type_id)
-            .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
+    // Args boxed, so alias can pass them to target service
+    pub (crate) fn resolve_with_boxed_args<TService: Sync + Send + 'static>(self: &Arc<Self>, args: Box<dyn Any + Sync + Send>, args_type_info: TypeInfo, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
+        if let Some(alias) = self.get_alias::<TService>() {
+            return alias.resolve_with(self, args, args_type_info, id, local_context).map(downcast_alias_result::<TService>);
+        }
+
+        let component_service_pair = match self.get_contextual_binding::<TService>(&id) {
+            Some(cycled_component_id) => self.cycled_component_service_collection.read().unwrap().get_all_by_service_type_with_cycled_component_id::<TService>(cycled_component_id)
+                .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?,
+            None => {
+                let component_service_pairs = self.cycled_component_service_collection.read().unwrap().get_default_candidates_by_service_type::<TService>()
+                    .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
+
+                self.select_candidate::<TService>(component_service_pairs)?
+            },
+        };
 
         let component_cycled_component_pair = self.component_cycled_components_collection.read().unwrap()
             .get_by_cycled_component_id(&component_service_pair.cycled_component_id)
-            .expect(&format!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"));
+            .unwrap_or_else(|| panic!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"));
+
+        let component = self.components.read().unwrap().get(&component_cycled_component_pair.component_type_info.type_id)
+            .unwrap_or_else(|| panic!("Component cycled component pair exist but component not found:[{component_cycled_component_pair:?}]"))
+            .clone();
+
+        let expected_args_type_info = component.ctor.args_type_info();
+
+        if expected_args_type_info.as_ref() != Some(&args_type_info) {
+            return Err(BuildDependencyError::ArgsTypeMismatch {
+                component_type_info: component.component_type_info.clone(),
+                expected_args_type_info,
+                found_args_type_info: Some(args_type_info),
+            });
+        }
 
         #[cfg(feature = "loop-check")]
-        if let DependencyContextId::TypeId(type_info) = &id {
-            // Link created on dependency add, we need take link for dependency, not cycled dependency or service
-            check_link(self.clone(), &component_cycled_component_pair.component_type_info, type_info)?;
+        if let DependencyContextId::TypeId(type_info, _) = &id {
+            check_link(self.clone(), &component.component_type_info, type_info)?;
         }
 
-        let cycled_component = component_cycled_component_pair.converter.build(self.clone(), local_context)?;
+        // Component with args registered only as transient, so built component is cycled component
+        let dependency_context = DependencyContext::new_dependency(DependencyContextId::child(&id, component.component_type_info.clone()), self.clone(), local_context.clone());
+        let cycled_component = self.construct_component(&component, dependency_context, Some(args), &id)?;
+
         let service: Box<TService> = component_service_pair.converter.build(cycled_component)
             .downcast::<TService>()
-            .expect(&format!("Invalid service cast expected service_id:[{service_id:?}] service_name:[{service_name}], found [{component_cycled_component_pair:?}]", service_name = type_name::<TService>().to_string()));
+            .unwrap_or_else(|_| panic!("Invalid service cast expected service_name:[{}], found [{component_cycled_component_pair:?}]", type_name::<TService>()));
 
-        return Ok(Box::into_inner(service));
+        self.decorate::<TService>(Box::into_inner(service), &component_service_pair, &component_cycled_component_pair, &id, local_context)
     }
 
-    pub (crate) fn resolve_collection<TService: Sync + Send + 'static>(self: &Arc<Self>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Vec<TService>> {
-        let service_id = TypeId::of::<TService>();
+    pub (crate) fn add_alias<TAlias: Sync + Send + 'static, TTarget: Sync + Send + 'static + CoerceUnsized<TAlias>>(&self) -> AddDependencyResult<()> {
+        let mut aliases_write_guard = self.aliases.write().unwrap();
 
-        let component_service_pairs = self.cycled_component_service_collection.read().unwrap().get_all_by_service_type::<TService>()
-            .ok_or(BuildDependencyError::NotFound{ type_info: TypeInfo::from_type::<TService>() })?;
+        if aliases_write_guard.contains_key(&TypeId::of::<TAlias>()) {
+            return Err(AddDependencyError::AliasExist { alias_type_info: TypeInfo::from_type::<TAlias>() });
+        }
 
-        let mut result = Vec::new();
-        for component_service_pair in component_service_pairs.iter() {
-            let component_cycled_component_pair = self.component_cycled_components_collection.read().unwrap()
-                .get_by_cycled_component_id(&component_service_pair.cycled_component_id)
-                .expect(&format!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"));
+        // Alias chain can't return to alias, because coercion is possible only from subtype to supertype
+        aliases_write_guard.insert(TypeId::of::<TAlias>(), Arc::new(ServiceAlias::<TAlias, TTarget>::new()));
 
-            #[cfg(feature = "loop-check")]
-            if let DependencyContextId::TypeId(type_info) = &id {
-                // Link created on dependency add, we need take link for dependency, not cycled dependency or service
-                check_link(self.clone(), &component_cycled_component_pair.component_type_info, type_info)?;
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn get_alias<TService: 'static>(&self) -> Option<Arc<dyn IServiceAlias>> {
+        self.aliases.read().unwrap().get(&TypeId::of::<TService>()).cloned()
+    }
+
+    pub (crate) fn add_decorator<TService: Sync + Send + 'static>(&self, decorator: Arc<dyn IServiceDecorator>) {
+        self.decorators.write().unwrap().entry(TypeId::of::<TService>()).or_default().push(decorator);
+
+        // Decorated singletons dropped, so new decorator applied on next resolve
+        self.global_context.write().unwrap().decorated_singletons.retain(|(_, service_id), _| *service_id != TypeId::of::<TService>());
+    }
+
+    // Wrap built service with decorators, registered for service, in registration order
+    // Service of singleton and context dependent component decorated once and cached with component instance
+    fn decorate<TService: Sync + Send + 'static>(self: &Arc<Self>, service: TService, component_service_pair: &CycledComponentServicePair, component_cycled_component_pair: &ComponentCycledComponentPair, id: &DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<TService> {
+        let Some(decorators) = self.decorators.read().unwrap().get(&TypeId::of::<TService>()).cloned() else {
+            return Ok(service);
+        };
+
+        let decorated_service_key = (component_service_pair.cycled_component_id, component_service_pair.service_id);
+
+        let decorated_service_ref = match component_cycled_component_pair.life_cycle {
+            LifeCycle::Transient => {
+                let decorated_service = self.apply_decorators(&decorators, Box::new(service), &component_cycled_component_pair.component_type_info, id, local_context)?;
+                return downcast_decorated_service::<TService>(decorated_service);
+            },
+            LifeCycle::Singleton => self.global_context.write().unwrap().decorated_singletons.entry(decorated_service_key).or_default().clone(),
+            LifeCycle::ContextDependent => local_context.decorated_services.write().unwrap().entry(decorated_service_key).or_default().clone(),
+        };
+
+        if let Some(decorated_service) = decorated_service_ref.read().unwrap().as_ref() {
+            return clone_decorated_service::<TService>(component_service_pair, &**decorated_service);
+        }
+
+        // Decorators run without cache lock, so decorator can resolve services, which use the same cache
+        let decorated_service = self.apply_decorators(&decorators, Box::new(service), &component_cycled_component_pair.component_type_info, id, local_context)?;
+
+        if !decorated_service.is::<TService>() {
+            return Err(BuildDependencyError::InvalidDecoratedService { service_type_info: TypeInfo::from_type::<TService>() });
+        }
+
+        // Service, decorated by another resolve meanwhile, kept, so shared service decorated only once
+        let mut decorated_service_write_guard = decorated_service_ref.write().unwrap();
+        let decorated_service = decorated_service_write_guard.get_or_insert(decorated_service);
+
+        clone_decorated_service::<TService>(component_service_pair, &**decorated_service)
+    }
+
+    fn apply_decorators(self: &Arc<Self>, decorators: &[Arc<dyn IServiceDecorator>], service: Box<dyn Any + Sync + Send>, component_type_info: &TypeInfo, id: &DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let mut service = service;
+
+        for decorator in decorators.iter() {
+            let dependency_context = DependencyContext::new_dependency(DependencyContextId::child(id, component_type_info.clone()), self.clone(), local_context.clone());
+            service = decorator.decorate(dependency_context, service)?;
+        }
+
+        Ok(service)
+    }
+
+    pub (crate) fn add_resolution_source(&self, resolution_source: Arc<dyn ResolutionSource>) {
+        self.resolution_sources.write().unwrap().push(resolution_source);
+    }
+
+    // Ask resolution sources in adding order, first source, which produce service, win
+    fn resolve_from_sources<TService: Sync + Send + 'static>(self: &Arc<Self>, id: &DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Option<TService>> {
+        let resolution_sources = self.resolution_sources.read().unwrap().clone();
+
+        if resolution_sources.is_empty() {
+            return Ok(None);
+        }
+
+        let service_type_info = TypeInfo::from_type::<TService>();
+
+        for resolution_source in resolution_sources.iter() {
+            let dependency_context = DependencyContext::new_dependency(id.clone(), self.clone(), local_context.clone());
+            let source_resolution = resolution_source.resolve(&dependency_context, &service_type_info)?;
+
+            match source_resolution {
+                SourceResolution::NotFound => {},
+                SourceResolution::Instance(instance) => return instance.downcast::<TService>()
+                    .map(|service| Some(Box::into_inner(service)))
+                    .map_err(|_| BuildDependencyError::InvalidSourceInstance { service_type_info }),
+                SourceResolution::Registered => {
+                    // Source, which not registered service, is skipped
+                    let Some(component_service_pairs) = self.cycled_component_service_collection.read().unwrap().get_default_candidates_by_service_type::<TService>() else {
+                        continue;
+                    };
+
+                    let component_service_pair = self.select_candidate::<TService>(component_service_pairs)?;
+
+                    return self.build_service::<TService>(component_service_pair, id, local_context).map(Some);
+                },
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub (crate) fn add_interceptor(&self, interceptor: Arc<dyn ResolveInterceptor>) {
+        self.interceptors.write().unwrap().push(interceptor);
+    }
+
+    // Call component ctor, wrapped with resolve interceptors
+    pub (crate) fn construct_component(self: &Arc<Self>, component: &Component, dependency_context: DependencyContext, args: Option<Box<dyn Any + Sync + Send>>, parent: &DependencyContextId) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let interceptors = self.interceptors.read().unwrap().clone();
+
+        if interceptors.is_empty() {
+            return Self::call_ctor(component, dependency_context, args);
+        }
+
+        let resolve_info = ResolveInfo {
+            component_type_info: component.component_type_info.clone(),
+            life_cycle: component.life_cycle_type,
+            parent_type_info: match parent {
+                DependencyContextId::TypeId(parent_type_info, _) => Some(parent_type_info.clone()),
+                DependencyContextId::Root => None,
+            },
+        };
+
+        let mut intercepted_result = None;
+        // Only interceptors, which before_resolve called, see result
+        let mut called_interceptors_count = interceptors.len();
+
+        for (index, interceptor) in interceptors.iter().enumerate() {
+            match interceptor.before_resolve(&resolve_info) {
+                Ok(None) => {},
+                Ok(Some(instance)) => { intercepted_result = Some(Ok(instance)); called_interceptors_count = index + 1; break; },
+                Err(err) => { intercepted_result = Some(Err(err)); called_interceptors_count = index + 1; break; },
             }
+        }
 
-            let cycled_component = component_cycled_component_pair.converter.build(self.clone(), local_context.clone())?;
-            let service: Box<TService> = component_service_pair.converter.build(cycled_component)
-                .downcast::<TService>()
-                .expect(&format!("Invalid service cast expected service_id:[{service_id:?}] service_name:[{service_name}], found [{component_cycled_component_pair:?}]", service_name = type_name::<TService>().to_string()));
+        let mut result = match intercepted_result {
+            Some(result) => result,
+            None => Self::call_ctor(component, dependency_context, args),
+        };
 
-            result.push(Box::into_inner(service));
+        for interceptor in interceptors[..called_interceptors_count].iter().rev() {
+            result = interceptor.after_resolve(&resolve_info, result);
         }
 
-       return Ok(result);
+        // Interceptor can return instance of any type, builder expect component type
+        let instance = result?;
+
+        if (*instance).type_id() != component.component_type_info.type_id {
+            return Err(BuildDependencyError::InvalidInterceptedInstance { component_type_info: component.component_type_info.clone() });
+        }
+
+        Ok(instance)
+    }
+
+    // Component with args constructed only by resolve_with
+    #[inline(always)]
+    fn call_ctor(component: &Component, dependency_context: DependencyContext, args: Option<Box<dyn Any + Sync + Send>>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        match args {
+            Some(args) => component.ctor.ctor_with_args(dependency_context, args),
+            None => component.ctor.ctor(dependency_context),
+        }
     }
 
-    pub fn map_component<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static>(self: &Arc<Self>) -> MapComponentResult<Arc<Self>> where TComponent: Unsize<TService> {
+    pub (crate) fn map_component<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static>(self: &Arc<Self>) -> MapComponentResult<Arc<CycledComponentServicePair>> where TComponent: Unsize<TService> {
         let component_id = TypeId::of::<TComponent>();
 
         let components_read_guard = self.components.read().unwrap();
@@ -588,45 +2049,56 @@ impl CoreContext {
 
         let component = component.unwrap();
 
-        match component.life_cycle_type {
-            LifeCycle::Transient => self.cycled_component_service_collection.write().unwrap().add_mapping_as_transient::<TComponent, TService>(),
-            LifeCycle::Singleton => self.cycled_component_service_collection.write().unwrap().add_mapping_as_singleton::<TComponent, TService>(),
-            LifeCycle::ContextDependent => self.cycled_component_service_collection.write().unwrap().add_mapping_as_context_dependent::<TComponent, TService>(),
+        let component_service_pair = match component.life_cycle_type {
+            LifeCycle::Transient => self.cycled_component_service_collection.write().unwrap().add_mapping_as_transient::<TComponent, TService>(ServiceMappingOptions::default()),
+            LifeCycle::Singleton => self.cycled_component_service_collection.write().unwrap().add_mapping_as_singleton::<TComponent, TService>(ServiceMappingOptions::default()),
+            LifeCycle::ContextDependent => self.cycled_component_service_collection.write().unwrap().add_mapping_as_context_dependent::<TComponent, TService>(ServiceMappingOptions::default()),
         };
 
-        Ok(self.clone())
+        Ok(component_service_pair)
     }
 
     pub fn delete_component<TComponent: 'static>(&self) -> DeleteComponentResult<()> {
+        self.delete_component_by_type_info(&TypeInfo::from_type::<TComponent>())
+    }
+
+    pub (crate) fn delete_component_by_type_info(&self, component_type_info: &TypeInfo) -> DeleteComponentResult<()> {
         // Get cycled_component_service_collection write guard at first, because resolve service start from cycled_component_service_collection read. Here we block resolve before delete
         // I dont make top RwLock, because in resolve time we can add new dependency from ctr, that mean write lock and then deadlock, because top level RwLock will be in read lock
         let mut cycled_component_service_collection_write_guard = self.cycled_component_service_collection.write().unwrap();
 
+        self.delete_component_with_guard(&mut cycled_component_service_collection_write_guard, component_type_info)
+    }
+
+    // Delete component under cycled_component_service_collection write guard, taken by caller
+    fn delete_component_with_guard(&self, cycled_component_service_collection_write_guard: &mut CycledComponentServiceCollection, component_type_info: &TypeInfo) -> DeleteComponentResult<()> {
         // We check life cycle & check component existence in one time
-        let life_cycle = self.components.read().unwrap().get(&TypeId::of::<TComponent>())
-            .ok_or(DeleteComponentError::ComponentNotFound { component_type_info: TypeInfo::from_type::<TComponent>() })?.life_cycle_type;
+        let life_cycle = self.components.read().unwrap().get(&component_type_info.type_id)
+            .ok_or(DeleteComponentError::ComponentNotFound { component_type_info: component_type_info.clone() })?.life_cycle_type;
 
         if ![LifeCycle::Singleton, LifeCycle::Transient].contains(&life_cycle) {
-            return Err(DeleteComponentError::NotSupportedLifeCycle { component_type_info: TypeInfo::from_type::<TComponent>(), life_cycle })
+            return Err(DeleteComponentError::NotSupportedLifeCycle { component_type_info: component_type_info.clone(), life_cycle })
         }
 
+        self.remove_component_with_guard(cycled_component_service_collection_write_guard, component_type_info)
+    }
+
+    // Remove component of any life cycle, context dependent instances stay cached in local contexts
+    fn remove_component_with_guard(&self, cycled_component_service_collection_write_guard: &mut CycledComponentServiceCollection, component_type_info: &TypeInfo) -> DeleteComponentResult<()> {
         let component = self.components.write().unwrap()
-            .remove(&TypeId::of::<TComponent>())
-            .unwrap();
+            .remove(&component_type_info.type_id)
+            .ok_or(DeleteComponentError::ComponentNotFound { component_type_info: component_type_info.clone() })?;
 
         #[cfg(feature = "loop-check")]
-        let _ = self.links.write().unwrap().remove(&TypeId::of::<TComponent>()).unwrap();
+        let _ = self.links.write().unwrap().remove(&component_type_info.type_id).unwrap();
 
         let cycled_component_builder = self.component_cycled_components_collection.write().unwrap()
-            .delete_by_component::<TComponent>()
+            .delete_by_component_id(&component_type_info.type_id)
             .unwrap();
 
-        match component.life_cycle_type {
-            // Singleton mayby not exist, because not requested
-            LifeCycle::Singleton => _ = self.global_context.write().unwrap().singletons
-                    .remove(&cycled_component_builder.cycled_component_type_info.type_id),
-            LifeCycle::ContextDependent => panic!("Failed life cycle check before delete, life cycle:[{life_cycle:?}]"),
-            _ => {}
+        // Singleton mayby not exist, because not requested
+        if component.life_cycle_type == LifeCycle::Singleton {
+            self.global_context.write().unwrap().remove_singleton(&cycled_component_builder.cycled_component_type_info.type_id);
         }
 
         _ = cycled_component_service_collection_write_guard.delete_cycled_component(&cycled_component_builder.cycled_component_type_info.type_id)
@@ -635,17 +2107,248 @@ impl CoreContext {
         Ok(())
     }
 
+    // Components with any mapping tagged, deleted only if all of them support delete
+    pub (crate) fn delete_components_with_tag(&self, tag: &str) -> DeleteComponentResult<Vec<TypeInfo>> {
+        // Same guard for check and delete, so tagged components can't be changed between them
+        let mut cycled_component_service_collection_write_guard = self.cycled_component_service_collection.write().unwrap();
+
+        let mut component_type_infos: Vec<TypeInfo> = Vec::new();
+
+        {
+            let component_cycled_components_collection_read_guard = self.component_cycled_components_collection.read().unwrap();
+
+            for component_service_pair in cycled_component_service_collection_write_guard.cycled_components_search_idx.values().flatten() {
+                if !component_service_pair.metadata.tag(tag) {
+                    continue;
+                }
+
+                let component_type_info = &component_cycled_components_collection_read_guard.get_by_cycled_component_id(&component_service_pair.cycled_component_id)
+                    .unwrap_or_else(|| panic!("Component service pair exist but component cycled component pair not found:[{component_service_pair:?}]"))
+                    .component_type_info;
+
+                if !component_type_infos.contains(component_type_info) {
+                    component_type_infos.push(component_type_info.clone());
+                }
+            }
+        }
+
+        {
+            let components_read_guard = self.components.read().unwrap();
+
+            for component_type_info in component_type_infos.iter() {
+                let life_cycle = components_read_guard.get(&component_type_info.type_id)
+                    .ok_or(DeleteComponentError::ComponentNotFound { component_type_info: component_type_info.clone() })?.life_cycle_type;
+
+                if ![LifeCycle::Singleton, LifeCycle::Transient].contains(&life_cycle) {
+                    return Err(DeleteComponentError::NotSupportedLifeCycle { component_type_info: component_type_info.clone(), life_cycle })
+                }
+            }
+        }
+
+        for component_type_info in component_type_infos.iter() {
+            self.delete_component_with_guard(&mut cycled_component_service_collection_write_guard, component_type_info)
+                .unwrap_or_else(|err| panic!("Component checked before delete, but delete failed:[{err:?}]"));
+        }
+
+        Ok(component_type_infos)
+    }
+
     #[inline(always)]
     pub (crate) fn is_service_exist(&self, service_type_id: TypeId) -> bool {
+        // Alias exist, if alias target exist
+        let mut service_type_id = service_type_id;
+        while let Some(alias) = self.aliases.read().unwrap().get(&service_type_id) {
+            service_type_id = alias.target_type_info().type_id;
+        }
+
         self.cycled_component_service_collection.read().unwrap().is_service_exist(&service_type_id)
     }
 
+    #[inline(always)]
+    pub (crate) fn is_named_service_exist(&self, service_type_id: TypeId, name: &str) -> bool {
+        self.cycled_component_service_collection.read().unwrap().is_named_service_exist(&service_type_id, name)
+    }
+
     #[inline(always)]
     pub (crate) fn is_component_exist(&self, component_type_id: TypeId) -> bool {
         self.components.read().unwrap().contains_key(&component_type_id)
     }
+
+    #[inline(always)]
+    pub (crate) fn is_module_installed(&self, module_type_id: TypeId) -> bool {
+        self.modules.read().unwrap().contains_key(&module_type_id)
+    }
+
+    pub (crate) fn check_module_install(&self, module_type_info: &TypeInfo, dependencies: &[TypeInfo]) -> InstallModuleResult<()> {
+        let modules_read_guard = self.modules.read().unwrap();
+
+        if modules_read_guard.contains_key(&module_type_info.type_id) {
+            return Err(InstallModuleError::ModuleExist { module_type_info: module_type_info.clone() });
+        }
+
+        if let Some(dependency_type_info) = dependencies.iter().find(|dependency_type_info| !modules_read_guard.contains_key(&dependency_type_info.type_id)) {
+            return Err(InstallModuleError::DependencyNotInstalled { module_type_info: module_type_info.clone(), dependency_type_info: dependency_type_info.clone() });
+        }
+
+        Ok(())
+    }
+
+    pub (crate) fn add_module(&self, module_type_info: &TypeInfo, installed_module: InstalledModule) -> InstallModuleResult<()> {
+        let mut modules_write_guard = self.modules.write().unwrap();
+
+        // Same module can be installed in parallel
+        if modules_write_guard.contains_key(&module_type_info.type_id) {
+            return Err(InstallModuleError::ModuleExist { module_type_info: module_type_info.clone() });
+        }
+
+        // Dependency can be uninstalled while module installed
+        if let Some(dependency_type_info) = installed_module.dependencies.iter().find(|dependency_type_info| !modules_write_guard.contains_key(&dependency_type_info.type_id)) {
+            return Err(InstallModuleError::DependencyNotInstalled { module_type_info: module_type_info.clone(), dependency_type_info: dependency_type_info.clone() });
+        }
+
+        // Context dependent instances cached in local contexts, so module can't delete component on uninstall
+        {
+            let components_read_guard = self.components.read().unwrap();
+
+            if let Some(component_type_info) = installed_module.components.iter().find(|component_type_info| components_read_guard.get(&component_type_info.type_id)
+                .is_some_and(|component| component.life_cycle_type == LifeCycle::ContextDependent)) {
+                return Err(InstallModuleError::NotSupportedLifeCycle { module_type_info: module_type_info.clone(), component_type_info: component_type_info.clone(), life_cycle: LifeCycle::ContextDependent });
+            }
+        }
+
+        modules_write_guard.insert(module_type_info.type_id, installed_module);
+
+        Ok(())
+    }
+
+    // Check module can be uninstalled and return module for uninstall hook
+    pub (crate) fn get_module_for_uninstall(&self, module_type_info: &TypeInfo) -> UninstallModuleResult<Arc<dyn DependencyModule>> {
+        let modules_read_guard = self.modules.read().unwrap();
+        let installed_module = check_module_uninstall(&modules_read_guard, module_type_info)?;
+
+        Ok(installed_module.module.clone())
+    }
+
+    pub (crate) fn delete_module(&self, module_type_info: &TypeInfo) -> UninstallModuleResult<()> {
+        // Guards taken in register_or_replace order, so module components can't be replaced until deleted
+        let mut modules_write_guard = self.modules.write().unwrap();
+        let mut cycled_component_service_collection_write_guard = self.cycled_component_service_collection.write().unwrap();
+
+        // Modules can be changed by uninstall hook, so check again
+        check_module_uninstall(&modules_write_guard, module_type_info)?;
+
+        // Module removed before delete, so dependent module can't be installed meanwhile
+        let installed_module = modules_write_guard.remove(&module_type_info.type_id)
+            .unwrap_or_else(|| panic!("Module checked but not found:[{module_type_info:?}]"));
+
+        drop(modules_write_guard);
+
+        // Module components are never context dependent, so only manually deleted component can't be deleted
+        for component_type_info in installed_module.components.iter() {
+            _ = self.delete_component_with_guard(&mut cycled_component_service_collection_write_guard, component_type_info);
+        }
+
+        for component_service_pair in installed_module.mappings.iter() {
+            // Pair can be deleted with component
+            _ = cycled_component_service_collection_write_guard.delete_pair(component_service_pair);
+        }
+
+        Ok(())
+    }
+
+    // Rollback registrations of failed module install, context dependent components are removed too
+    pub (crate) fn delete_module_registrations(&self, components: &[TypeInfo], mappings: &[Arc<CycledComponentServicePair>]) {
+        let mut cycled_component_service_collection_write_guard = self.cycled_component_service_collection.write().unwrap();
+
+        for component_type_info in components.iter() {
+            _ = self.remove_component_with_guard(&mut cycled_component_service_collection_write_guard, component_type_info);
+        }
+
+        for component_service_pair in mappings.iter() {
+            // Pair can be deleted with component
+            _ = cycled_component_service_collection_write_guard.delete_pair(component_service_pair);
+        }
+    }
+}
+
+
+// Module exist and no installed module depends on it
+fn check_module_uninstall<'a>(modules: &'a HashMap<TypeId, InstalledModule>, module_type_info: &TypeInfo) -> UninstallModuleResult<&'a InstalledModule> {
+    let installed_module = modules.get(&module_type_info.type_id)
+        .ok_or(UninstallModuleError::ModuleNotFound { module_type_info: module_type_info.clone() })?;
+
+    if let Some(dependent_module) = modules.values().find(|installed_module| installed_module.dependencies.contains(module_type_info)) {
+        return Err(UninstallModuleError::ModuleRequired { module_type_info: module_type_info.clone(), dependent_module_type_info: dependent_module.module_type_info.clone() });
+    }
+
+    Ok(installed_module)
+}
+
+// Decorator can return service of any type, resolve expect service type
+#[inline(always)]
+fn downcast_decorated_service<TService: 'static>(service: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<TService> {
+    service.downcast::<TService>()
+        .map(|service| Box::into_inner(service))
+        .map_err(|_| BuildDependencyError::InvalidDecoratedService { service_type_info: TypeInfo::from_type::<TService>() })
+}
+
+// Shared service cached decorated, so every resolve get clone of cached service
+#[inline(always)]
+fn clone_decorated_service<TService: 'static>(component_service_pair: &CycledComponentServicePair, decorated_service: &(dyn Any + Sync + Send)) -> BuildDependencyResult<TService> {
+    let decorated_service = component_service_pair.converter.clone_service(decorated_service)
+        .unwrap_or_else(|| panic!("Service of shared component can't be cloned:[{component_service_pair:?}]"));
+
+    downcast_decorated_service::<TService>(decorated_service)
+}
+
+// Cycled component id, which life cycle builder create for component
+#[inline(always)]
+fn cycled_component_type_id<TComponent: 'static>(life_cycle: &LifeCycle) -> TypeId {
+    match life_cycle {
+        LifeCycle::Transient => TypeId::of::<TComponent>(),
+        LifeCycle::Singleton => TypeId::of::<Arc<TComponent>>(),
+        LifeCycle::ContextDependent => TypeId::of::<Weak<TComponent>>(),
+    }
+}
+
+// Empty self mapping and life cycle builder for new component
+#[inline(always)]
+fn add_life_cycle<TComponent: Sync + Send + 'static>(
+    cycled_component_service_collection: &mut CycledComponentServiceCollection,
+    component_cycled_components_collection: &mut ComponentCycledComponentCollection,
+    life_cycle: &LifeCycle
+) {
+    match life_cycle {
+        LifeCycle::Transient => {
+            cycled_component_service_collection.add_mapping_as_self::<TComponent>();
+            component_cycled_components_collection.add_transient_cycle_builder::<TComponent>();
+        },
+        LifeCycle::Singleton => {
+            cycled_component_service_collection.add_mapping_as_shared_self::<Arc<TComponent>>();
+            component_cycled_components_collection.add_singleton_cycle_builder::<TComponent>();
+        },
+        LifeCycle::ContextDependent => {
+            cycled_component_service_collection.add_mapping_as_shared_self::<Weak<TComponent>>();
+            component_cycled_components_collection.add_context_dependent_cycle_builder::<TComponent>();
+        },
+    }
 }
 
+// Remove component links to childs, parents links kept, because parents still depend on component
+#[cfg(feature = "loop-check")]
+#[inline(always)]
+fn reset_child_links(links: &mut HashMap<TypeId, DependencyLink>, component_id: &TypeId) {
+    let component_links = links.get_mut(component_id)
+        .unwrap_or_else(|| panic!("component dependency link required TypeId:[{component_id:?}]"));
+
+    let childs = std::mem::take(&mut component_links.childs);
+
+    for child_id in childs.iter() {
+        if let Some(child_links) = links.get_mut(child_id) {
+            child_links.parents.retain(|parent_id| parent_id != component_id);
+        }
+    }
+}
 
 #[cfg(all(feature = "loop-check", feature = "async-mode"))]
 #[inline(always)]
@@ -2,6 +2,7 @@ use core::fmt::Debug;
 use core::any::TypeId;
 use std::{
     any::Any,
+    collections::HashMap,
     sync::Arc,
     marker::PhantomData
 };
@@ -11,6 +12,7 @@ use crate::{
         BuildDependencyResult,
         TypeInfo, AnthillRwLock
     },
+    global_context::InstanceSlot,
     core_context::CoreContext,
     LocalContext,
     DependencyContextId,
@@ -19,15 +21,22 @@ use crate::{
 
 #[cfg(not(feature = "async-mode"))]
 pub (crate) trait ICycledComponentBuilder where Self: Debug + Sync + Send + 'static {    
-    fn build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+    fn build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>, parent: &DependencyContextId) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
 }
 
 #[cfg(feature = "async-mode")]
 #[async_trait_with_sync::async_trait(Sync)]
 pub (crate) trait ICycledComponentBuilder where Self: Debug + Sync + Send + 'static {    
-    async fn build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+    async fn build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>, parent: &DependencyContextId) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
     #[cfg(feature = "blocking")]
-    fn blocking_build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+    fn blocking_build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>, parent: &DependencyContextId) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+}
+
+// Remove slot of failed construction, if it not replaced by other resolve
+fn remove_empty_slot(slots: &mut HashMap<TypeId, InstanceSlot>, type_id: &TypeId, slot: &InstanceSlot) {
+    if slots.get(type_id).is_some_and(|current_slot| Arc::ptr_eq(current_slot, slot)) {
+        slots.remove(type_id);
+    }
 }
 
 // ----------- Maby move to self file -----------
@@ -52,150 +61,227 @@ impl<TComponent: Sync + Send + 'static> SingletonComponentBuilder<TComponent> {
 #[cfg(feature = "async-mode")]
 #[async_trait_with_sync::async_trait(Sync)]
 impl<TComponent: Sync + Send + 'static> ICycledComponentBuilder for SingletonComponentBuilder<TComponent> {
-    async fn build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>{
+    async fn build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>, parent: &DependencyContextId) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>{
         let singleton_component_type_id = TypeId::of::<Arc<TComponent>>();
-        let global_context_read_guard = core_context.global_context.read().await;
-    
-        if let Some(singleton_component_instance) = global_context_read_guard.singletons.get(&singleton_component_type_id) {
-            let singleton_component_instance: Arc<TComponent> = singleton_component_instance.read().await.as_ref().unwrap().clone().downcast::<TComponent>()
-                .expect(&format!("Incorrect singleton type expected type_info:[{:?}]", TypeInfo::from_type::<Arc<TComponent>>()));
-            return Ok(Box::new(singleton_component_instance) as Box<dyn Any + Sync + Send>);
-        }
-    
-        drop(global_context_read_guard); // дедлок!!!!!!!!!!!!!!!!!!!
-        let mut global_context_write_guard = core_context.global_context.write().await;
-    
-        if let Some(singleton_component_instance) = global_context_write_guard.singletons.get(&singleton_component_type_id) {
-            let singleton_component_instance: Arc<TComponent> = singleton_component_instance.read().await.as_ref().unwrap().clone().downcast::<TComponent>()
-                .expect(&format!("Incorrect singleton type expected type_info:[{:?}]", TypeInfo::from_type::<Arc<TComponent>>()));
-            return Ok(Box::new(singleton_component_instance) as Box<dyn Any + Sync + Send>);
-        }
-    
-        let new_singleton = Arc::new(AnthillRwLock::new(Option::<Arc<dyn Any + Sync + Send>>::None));
-        global_context_write_guard.singletons.insert(singleton_component_type_id, new_singleton.clone());
-    
-        let mut new_singleton_write_guard = new_singleton.write().await;
-        drop(global_context_write_guard); // Выглядит всрато, но надо отпустить лок всей коллекции, чтобы в дочерних элементах получить в кей доступ
-    
-        let component_type_id = TypeId::of::<TComponent>();
-    
-        let dependency_context_id = DependencyContextId::TypeId(TypeInfo::from_type::<TComponent>());
-        let dependency_context = DependencyContext::new_dependency(dependency_context_id, core_context.clone(), local_context.clone());
-    
-        let component = core_context.components.read().await.get(&component_type_id)
-            .expect(&format!("dependency not found, expected checked dependency type_info:[{:?}]", TypeInfo::from_type::<TComponent>()))
-            .clone();
-    
-        let new_component_instance = component.ctor.ctor(dependency_context).await?;
-        let new_component_instance: Box<TComponent> = new_component_instance.downcast::<TComponent>()
-            .expect(&format!("expected type_info:[{:?}] find type_info:[{:?}]",
-                TypeInfo::from_type::<TComponent>(),
-                component.component_type_info,
-            ));
-    
-        let new_component_instance_ref = Arc::new(Box::into_inner(new_component_instance));
-        _ = new_singleton_write_guard.insert(new_component_instance_ref.clone());
 
-        return Ok(Box::new(new_component_instance_ref) as Box<dyn Any + Sync + Send>);
+        loop {
+            // Slot cloned, so global context not locked, while other resolve construct instance
+            let singleton = core_context.global_context.read().await.singletons.get(&singleton_component_type_id).cloned();
+
+            if let Some(singleton) = singleton {
+                if let Some(singleton_component_instance) = singleton.read().await.as_ref() {
+                    let singleton_component_instance: Arc<TComponent> = singleton_component_instance.clone().downcast::<TComponent>()
+                        .expect(&format!("Incorrect singleton type expected type_info:[{:?}]", TypeInfo::from_type::<Arc<TComponent>>()));
+                    return Ok(Box::new(singleton_component_instance) as Box<dyn Any + Sync + Send>);
+                }
+
+                // Empty slot left by failed construction, instance constructed again
+                remove_empty_slot(&mut core_context.global_context.write().await.singletons, &singleton_component_type_id, &singleton);
+                continue;
+            }
+
+            let mut slots_write_guard = core_context.global_context.write().await;
+
+            // Slot can be added by other resolve, while write guard awaited
+            if slots_write_guard.singletons.contains_key(&singleton_component_type_id) {
+                continue;
+            }
+
+            let new_singleton = Arc::new(AnthillRwLock::new(Option::<Arc<dyn Any + Sync + Send>>::None));
+            slots_write_guard.singletons.insert(singleton_component_type_id, new_singleton.clone());
+
+            let mut new_singleton_write_guard = new_singleton.write().await;
+            drop(slots_write_guard); // Выглядит всрато, но надо отпустить лок всей коллекции, чтобы в дочерних элементах получить в кей доступ
+
+            let component_type_id = TypeId::of::<TComponent>();
+
+            let dependency_context_id = DependencyContextId::child(parent, TypeInfo::from_type::<TComponent>());
+            let dependency_context = DependencyContext::new_dependency(dependency_context_id, core_context.clone(), local_context.clone());
+
+            let component = core_context.components.read().await.get(&component_type_id)
+                .expect(&format!("dependency not found, expected checked dependency type_info:[{:?}]", TypeInfo::from_type::<TComponent>()))
+                .clone();
+
+            // Shared instance registered by register_arc cached as is, ctor and interceptors not called
+            if let Some(shared_instance) = component.ctor.shared_instance() {
+                let shared_instance: Arc<TComponent> = shared_instance.downcast::<TComponent>()
+                    .unwrap_or_else(|_| panic!("Incorrect shared instance type expected type_info:[{:?}]", TypeInfo::from_type::<TComponent>()));
+                _ = new_singleton_write_guard.insert(shared_instance.clone());
+
+                return Ok(Box::new(shared_instance) as Box<dyn Any + Sync + Send>);
+            }
+
+            let new_component_instance = match core_context.construct_component(&component, dependency_context, None, parent).await {
+                Ok(new_component_instance) => new_component_instance,
+                Err(err) => {
+                    // Waiting resolves hold only slot, so slot released before collection locked
+                    drop(new_singleton_write_guard);
+                    remove_empty_slot(&mut core_context.global_context.write().await.singletons, &singleton_component_type_id, &new_singleton);
+                    return Err(err);
+                },
+            };
+
+            let new_component_instance: Box<TComponent> = new_component_instance.downcast::<TComponent>()
+                .expect(&format!("expected type_info:[{:?}] find type_info:[{:?}]",
+                    TypeInfo::from_type::<TComponent>(),
+                    component.component_type_info,
+                ));
+
+            let new_component_instance_ref = Arc::new(Box::into_inner(new_component_instance));
+            _ = new_singleton_write_guard.insert(new_component_instance_ref.clone());
+
+            return Ok(Box::new(new_component_instance_ref) as Box<dyn Any + Sync + Send>);
+        }
     }
 
     #[cfg(feature = "blocking")]
-    fn blocking_build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+    fn blocking_build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>, parent: &DependencyContextId) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
         let singleton_component_type_id = TypeId::of::<Arc<TComponent>>();
-        let global_context_read_guard = core_context.global_context.blocking_read();
-    
-        if let Some(singleton_component_instance) = global_context_read_guard.singletons.get(&singleton_component_type_id) {
-            let singleton_component_instance: Arc<TComponent> = singleton_component_instance.blocking_read().as_ref().unwrap().clone().downcast::<TComponent>()
-                .expect(&format!("Incorrect singleton type expected type_info:[{:?}]", TypeInfo::from_type::<Arc<TComponent>>()));
-            return Ok(Box::new(singleton_component_instance) as Box<dyn Any + Sync + Send>);
-        }
-    
-        drop(global_context_read_guard); // дедлок!!!!!!!!!!!!!!!!!!!
-        let mut global_context_write_guard = core_context.global_context.blocking_write();
-    
-        if let Some(singleton_component_instance) = global_context_write_guard.singletons.get(&singleton_component_type_id) {
-            let singleton_component_instance: Arc<TComponent> = singleton_component_instance.blocking_read().as_ref().unwrap().clone().downcast::<TComponent>()
-                .expect(&format!("Incorrect singleton type expected type_info:[{:?}]", TypeInfo::from_type::<Arc<TComponent>>()));
-            return Ok(Box::new(singleton_component_instance) as Box<dyn Any + Sync + Send>);
-        }
-    
-        let new_singleton = Arc::new(AnthillRwLock::new(Option::<Arc<dyn Any + Sync + Send>>::None));
-        global_context_write_guard.singletons.insert(singleton_component_type_id, new_singleton.clone());
-    
-        let mut new_singleton_write_guard = new_singleton.blocking_write();
-        drop(global_context_write_guard); // Выглядит всрато, но надо отпустить лок всей коллекции, чтобы в дочерних элементах получить в кей доступ
-    
-        let component_type_id = TypeId::of::<TComponent>();
-    
-        let dependency_context_id = DependencyContextId::TypeId(TypeInfo::from_type::<TComponent>());
-        let dependency_context = DependencyContext::new_dependency(dependency_context_id, core_context.clone(), local_context.clone());
-    
-        let component = core_context.components.blocking_read().get(&component_type_id)
-            .expect(&format!("dependency not found, expected checked dependency type_info:[{:?}]", TypeInfo::from_type::<TComponent>()))
-            .clone();
-    
-        let component_ref = component.clone();
-        let new_component_instance = component_ref.ctor.blocking_ctor(dependency_context)?;
-        let new_component_instance: Box<TComponent> = new_component_instance.downcast::<TComponent>()
-            .expect(&format!("expected type_info:[{:?}] find type_info:[{:?}]",
-                TypeInfo::from_type::<TComponent>(),
-                component.component_type_info,
-            ));
-    
-        let new_component_instance_ref = Arc::new(Box::into_inner(new_component_instance));
-        _ = new_singleton_write_guard.insert(new_component_instance_ref.clone());
 
-        return Ok(Box::new(new_component_instance_ref) as Box<dyn Any + Sync + Send>);
+        loop {
+            // Slot cloned, so global context not locked, while other resolve construct instance
+            let singleton = core_context.global_context.blocking_read().singletons.get(&singleton_component_type_id).cloned();
+
+            if let Some(singleton) = singleton {
+                if let Some(singleton_component_instance) = singleton.blocking_read().as_ref() {
+                    let singleton_component_instance: Arc<TComponent> = singleton_component_instance.clone().downcast::<TComponent>()
+                        .expect(&format!("Incorrect singleton type expected type_info:[{:?}]", TypeInfo::from_type::<Arc<TComponent>>()));
+                    return Ok(Box::new(singleton_component_instance) as Box<dyn Any + Sync + Send>);
+                }
+
+                // Empty slot left by failed construction, instance constructed again
+                remove_empty_slot(&mut core_context.global_context.blocking_write().singletons, &singleton_component_type_id, &singleton);
+                continue;
+            }
+
+            let mut slots_write_guard = core_context.global_context.blocking_write();
+
+            // Slot can be added by other resolve, while write guard awaited
+            if slots_write_guard.singletons.contains_key(&singleton_component_type_id) {
+                continue;
+            }
+
+            let new_singleton = Arc::new(AnthillRwLock::new(Option::<Arc<dyn Any + Sync + Send>>::None));
+            slots_write_guard.singletons.insert(singleton_component_type_id, new_singleton.clone());
+
+            let mut new_singleton_write_guard = new_singleton.blocking_write();
+            drop(slots_write_guard); // Выглядит всрато, но надо отпустить лок всей коллекции, чтобы в дочерних элементах получить в кей доступ
+
+            let component_type_id = TypeId::of::<TComponent>();
+
+            let dependency_context_id = DependencyContextId::child(parent, TypeInfo::from_type::<TComponent>());
+            let dependency_context = DependencyContext::new_dependency(dependency_context_id, core_context.clone(), local_context.clone());
+
+            let component = core_context.components.blocking_read().get(&component_type_id)
+                .expect(&format!("dependency not found, expected checked dependency type_info:[{:?}]", TypeInfo::from_type::<TComponent>()))
+                .clone();
+
+            // Shared instance registered by register_arc cached as is, ctor and interceptors not called
+            if let Some(shared_instance) = component.ctor.shared_instance() {
+                let shared_instance: Arc<TComponent> = shared_instance.downcast::<TComponent>()
+                    .unwrap_or_else(|_| panic!("Incorrect shared instance type expected type_info:[{:?}]", TypeInfo::from_type::<TComponent>()));
+                _ = new_singleton_write_guard.insert(shared_instance.clone());
+
+                return Ok(Box::new(shared_instance) as Box<dyn Any + Sync + Send>);
+            }
+
+            let new_component_instance = match core_context.blocking_construct_component(&component, dependency_context, None, parent) {
+                Ok(new_component_instance) => new_component_instance,
+                Err(err) => {
+                    // Waiting resolves hold only slot, so slot released before collection locked
+                    drop(new_singleton_write_guard);
+                    remove_empty_slot(&mut core_context.global_context.blocking_write().singletons, &singleton_component_type_id, &new_singleton);
+                    return Err(err);
+                },
+            };
+
+            let new_component_instance: Box<TComponent> = new_component_instance.downcast::<TComponent>()
+                .expect(&format!("expected type_info:[{:?}] find type_info:[{:?}]",
+                    TypeInfo::from_type::<TComponent>(),
+                    component.component_type_info,
+                ));
+
+            let new_component_instance_ref = Arc::new(Box::into_inner(new_component_instance));
+            _ = new_singleton_write_guard.insert(new_component_instance_ref.clone());
+
+            return Ok(Box::new(new_component_instance_ref) as Box<dyn Any + Sync + Send>);
+        }
     }
 }
 
 #[cfg(not(feature = "async-mode"))]
 impl<TComponent: Sync + Send + 'static> ICycledComponentBuilder for SingletonComponentBuilder<TComponent> {
-    fn build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>{
+    fn build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>, parent: &DependencyContextId) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>{
         let singleton_component_type_id = TypeId::of::<Arc<TComponent>>();
-        let global_context_read_guard = core_context.global_context.read().unwrap();
-    
-        if let Some(singleton_component_instance) = global_context_read_guard.singletons.get(&singleton_component_type_id) {
-            let singleton_component_instance: Arc<TComponent> = singleton_component_instance.read().unwrap().as_ref().unwrap().clone().downcast::<TComponent>()
-                .expect(&format!("Incorrect singleton type expected type_info:[{:?}]", TypeInfo::from_type::<Arc<TComponent>>()));
-            return Ok(Box::new(singleton_component_instance) as Box<dyn Any + Sync + Send>);
-        }
-    
-        drop(global_context_read_guard); // дедлок!!!!!!!!!!!!!!!!!!!
-        let mut global_context_write_guard = core_context.global_context.write().unwrap();
-    
-        if let Some(singleton_component_instance) = global_context_write_guard.singletons.get(&singleton_component_type_id) {
-            let singleton_component_instance: Arc<TComponent> = singleton_component_instance.read().unwrap().as_ref().unwrap().clone().downcast::<TComponent>()
-                .expect(&format!("Incorrect singleton type expected type_info:[{:?}]", TypeInfo::from_type::<Arc<TComponent>>()));
-            return Ok(Box::new(singleton_component_instance) as Box<dyn Any + Sync + Send>);
-        }
-    
-        let new_singleton = Arc::new(AnthillRwLock::new(Option::<Arc<dyn Any + Sync + Send>>::None));
-        global_context_write_guard.singletons.insert(singleton_component_type_id, new_singleton.clone());
-    
-        let mut new_singleton_write_guard = new_singleton.write().unwrap();
-        drop(global_context_write_guard); // Выглядит всрато, но надо отпустить лок всей коллекции, чтобы в дочерних элементах получить в кей доступ
-    
-        let component_type_id = TypeId::of::<TComponent>();
-    
-        let dependency_context_id = DependencyContextId::TypeId(TypeInfo::from_type::<TComponent>());
-        let dependency_context = DependencyContext::new_dependency(dependency_context_id, core_context.clone(), local_context.clone());
-    
-        let component = core_context.components.read().unwrap().get(&component_type_id)
-            .expect(&format!("dependency not found, expected checked dependency type_info:[{:?}]", TypeInfo::from_type::<TComponent>()))
-            .clone();
-    
-        let new_component_instance = component.ctor.ctor(dependency_context)?;
-        let new_component_instance: Box<TComponent> = new_component_instance.downcast::<TComponent>()
-            .expect(&format!("expected type_info:[{:?}] find type_info:[{:?}]",
-                TypeInfo::from_type::<TComponent>(),
-                component.component_type_info,
-            ));
-    
-        let new_component_instance_ref = Arc::new(Box::into_inner(new_component_instance));
-        _ = new_singleton_write_guard.insert(new_component_instance_ref.clone());
 
-        return Ok(Box::new(new_component_instance_ref) as Box<dyn Any + Sync + Send>);
+        loop {
+            // Slot cloned, so global context not locked, while other resolve construct instance
+            let singleton = core_context.global_context.read().unwrap().singletons.get(&singleton_component_type_id).cloned();
+
+            if let Some(singleton) = singleton {
+                if let Some(singleton_component_instance) = singleton.read().unwrap().as_ref() {
+                    let singleton_component_instance: Arc<TComponent> = singleton_component_instance.clone().downcast::<TComponent>()
+                        .expect(&format!("Incorrect singleton type expected type_info:[{:?}]", TypeInfo::from_type::<Arc<TComponent>>()));
+                    return Ok(Box::new(singleton_component_instance) as Box<dyn Any + Sync + Send>);
+                }
+
+                // Empty slot left by failed construction, instance constructed again
+                remove_empty_slot(&mut core_context.global_context.write().unwrap().singletons, &singleton_component_type_id, &singleton);
+                continue;
+            }
+
+            let mut slots_write_guard = core_context.global_context.write().unwrap();
+
+            // Slot can be added by other resolve, while write guard awaited
+            if slots_write_guard.singletons.contains_key(&singleton_component_type_id) {
+                continue;
+            }
+
+            let new_singleton = Arc::new(AnthillRwLock::new(Option::<Arc<dyn Any + Sync + Send>>::None));
+            slots_write_guard.singletons.insert(singleton_component_type_id, new_singleton.clone());
+
+            let mut new_singleton_write_guard = new_singleton.write().unwrap();
+            drop(slots_write_guard); // Выглядит всрато, но надо отпустить лок всей коллекции, чтобы в дочерних элементах получить в кей доступ
+
+            let component_type_id = TypeId::of::<TComponent>();
+
+            let dependency_context_id = DependencyContextId::child(parent, TypeInfo::from_type::<TComponent>());
+            let dependency_context = DependencyContext::new_dependency(dependency_context_id, core_context.clone(), local_context.clone());
+
+            let component = core_context.components.read().unwrap().get(&component_type_id)
+                .expect(&format!("dependency not found, expected checked dependency type_info:[{:?}]", TypeInfo::from_type::<TComponent>()))
+                .clone();
+
+            // Shared instance registered by register_arc cached as is, ctor and interceptors not called
+            if let Some(shared_instance) = component.ctor.shared_instance() {
+                let shared_instance: Arc<TComponent> = shared_instance.downcast::<TComponent>()
+                    .unwrap_or_else(|_| panic!("Incorrect shared instance type expected type_info:[{:?}]", TypeInfo::from_type::<TComponent>()));
+                _ = new_singleton_write_guard.insert(shared_instance.clone());
+
+                return Ok(Box::new(shared_instance) as Box<dyn Any + Sync + Send>);
+            }
+
+            let new_component_instance = match core_context.construct_component(&component, dependency_context, None, parent) {
+                Ok(new_component_instance) => new_component_instance,
+                Err(err) => {
+                    // Waiting resolves hold only slot, so slot released before collection locked
+                    drop(new_singleton_write_guard);
+                    remove_empty_slot(&mut core_context.global_context.write().unwrap().singletons, &singleton_component_type_id, &new_singleton);
+                    return Err(err);
+                },
+            };
+
+            let new_component_instance: Box<TComponent> = new_component_instance.downcast::<TComponent>()
+                .expect(&format!("expected type_info:[{:?}] find type_info:[{:?}]",
+                    TypeInfo::from_type::<TComponent>(),
+                    component.component_type_info,
+                ));
+
+            let new_component_instance_ref = Arc::new(Box::into_inner(new_component_instance));
+            _ = new_singleton_write_guard.insert(new_component_instance_ref.clone());
+
+            return Ok(Box::new(new_component_instance_ref) as Box<dyn Any + Sync + Send>);
+        }
     }
 }
 
@@ -221,153 +307,200 @@ impl<TComponent: Sync + Send + 'static> ContextDependentComponentBuilder<TCompon
 #[cfg(feature = "async-mode")]
 #[async_trait_with_sync::async_trait(Sync)]
 impl<TComponent: Sync + Send + 'static> ICycledComponentBuilder for ContextDependentComponentBuilder<TComponent> {
-    async fn build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>{
+    async fn build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>, parent: &DependencyContextId) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>{
         let context_dependent_component_type_id = TypeId::of::<Arc<TComponent>>();
-        let local_context_read_guard = local_context.local_context.read().await;
 
-        if let Some(context_dependent_component_instance_ref) = local_context_read_guard.get(&context_dependent_component_type_id) {
-            let context_dependent_component_instance: Arc<TComponent> = context_dependent_component_instance_ref.read().await.as_ref().unwrap().clone().downcast::<TComponent>()
-                .expect(&format!("Incorrect context dependent type expected type_info:[{:?}]", TypeInfo::from_type::<Arc<TComponent>>()));
-            return Ok(Box::new(Arc::downgrade(&context_dependent_component_instance)) as Box<dyn Any + Sync + Send>);
-        }
+        loop {
+            // Slot cloned, so local context not locked, while other resolve construct instance
+            let context_dependent = local_context.local_context.read().await.get(&context_dependent_component_type_id).cloned();
 
-        drop(local_context_read_guard);
-        let mut local_context_write_guard = local_context.local_context.write().await;
+            if let Some(context_dependent) = context_dependent {
+                if let Some(context_dependent_component_instance) = context_dependent.read().await.as_ref() {
+                    let context_dependent_component_instance: Arc<TComponent> = context_dependent_component_instance.clone().downcast::<TComponent>()
+                        .expect(&format!("Incorrect context dependent type expected type_info:[{:?}]", TypeInfo::from_type::<Arc<TComponent>>()));
+                    return Ok(Box::new(Arc::downgrade(&context_dependent_component_instance)) as Box<dyn Any + Sync + Send>);
+                }
 
-        if let Some(context_dependent_component_instance_ref) = local_context_write_guard.get(&context_dependent_component_type_id) {
-            let context_dependent_component_instance: Arc<TComponent> = context_dependent_component_instance_ref.read().await.as_ref().unwrap().clone().downcast::<TComponent>()
-                .expect(&format!("Incorrect context dependent type expected type_info:[{:?}]", TypeInfo::from_type::<Arc<TComponent>>()));
+                // Empty slot left by failed construction, instance constructed again
+                remove_empty_slot(&mut *local_context.local_context.write().await, &context_dependent_component_type_id, &context_dependent);
+                continue;
+            }
 
-            return Ok(Box::new(context_dependent_component_instance) as Box<dyn Any + Sync + Send>);
-        }
+            let mut slots_write_guard = local_context.local_context.write().await;
 
-        let new_context_dependent_any_ref = Arc::new(AnthillRwLock::new(Option::<Arc<dyn Any + Sync + Send>>::None));
-        local_context_write_guard.insert(context_dependent_component_type_id, new_context_dependent_any_ref.clone());
+            // Slot can be added by other resolve, while write guard awaited
+            if slots_write_guard.contains_key(&context_dependent_component_type_id) {
+                continue;
+            }
 
-        let mut new_context_dependent_any_ref_write_guard = new_context_dependent_any_ref.write().await;
-        drop(local_context_write_guard); // Выглядит всрато, но надо отпустить лок всей коллекции, чтобы в дочерних элементах получить в кей доступ
+            let new_context_dependent = Arc::new(AnthillRwLock::new(Option::<Arc<dyn Any + Sync + Send>>::None));
+            slots_write_guard.insert(context_dependent_component_type_id, new_context_dependent.clone());
 
-        let component_type_id = TypeId::of::<TComponent>();
+            let mut new_context_dependent_write_guard = new_context_dependent.write().await;
+            drop(slots_write_guard); // Выглядит всрато, но надо отпустить лок всей коллекции, чтобы в дочерних элементах получить в кей доступ
 
-        let dependency_context_id = DependencyContextId::TypeId(TypeInfo::from_type::<TComponent>());
-        let dependency_context = DependencyContext::new_dependency(dependency_context_id, core_context.clone(), local_context.clone());
+            let component_type_id = TypeId::of::<TComponent>();
 
-        let component = core_context.components.read().await.get(&component_type_id)
-            .expect(&format!("dependency not found, expected checked dependency type_info:[{:?}]", TypeInfo::from_type::<TComponent>()))
-            .clone();
+            let dependency_context_id = DependencyContextId::child(parent, TypeInfo::from_type::<TComponent>());
+            let dependency_context = DependencyContext::new_dependency(dependency_context_id, core_context.clone(), local_context.clone());
 
-        let new_component_instance = component.ctor.ctor(dependency_context).await?;
-        let new_component_instance: Box<TComponent> = new_component_instance.downcast::<TComponent>()
-            .expect(&format!("expected type_info:[{:?}] find type_info:[{:?}]",
-                TypeInfo::from_type::<TComponent>(),
-                component.component_type_info,
-            ));
+            let component = core_context.components.read().await.get(&component_type_id)
+                .expect(&format!("dependency not found, expected checked dependency type_info:[{:?}]", TypeInfo::from_type::<TComponent>()))
+                .clone();
+
+            let new_component_instance = match core_context.construct_component(&component, dependency_context, None, parent).await {
+                Ok(new_component_instance) => new_component_instance,
+                Err(err) => {
+                    // Waiting resolves hold only slot, so slot released before collection locked
+                    drop(new_context_dependent_write_guard);
+                    remove_empty_slot(&mut *local_context.local_context.write().await, &context_dependent_component_type_id, &new_context_dependent);
+                    return Err(err);
+                },
+            };
 
-        let new_component_instance_ref = Arc::new(Box::into_inner(new_component_instance));
-        _ = new_context_dependent_any_ref_write_guard.insert(new_component_instance_ref.clone());
+            let new_component_instance: Box<TComponent> = new_component_instance.downcast::<TComponent>()
+                .expect(&format!("expected type_info:[{:?}] find type_info:[{:?}]",
+                    TypeInfo::from_type::<TComponent>(),
+                    component.component_type_info,
+                ));
 
-        Ok(Box::new(Arc::downgrade(&new_component_instance_ref)) as Box<dyn Any + Sync + Send>)
+            let new_component_instance_ref = Arc::new(Box::into_inner(new_component_instance));
+            _ = new_context_dependent_write_guard.insert(new_component_instance_ref.clone());
+
+            return Ok(Box::new(Arc::downgrade(&new_component_instance_ref)) as Box<dyn Any + Sync + Send>);
+        }
     }
 
     #[cfg(feature = "blocking")]
-    fn blocking_build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+    fn blocking_build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>, parent: &DependencyContextId) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
         let context_dependent_component_type_id = TypeId::of::<Arc<TComponent>>();
-        let local_context_read_guard = local_context.local_context.blocking_read();
 
-        if let Some(context_dependent_component_instance_ref) = local_context_read_guard.get(&context_dependent_component_type_id) {
-            let context_dependent_component_instance: Arc<TComponent> = context_dependent_component_instance_ref.blocking_read().as_ref().unwrap().clone().downcast::<TComponent>()
-                .expect(&format!("Incorrect context dependent type expected type_info:[{:?}]", TypeInfo::from_type::<Arc<TComponent>>()));
-            return Ok(Box::new(Arc::downgrade(&context_dependent_component_instance)) as Box<dyn Any + Sync + Send>);
-        }
+        loop {
+            // Slot cloned, so local context not locked, while other resolve construct instance
+            let context_dependent = local_context.local_context.blocking_read().get(&context_dependent_component_type_id).cloned();
 
-        drop(local_context_read_guard);
-        let mut local_context_write_guard = local_context.local_context.blocking_write();
+            if let Some(context_dependent) = context_dependent {
+                if let Some(context_dependent_component_instance) = context_dependent.blocking_read().as_ref() {
+                    let context_dependent_component_instance: Arc<TComponent> = context_dependent_component_instance.clone().downcast::<TComponent>()
+                        .expect(&format!("Incorrect context dependent type expected type_info:[{:?}]", TypeInfo::from_type::<Arc<TComponent>>()));
+                    return Ok(Box::new(Arc::downgrade(&context_dependent_component_instance)) as Box<dyn Any + Sync + Send>);
+                }
 
-        if let Some(context_dependent_component_instance_ref) = local_context_write_guard.get(&context_dependent_component_type_id) {
-            let context_dependent_component_instance: Arc<TComponent> = context_dependent_component_instance_ref.blocking_read().as_ref().unwrap().clone().downcast::<TComponent>()
-                .expect(&format!("Incorrect context dependent type expected type_info:[{:?}]", TypeInfo::from_type::<Arc<TComponent>>()));
+                // Empty slot left by failed construction, instance constructed again
+                remove_empty_slot(&mut local_context.local_context.blocking_write(), &context_dependent_component_type_id, &context_dependent);
+                continue;
+            }
 
-            return Ok(Box::new(context_dependent_component_instance) as Box<dyn Any + Sync + Send>);
-        }
+            let mut slots_write_guard = local_context.local_context.blocking_write();
 
-        let new_context_dependent_any_ref = Arc::new(AnthillRwLock::new(Option::<Arc<dyn Any + Sync + Send>>::None));
-        local_context_write_guard.insert(context_dependent_component_type_id, new_context_dependent_any_ref.clone());
+            // Slot can be added by other resolve, while write guard awaited
+            if slots_write_guard.contains_key(&context_dependent_component_type_id) {
+                continue;
+            }
 
-        let mut new_context_dependent_any_ref_write_guard = new_context_dependent_any_ref.blocking_write();
-        drop(local_context_write_guard); // Выглядит всрато, но надо отпустить лок всей коллекции, чтобы в дочерних элементах получить в кей доступ
+            let new_context_dependent = Arc::new(AnthillRwLock::new(Option::<Arc<dyn Any + Sync + Send>>::None));
+            slots_write_guard.insert(context_dependent_component_type_id, new_context_dependent.clone());
 
-        let component_type_id = TypeId::of::<TComponent>();
+            let mut new_context_dependent_write_guard = new_context_dependent.blocking_write();
+            drop(slots_write_guard); // Выглядит всрато, но надо отпустить лок всей коллекции, чтобы в дочерних элементах получить в кей доступ
 
-        let dependency_context_id = DependencyContextId::TypeId(TypeInfo::from_type::<TComponent>());
-        let dependency_context = DependencyContext::new_dependency(dependency_context_id, core_context.clone(), local_context.clone());
+            let component_type_id = TypeId::of::<TComponent>();
 
-        let component = core_context.components.blocking_read().get(&component_type_id)
-            .expect(&format!("dependency not found, expected checked dependency type_info:[{:?}]", TypeInfo::from_type::<TComponent>()))
-            .clone();
+            let dependency_context_id = DependencyContextId::child(parent, TypeInfo::from_type::<TComponent>());
+            let dependency_context = DependencyContext::new_dependency(dependency_context_id, core_context.clone(), local_context.clone());
 
-        let component_ref = component.clone();
-        let new_component_instance = component_ref.ctor.blocking_ctor(dependency_context)?;
-        let new_component_instance: Box<TComponent> = new_component_instance.downcast::<TComponent>()
-            .expect(&format!("expected type_info:[{:?}] find type_info:[{:?}]",
-                TypeInfo::from_type::<TComponent>(),
-                component.component_type_info,
-            ));
+            let component = core_context.components.blocking_read().get(&component_type_id)
+                .expect(&format!("dependency not found, expected checked dependency type_info:[{:?}]", TypeInfo::from_type::<TComponent>()))
+                .clone();
+
+            let new_component_instance = match core_context.blocking_construct_component(&component, dependency_context, None, parent) {
+                Ok(new_component_instance) => new_component_instance,
+                Err(err) => {
+                    // Waiting resolves hold only slot, so slot released before collection locked
+                    drop(new_context_dependent_write_guard);
+                    remove_empty_slot(&mut local_context.local_context.blocking_write(), &context_dependent_component_type_id, &new_context_dependent);
+                    return Err(err);
+                },
+            };
+
+            let new_component_instance: Box<TComponent> = new_component_instance.downcast::<TComponent>()
+                .expect(&format!("expected type_info:[{:?}] find type_info:[{:?}]",
+                    TypeInfo::from_type::<TComponent>(),
+                    component.component_type_info,
+                ));
 
-        let new_component_instance_ref = Arc::new(Box::into_inner(new_component_instance));
-        _ = new_context_dependent_any_ref_write_guard.insert(new_component_instance_ref.clone());
+            let new_component_instance_ref = Arc::new(Box::into_inner(new_component_instance));
+            _ = new_context_dependent_write_guard.insert(new_component_instance_ref.clone());
 
-        Ok(Box::new(Arc::downgrade(&new_component_instance_ref)) as Box<dyn Any + Sync + Send>)
+            return Ok(Box::new(Arc::downgrade(&new_component_instance_ref)) as Box<dyn Any + Sync + Send>);
+        }
     }
 }
 
 #[cfg(not(feature = "async-mode"))]
 impl<TComponent: Sync + Send + 'static> ICycledComponentBuilder for ContextDependentComponentBuilder<TComponent> {
-    fn build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>{
+    fn build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>, parent: &DependencyContextId) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>{
         let context_dependent_component_type_id = TypeId::of::<Arc<TComponent>>();
-        let local_context_read_guard = local_context.local_context.read().unwrap();
 
-        if let Some(context_dependent_component_instance_ref) = local_context_read_guard.get(&context_dependent_component_type_id) {
-            let context_dependent_component_instance: Arc<TComponent> = context_dependent_component_instance_ref.read().unwrap().as_ref().unwrap().clone().downcast::<TComponent>()
-                .expect(&format!("Incorrect context dependent type expected type_info:[{:?}]", TypeInfo::from_type::<Arc<TComponent>>()));
-            return Ok(Box::new(Arc::downgrade(&context_dependent_component_instance)) as Box<dyn Any + Sync + Send>);
-        }
+        loop {
+            // Slot cloned, so local context not locked, while other resolve construct instance
+            let context_dependent = local_context.local_context.read().unwrap().get(&context_dependent_component_type_id).cloned();
 
-        drop(local_context_read_guard);
-        let mut local_context_write_guard = local_context.local_context.write().unwrap();
+            if let Some(context_dependent) = context_dependent {
+                if let Some(context_dependent_component_instance) = context_dependent.read().unwrap().as_ref() {
+                    let context_dependent_component_instance: Arc<TComponent> = context_dependent_component_instance.clone().downcast::<TComponent>()
+                        .expect(&format!("Incorrect context dependent type expected type_info:[{:?}]", TypeInfo::from_type::<Arc<TComponent>>()));
+                    return Ok(Box::new(Arc::downgrade(&context_dependent_component_instance)) as Box<dyn Any + Sync + Send>);
+                }
 
-        if let Some(context_dependent_component_instance_ref) = local_context_write_guard.get(&context_dependent_component_type_id) {
-            let context_dependent_component_instance: Arc<TComponent> = context_dependent_component_instance_ref.read().unwrap().as_ref().unwrap().clone().downcast::<TComponent>()
-                .expect(&format!("Incorrect context dependent type expected type_info:[{:?}]", TypeInfo::from_type::<Arc<TComponent>>()));
+                // Empty slot left by failed construction, instance constructed again
+                remove_empty_slot(&mut *local_context.local_context.write().unwrap(), &context_dependent_component_type_id, &context_dependent);
+                continue;
+            }
 
-            return Ok(Box::new(context_dependent_component_instance) as Box<dyn Any + Sync + Send>);
-        }
+            let mut slots_write_guard = local_context.local_context.write().unwrap();
 
-        let new_context_dependent_any_ref = Arc::new(AnthillRwLock::new(Option::<Arc<dyn Any + Sync + Send>>::None));
-        local_context_write_guard.insert(context_dependent_component_type_id, new_context_dependent_any_ref.clone());
+            // Slot can be added by other resolve, while write guard awaited
+            if slots_write_guard.contains_key(&context_dependent_component_type_id) {
+                continue;
+            }
 
-        let mut new_context_dependent_any_ref_write_guard = new_context_dependent_any_ref.write().unwrap();
-        drop(local_context_write_guard); // Выглядит всрато, но надо отпустить лок всей коллекции, чтобы в дочерних элементах получить в кей доступ
+            let new_context_dependent = Arc::new(AnthillRwLock::new(Option::<Arc<dyn Any + Sync + Send>>::None));
+            slots_write_guard.insert(context_dependent_component_type_id, new_context_dependent.clone());
 
-        let component_type_id = TypeId::of::<TComponent>();
+            let mut new_context_dependent_write_guard = new_context_dependent.write().unwrap();
+            drop(slots_write_guard); // Выглядит всрато, но надо отпустить лок всей коллекции, чтобы в дочерних элементах получить в кей доступ
 
-        let dependency_context_id = DependencyContextId::TypeId(TypeInfo::from_type::<TComponent>());
-        let dependency_context = DependencyContext::new_dependency(dependency_context_id, core_context.clone(), local_context.clone());
+            let component_type_id = TypeId::of::<TComponent>();
 
-        let component = core_context.components.read().unwrap().get(&component_type_id)
-            .expect(&format!("dependency not found, expected checked dependency type_info:[{:?}]", TypeInfo::from_type::<TComponent>()))
-            .clone();
+            let dependency_context_id = DependencyContextId::child(parent, TypeInfo::from_type::<TComponent>());
+            let dependency_context = DependencyContext::new_dependency(dependency_context_id, core_context.clone(), local_context.clone());
 
-        let new_component_instance = component.ctor.ctor(dependency_context)?;
-        let new_component_instance: Box<TComponent> = new_component_instance.downcast::<TComponent>()
-            .expect(&format!("expected type_info:[{:?}] find type_info:[{:?}]",
-                TypeInfo::from_type::<TComponent>(),
-                component.component_type_info,
-            ));
+            let component = core_context.components.read().unwrap().get(&component_type_id)
+                .expect(&format!("dependency not found, expected checked dependency type_info:[{:?}]", TypeInfo::from_type::<TComponent>()))
+                .clone();
+
+            let new_component_instance = match core_context.construct_component(&component, dependency_context, None, parent) {
+                Ok(new_component_instance) => new_component_instance,
+                Err(err) => {
+                    // Waiting resolves hold only slot, so slot released before collection locked
+                    drop(new_context_dependent_write_guard);
+                    remove_empty_slot(&mut *local_context.local_context.write().unwrap(), &context_dependent_component_type_id, &new_context_dependent);
+                    return Err(err);
+                },
+            };
 
-        let new_component_instance_ref = Arc::new(Box::into_inner(new_component_instance));
-        _ = new_context_dependent_any_ref_write_guard.insert(new_component_instance_ref.clone());
+            let new_component_instance: Box<TComponent> = new_component_instance.downcast::<TComponent>()
+                .expect(&format!("expected type_info:[{:?}] find type_info:[{:?}]",
+                    TypeInfo::from_type::<TComponent>(),
+                    component.component_type_info,
+                ));
 
-        Ok(Box::new(Arc::downgrade(&new_component_instance_ref)) as Box<dyn Any + Sync + Send>)
+            let new_component_instance_ref = Arc::new(Box::into_inner(new_component_instance));
+            _ = new_context_dependent_write_guard.insert(new_component_instance_ref.clone());
+
+            return Ok(Box::new(Arc::downgrade(&new_component_instance_ref)) as Box<dyn Any + Sync + Send>);
+        }
     }
 }
 
@@ -393,17 +526,17 @@ impl<TComponent: Sync + Send + 'static> TransientComponentBuilder<TComponent> {
 #[cfg(feature = "async-mode")]
 #[async_trait_with_sync::async_trait(Sync)]
 impl<TComponent: Sync + Send + 'static> ICycledComponentBuilder for TransientComponentBuilder<TComponent> {
-    async fn build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>{
+    async fn build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>, parent: &DependencyContextId) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>{
         let component_type_id = TypeId::of::<TComponent>();
 
-        let dependency_context_id = DependencyContextId::TypeId(TypeInfo::from_type::<TComponent>());
+        let dependency_context_id = DependencyContextId::child(parent, TypeInfo::from_type::<TComponent>());
         let dependency_context = DependencyContext::new_dependency(dependency_context_id, core_context.clone(), local_context.clone());
 
         let component = core_context.components.read().await.get(&component_type_id)
             .expect(&format!("dependency not found, expected checked dependency type_info:[{:?}]", TypeInfo::from_type::<TComponent>()))
             .clone();
 
-        let new_component_instance = component.ctor.ctor(dependency_context).await?;
+        let new_component_instance = core_context.construct_component(&component, dependency_context, None, parent).await?;
         let new_component_instance: Box<TComponent> = new_component_instance.downcast::<TComponent>()
             .expect(&format!("expected type_info:[{:?}] find type_info:[{:?}]",
                 TypeInfo::from_type::<TComponent>(),
@@ -414,10 +547,10 @@ impl<TComponent: Sync + Send + 'static> ICycledComponentBuilder for TransientCom
     }
 
     #[cfg(feature = "blocking")]
-    fn blocking_build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+    fn blocking_build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>, parent: &DependencyContextId) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
         let component_type_id = TypeId::of::<TComponent>();
 
-        let dependency_context_id = DependencyContextId::TypeId(TypeInfo::from_type::<TComponent>());
+        let dependency_context_id = DependencyContextId::child(parent, TypeInfo::from_type::<TComponent>());
         let dependency_context = DependencyContext::new_dependency(dependency_context_id, core_context.clone(), local_context.clone());
 
         let component = core_context.components.blocking_read().get(&component_type_id)
@@ -425,7 +558,7 @@ impl<TComponent: Sync + Send + 'static> ICycledComponentBuilder for TransientCom
             .clone();
 
         let component_ref = component.clone();
-        let new_component_instance = component_ref.ctor.blocking_ctor(dependency_context)?;
+        let new_component_instance = core_context.blocking_construct_component(&component_ref, dependency_context, None, parent)?;
         let new_component_instance: Box<TComponent> = new_component_instance.downcast::<TComponent>()
             .expect(&format!("expected type_info:[{:?}] find type_info:[{:?}]",
                 TypeInfo::from_type::<TComponent>(),
@@ -438,17 +571,17 @@ impl<TComponent: Sync + Send + 'static> ICycledComponentBuilder for TransientCom
 
 #[cfg(not(feature = "async-mode"))]
 impl<TComponent: Sync + Send + 'static> ICycledComponentBuilder for TransientComponentBuilder<TComponent> {
-    fn build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>{
+    fn build(&self, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>, parent: &DependencyContextId) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>{
         let component_type_id = TypeId::of::<TComponent>();
 
-        let dependency_context_id = DependencyContextId::TypeId(TypeInfo::from_type::<TComponent>());
+        let dependency_context_id = DependencyContextId::child(parent, TypeInfo::from_type::<TComponent>());
         let dependency_context = DependencyContext::new_dependency(dependency_context_id, core_context.clone(), local_context.clone());
 
         let component = core_context.components.read().unwrap().get(&component_type_id)
             .expect(&format!("dependency not found, expected checked dependency type_info:[{:?}]", TypeInfo::from_type::<TComponent>()))
             .clone();
 
-        let new_component_instance = component.ctor.ctor(dependency_context)?;
+        let new_component_instance = core_context.construct_component(&component, dependency_context, None, parent)?;
         let new_component_instance: Box<TComponent> = new_component_instance.downcast::<TComponent>()
             .expect(&format!("expected type_info:[{:?}] find type_info:[{:?}]",
                 TypeInfo::from_type::<TComponent>(),
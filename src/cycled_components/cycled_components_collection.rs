@@ -1,6 +1,9 @@
 use std::{any::TypeId, collections::HashMap, sync::{Arc, Weak}};
 
-use crate::types::TypeInfo;
+use crate::{
+    LifeCycle,
+    types::TypeInfo,
+};
 
 use super::{ICycledComponentBuilder, SingletonComponentBuilder, TransientComponentBuilder, ContextDependentComponentBuilder};
 
@@ -8,6 +11,7 @@ pub (crate) struct ComponentCycledComponentPair {
     //pub (crate) component_id: TypeId,
     //pub (crate) cycled_component_id: TypeId, 
     pub (crate) converter: Box<dyn ICycledComponentBuilder>,
+    pub (crate) life_cycle: LifeCycle,
 
     //#[cfg(feature = "debug-type-info")]
     pub (crate) component_type_info: TypeInfo,
@@ -21,7 +25,8 @@ impl std::fmt::Debug for ComponentCycledComponentPair {
         let mut debug_struct = f.debug_struct("ComponentCycledComponentPair");
             //.field("component_id", &self.component_id)
             //.field("cycled_component_id", &self.cycled_component_id)
-        debug_struct.field("converter", &self.converter);
+        debug_struct.field("converter", &self.converter)
+            .field("life_cycle", &self.life_cycle);
         
         //#[cfg(feature = "debug-type-info")]
         debug_struct.field("component_type_info", &self.component_type_info)
@@ -32,11 +37,12 @@ impl std::fmt::Debug for ComponentCycledComponentPair {
 }
 
 impl ComponentCycledComponentPair {
-    pub (crate) fn new<TComponent: 'static, TCycledComponent: 'static>(converter: Box<dyn ICycledComponentBuilder>) -> Self {
+    pub (crate) fn new<TComponent: 'static, TCycledComponent: 'static>(converter: Box<dyn ICycledComponentBuilder>, life_cycle: LifeCycle) -> Self {
         Self {
             //component_id: TypeId::of::<TComponent>(),
             //cycled_component_id: TypeId::of::<TCycledComponent>(),
             converter,
+            life_cycle,
             //#[cfg(feature = "debug-type-info")]
             component_type_info: TypeInfo::from_type::<TComponent>(),
             //#[cfg(feature = "debug-type-info")]
@@ -54,7 +60,7 @@ pub (crate) struct ComponentCycledComponentCollection {
 impl ComponentCycledComponentCollection {
     #[inline(always)]
     pub (crate) fn add_transient_cycle_builder<TComponent: Sync + Send + 'static>(&mut self) {
-        let component_cycled_component_pair = Arc::new(ComponentCycledComponentPair::new::<TComponent, TComponent>(Box::new(TransientComponentBuilder::<TComponent>::new())));
+        let component_cycled_component_pair = Arc::new(ComponentCycledComponentPair::new::<TComponent, TComponent>(Box::new(TransientComponentBuilder::<TComponent>::new()), LifeCycle::Transient));
 
         self.component_cycled_component_pairs_component_idx.insert(TypeId::of::<TComponent>(), component_cycled_component_pair.clone());
         self.component_cycled_component_pairs_cycled_component_idx.insert(TypeId::of::<TComponent>(), component_cycled_component_pair);
@@ -62,7 +68,7 @@ impl ComponentCycledComponentCollection {
 
     #[inline(always)]
     pub (crate) fn add_singleton_cycle_builder<TComponent: Sync + Send + 'static>(&mut self) {
-        let component_cycled_component_pair = Arc::new(ComponentCycledComponentPair::new::<TComponent, Arc<TComponent>>(Box::new(SingletonComponentBuilder::<TComponent>::new())));
+        let component_cycled_component_pair = Arc::new(ComponentCycledComponentPair::new::<TComponent, Arc<TComponent>>(Box::new(SingletonComponentBuilder::<TComponent>::new()), LifeCycle::Singleton));
 
         self.component_cycled_component_pairs_component_idx.insert(TypeId::of::<TComponent>(), component_cycled_component_pair.clone());
         self.component_cycled_component_pairs_cycled_component_idx.insert(TypeId::of::<Arc<TComponent>>(), component_cycled_component_pair);
@@ -70,7 +76,7 @@ impl ComponentCycledComponentCollection {
 
     #[inline(always)]
     pub (crate) fn add_context_dependent_cycle_builder<TComponent: Sync + Send + 'static>(&mut self) {
-        let component_cycled_component_pair = Arc::new(ComponentCycledComponentPair::new::<TComponent, Weak<TComponent>>(Box::new(ContextDependentComponentBuilder::<TComponent>::new())));
+        let component_cycled_component_pair = Arc::new(ComponentCycledComponentPair::new::<TComponent, Weak<TComponent>>(Box::new(ContextDependentComponentBuilder::<TComponent>::new()), LifeCycle::ContextDependent));
 
         self.component_cycled_component_pairs_component_idx.insert(TypeId::of::<TComponent>(), component_cycled_component_pair.clone());
         self.component_cycled_component_pairs_cycled_component_idx.insert(TypeId::of::<Weak<TComponent>>(), component_cycled_component_pair);
@@ -87,7 +93,12 @@ impl ComponentCycledComponentCollection {
 
     #[inline(always)]
     pub (crate) fn delete_by_component<TComponent: 'static>(&mut self) -> Option<Arc<ComponentCycledComponentPair>> {
-        let component_cycled_component_pair = self.component_cycled_component_pairs_component_idx.remove(&TypeId::of::<TComponent>())?;
+        self.delete_by_component_id(&TypeId::of::<TComponent>())
+    }
+
+    #[inline(always)]
+    pub (crate) fn delete_by_component_id(&mut self, component_id: &TypeId) -> Option<Arc<ComponentCycledComponentPair>> {
+        let component_cycled_component_pair = self.component_cycled_component_pairs_component_idx.remove(component_id)?;
         _ = self.component_cycled_component_pairs_cycled_component_idx.remove(&component_cycled_component_pair.cycled_component_type_info.type_id)?;
         Some(component_cycled_component_pair)
     }
@@ -0,0 +1,67 @@
+use std::{
+    any::Any,
+    fmt::Debug,
+};
+
+use crate::{
+    DependencyContext,
+    types::BuildDependencyResult,
+};
+
+/// Service decorator, wrap service implementation
+///
+/// Decorators applied to service in registration order, every resolve of transient service
+///
+/// Singleton and context dependent service decorated once and cached with component instance
+///# Example
+///---
+/// ```ignore
+/// struct CachingRepo { inner: Box<dyn Repo> }
+///
+/// #[async_trait_with_sync::async_trait(Sync)]
+/// impl Decorator<Box<dyn Repo>> for CachingRepo {
+///     async fn decorate(_ctx: DependencyContext, inner: Box<dyn Repo>) -> BuildDependencyResult<Box<dyn Repo>> {
+///         Ok(Box::new(CachingRepo { inner }))
+///     }
+/// }
+/// ```
+#[cfg(not(feature = "async-mode"))]
+pub trait Decorator<TService: Sync + Send + 'static> where Self: 'static {
+    fn decorate(ctx: DependencyContext, inner: TService) -> BuildDependencyResult<TService>;
+}
+
+/// Service decorator, wrap service implementation
+///
+/// Decorators applied to service in registration order, every resolve of transient service
+///
+/// Singleton and context dependent service decorated once and cached with component instance
+///# Example
+///---
+/// ```ignore
+/// struct CachingRepo { inner: Box<dyn Repo> }
+///
+/// #[async_trait_with_sync::async_trait(Sync)]
+/// impl Decorator<Box<dyn Repo>> for CachingRepo {
+///     async fn decorate(_ctx: DependencyContext, inner: Box<dyn Repo>) -> BuildDependencyResult<Box<dyn Repo>> {
+///         Ok(Box::new(CachingRepo { inner }))
+///     }
+/// }
+/// ```
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+pub trait Decorator<TService: Sync + Send + 'static> where Self: 'static {
+    async fn decorate(ctx: DependencyContext, inner: TService) -> BuildDependencyResult<TService>;
+}
+
+#[cfg(not(feature = "async-mode"))]
+pub (crate) trait IServiceDecorator where Self: Sync + Send + Debug {
+    fn decorate(&self, ctx: DependencyContext, inner: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+pub (crate) trait IServiceDecorator where Self: Sync + Send + Debug {
+    async fn decorate(&self, ctx: DependencyContext, inner: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+    #[cfg(feature = "blocking")]
+    fn blocking_decorate(&self, ctx: DependencyContext, inner: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+}
@@ -0,0 +1,59 @@
+use std::{
+    fmt::Debug,
+    any::Any,
+};
+
+use derive_new::new;
+
+use crate::{
+    types::{
+        BuildDependencyResult,
+        TypeInfo,
+    },
+    DependencyContext,
+    IServiceDecorator,
+};
+
+trait DecoratorClosure<TService: Sync + Send + 'static> = Fn(DependencyContext, TService) -> BuildDependencyResult<TService> + Sync + Send;
+
+#[derive(new)]
+pub (crate) struct DecoratorFromClosure<TService: Sync + Send + 'static> {
+    closure: Box<dyn DecoratorClosure<TService>>,
+}
+
+impl<TService: Sync + Send + 'static> Debug for DecoratorFromClosure<TService> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DecoratorFromClosure")
+            .finish()
+    }
+}
+
+impl<TService: Sync + Send + 'static> DecoratorFromClosure<TService> {
+    fn downcast_service(inner: Box<dyn Any + Sync + Send>) -> TService {
+        Box::into_inner(inner.downcast::<TService>()
+            .unwrap_or_else(|_| panic!("Decorator registered for service, expected service type_info:[{:?}]", TypeInfo::from_type::<TService>())))
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl<TService: Sync + Send + 'static> IServiceDecorator for DecoratorFromClosure<TService> {
+    fn decorate(&self, ctx: DependencyContext, inner: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let service = (self.closure)(ctx, Self::downcast_service(inner))?;
+        Ok(Box::new(service))
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl<TService: Sync + Send + 'static> IServiceDecorator for DecoratorFromClosure<TService> {
+    async fn decorate(&self, ctx: DependencyContext, inner: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let service = (self.closure)(ctx, Self::downcast_service(inner))?;
+        Ok(Box::new(service))
+    }
+
+    #[cfg(feature = "blocking")]
+    fn blocking_decorate(&self, ctx: DependencyContext, inner: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let service = (self.closure)(ctx, Self::downcast_service(inner))?;
+        Ok(Box::new(service))
+    }
+}
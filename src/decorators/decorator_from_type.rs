@@ -0,0 +1,65 @@
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    any::Any
+};
+
+use crate::{
+    DependencyContext,
+    Decorator,
+    IServiceDecorator,
+    types::{
+        BuildDependencyResult,
+        TypeInfo,
+    },
+};
+
+use derive_new::new;
+#[cfg(feature = "blocking")]
+use tokio::runtime::Builder;
+
+#[derive(new)]
+pub (crate) struct DecoratorFromType<TService: Sync + Send + 'static, TDecorator: Decorator<TService>> {
+    service_phantom_data: PhantomData<fn() -> TService>,
+    decorator_phantom_data: PhantomData<fn() -> TDecorator>,
+}
+
+impl<TService: Sync + Send + 'static, TDecorator: Decorator<TService>> Debug for DecoratorFromType<TService, TDecorator> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DecoratorFromType")
+            .field("decorator_type_info", &TypeInfo::from_type::<TDecorator>())
+            .finish()
+    }
+}
+
+impl<TService: Sync + Send + 'static, TDecorator: Decorator<TService>> DecoratorFromType<TService, TDecorator> {
+    fn downcast_service(inner: Box<dyn Any + Sync + Send>) -> TService {
+        Box::into_inner(inner.downcast::<TService>()
+            .unwrap_or_else(|_| panic!("Decorator registered for service, expected service type_info:[{:?}]", TypeInfo::from_type::<TService>())))
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl<TService: Sync + Send + 'static, TDecorator: Decorator<TService>> IServiceDecorator for DecoratorFromType<TService, TDecorator> {
+    fn decorate(&self, ctx: DependencyContext, inner: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let service = TDecorator::decorate(ctx, Self::downcast_service(inner))?;
+        Ok(Box::new(service))
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl<TService: Sync + Send + 'static, TDecorator: Decorator<TService>> IServiceDecorator for DecoratorFromType<TService, TDecorator> {
+    async fn decorate(&self, ctx: DependencyContext, inner: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let service = TDecorator::decorate(ctx, Self::downcast_service(inner)).await?;
+        Ok(Box::new(service))
+    }
+
+    #[cfg(feature = "blocking")]
+    fn blocking_decorate(&self, ctx: DependencyContext, inner: Box<dyn Any + Sync + Send>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let inner = Self::downcast_service(inner);
+        let rt = Builder::new_current_thread().enable_all().build().unwrap();
+        let service = rt.block_on(async move { TDecorator::decorate(ctx, inner).await })?;
+        Ok(Box::new(service))
+    }
+}
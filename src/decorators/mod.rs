@@ -0,0 +1,5 @@
+mod decorator_from_type;
+pub (crate) use decorator_from_type::*;
+
+mod decorator_from_closure;
+pub (crate) use decorator_from_closure::*;
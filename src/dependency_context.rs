@@ -1,21 +1,49 @@
 #[cfg(feature = "async-mode")]
-use crate::constructors::ComponentFromAsyncClosure;
+use crate::constructors::{ComponentFromAsyncClosure, ComponentFromAsyncFn};
+#[cfg(feature = "async-mode")]
+use crate::InjectAsyncFn;
 #[cfg(feature = "async-mode")]
 use std::future::Future;
+#[cfg(feature = "blocking")]
+use tokio::runtime::Builder;
+#[cfg(feature = "discovery")]
+use crate::{
+    DiscoveredComponent,
+    types::RegisterDiscoveredResult,
+};
 
 use crate::{
     Constructor,
+    Decorator,
+    ResolveInterceptor,
+    ResolutionSource,
+    AutoWired,
+    ServiceInfo,
+    ServiceMetadata,
+    decorators::{
+        DecoratorFromClosure,
+        DecoratorFromType,
+    },
+    ConstructorWith,
     ComponentFromConstructor,
+    ComponentFromConstructorWithArgs,
     types::{
         TypeInfo,
         DeleteComponentResult,
     },
     constructors::{
         ComponentFromClosure,
+        ComponentFromClosureWithArgs,
+        ComponentFromFn,
         ComponentFromInstance,
+        ComponentFromArc,
+        ComponentFromSharedInstance,
+        ComponentFromPrototype,
     },
+    InjectFn,
 };
 use std::marker::Unsize;
+use std::ops::CoerceUnsized;
 use std::{
     any::TypeId,
     sync::Arc,
@@ -23,22 +51,53 @@ use std::{
 
 use crate::{
     CoreContext,
+    ImplicitService,
     LocalContext,
+    ContextSettings,
+    ContextualBindingBuilder,
+    DependencyModule,
+    InstalledModule,
+    ModuleTracker,
+    service::CycledComponentServicePair,
     ServiceMappingBuilder,
     types::{
+        AddDependencyError,
+        BuildDependencyError,
         BuildDependencyResult,
         AddDependencyResult,
         MapComponentResult,
+        RegisterCompositeError,
+        RegisterCompositeResult,
+        InstallModuleResult,
+        UninstallModuleResult,
     },
     LifeCycle,
 };
 
 #[derive(Debug, PartialEq, Clone)]
 pub (crate) enum DependencyContextId {
-    TypeId(TypeInfo),
+    // Constructed component and resolution path, ended by this component
+    TypeId(TypeInfo, Arc<[TypeInfo]>),
     Root,
 }
 
+impl DependencyContextId {
+    // Context of component, constructed from parent context
+    pub (crate) fn child(parent: &DependencyContextId, component_type_info: TypeInfo) -> Self {
+        let mut resolution_path = parent.resolution_path().to_vec();
+        resolution_path.push(component_type_info.clone());
+
+        Self::TypeId(component_type_info, resolution_path.into())
+    }
+
+    pub (crate) fn resolution_path(&self) -> &[TypeInfo] {
+        match self {
+            Self::TypeId(_, resolution_path) => resolution_path,
+            Self::Root => &[],
+        }
+    }
+}
+
 /// Root di context, or child context (in ctor or closure)
 /// Main component. You work with it most of the time
 #[derive(Debug, Clone)]
@@ -46,6 +105,8 @@ pub struct DependencyContext {
     id: DependencyContextId,
     core_context: Arc<CoreContext>,
     pub (crate) local_context: Arc<LocalContext>,
+    // Exist only in module context, while module installed
+    module_tracker: Option<Arc<ModuleTracker>>,
 }
 
 impl DependencyContext {
@@ -55,12 +116,33 @@ impl DependencyContext {
             id: DependencyContextId::Root,
             core_context: Arc::new(Default::default()),
             local_context: Arc::new(Default::default()),
+            module_tracker: None,
+        }
+    }
+
+    /// Create new root empty context with settings
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root_with_settings(ContextSettings { strict_resolve: true, ..Default::default() });
+    /// ```
+    pub fn new_root_with_settings(settings: ContextSettings) -> Self {
+        Self {
+            id: DependencyContextId::Root,
+            core_context: Arc::new(CoreContext::new(settings)),
+            local_context: Arc::new(Default::default()),
+            module_tracker: None,
         }
     }
 
     #[inline(always)]
     pub (crate) fn new_dependency(id: DependencyContextId, core_context: Arc<CoreContext>, local_context: Arc<LocalContext>) -> Self {
-        Self { id, core_context, local_context, }
+        Self { id, core_context, local_context, module_tracker: None, }
+    }
+
+    // Same context, but resolve from it not linked with current component
+    pub (crate) fn without_parent(&self) -> Self {
+        Self { id: DependencyContextId::Root, module_tracker: None, ..self.clone() }
     }
 
     /// Set saved local context
@@ -81,6 +163,67 @@ impl DependencyContext {
     /// Then you can restore context with ```set_context```
     #[inline(always)]
     pub fn get_context(&self) -> Arc<LocalContext> { self.local_context.clone() }
+
+    /// Components, which are being constructed, from first resolved to current component
+    /// 
+    /// Empty for root context
+    ///# Example
+    ///---
+    /// ```ignore
+    /// // In LoggerComponent ctor, resolved by BillingService ctor: [BillingService, LoggerComponent]
+    /// let category = ctx.resolution_path().iter().rev().nth(1).map(|parent| parent.type_name.clone());
+    /// ```
+    #[inline(always)]
+    pub fn resolution_path(&self) -> &[TypeInfo] { self.id.resolution_path() }
+
+    /// Start contextual binding, which choose component for service, when it resolved from parent component ctor
+    /// 
+    /// Binding applied to ```resolve```, ```try_resolve```, ```resolve_collection``` and ```resolve_by_type_id```
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.when_injected_into::<BillingService>()
+    ///     .map::<dyn Logger, AuditLogger>().await;
+    /// ```
+    #[inline(always)]
+    pub fn when_injected_into<TParent: Sync + Send + 'static>(&self) -> ContextualBindingBuilder<TParent> {
+        ContextualBindingBuilder::new(self.core_context.clone())
+    }
+
+    // Save component registered by module context
+    #[inline(always)]
+    fn track_component<TComponent: Sync + Send + 'static>(&self, result: AddDependencyResult<ServiceMappingBuilder<TComponent>>) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        if let (Some(module_tracker), Ok(_)) = (&self.module_tracker, &result) {
+            module_tracker.components.lock().unwrap().push(TypeInfo::from_type::<TComponent>());
+        }
+
+        result
+    }
+
+    // Save component registered by module context, replaced component is not owned by module
+    #[inline(always)]
+    fn track_added_component<TComponent: Sync + Send + 'static>(&self, result: AddDependencyResult<(ServiceMappingBuilder<TComponent>, bool)>) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        let (service_mapping_builder, is_replaced) = result?;
+
+        if let (Some(module_tracker), false) = (&self.module_tracker, is_replaced) {
+            module_tracker.components.lock().unwrap().push(TypeInfo::from_type::<TComponent>());
+        }
+
+        Ok(service_mapping_builder)
+    }
+
+    // Save mapping added by module context
+    #[inline(always)]
+    fn track_mapping(&self, result: MapComponentResult<Arc<CycledComponentServicePair>>) -> MapComponentResult<Arc<CoreContext>> {
+        let component_service_pair = result?;
+
+        if let Some(module_tracker) = &self.module_tracker {
+            module_tracker.mappings.lock().unwrap().push(component_service_pair);
+        }
+
+        Ok(self.core_context.clone())
+    }
 }
 
 #[cfg(feature = "async-mode")]
@@ -121,7 +264,7 @@ impl DependencyContext {
     /// ```
     #[inline(always)]
     pub async fn register_type<TComponent: Constructor + Sync + Send + 'static>(&self, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
-        self.core_context.register::<TComponent>(Box::new(ComponentFromConstructor::<TComponent>::new()), life_cycle).await
+        self.track_component(self.core_context.register::<TComponent>(Box::new(ComponentFromConstructor::<TComponent>::new()), life_cycle).await)
     }
 
     /// Register component from async closure
@@ -167,7 +310,7 @@ impl DependencyContext {
         TClosure: Fn(DependencyContext) -> TFuture,
         TClosure: Sync + Send + 'static,
     {
-        self.core_context.register::<TComponent>(Box::new(ComponentFromAsyncClosure::<TComponent, TFuture, TClosure>::new(closure)), life_cycle).await
+        self.track_component(self.core_context.register::<TComponent>(Box::new(ComponentFromAsyncClosure::<TComponent, TFuture, TClosure>::new(closure)), life_cycle).await)
     }
 
     /// Register component from closure
@@ -206,7 +349,35 @@ impl DependencyContext {
     /// ```
     #[inline(always)]
     pub async fn register_closure<TComponent: Sync + Send + 'static, TClosure: Fn(DependencyContext) -> BuildDependencyResult<TComponent> + Sync + Send + 'static>(&self, closure: TClosure, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
-        self.core_context.register::<TComponent>(Box::new(ComponentFromClosure::<TComponent>::new(Box::new(closure))), life_cycle).await
+        self.track_component(self.core_context.register::<TComponent>(Box::new(ComponentFromClosure::<TComponent>::new(Box::new(closure))), life_cycle).await)
+    }
+
+    /// Register component from function, which arguments resolved from context
+    /// 
+    /// Every argument resolved like ```resolve::<TArgument>()```, failed argument returned as ```BuildDependencyError::ArgumentResolveError```
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_fn(|db: Arc<dyn Db>, log: Box<dyn Log>| Service::new(db, log), LifeCycle::Singleton).await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn register_fn<TComponent: Sync + Send + 'static, TArgs: 'static, TFunction: InjectFn<TArgs, TComponent> + 'static>(&self, function: TFunction, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_component(self.core_context.register::<TComponent>(Box::new(ComponentFromFn::<TComponent, TArgs, TFunction>::new(function)), life_cycle).await)
+    }
+
+    /// Register component from async function, which arguments resolved from context
+    /// 
+    /// Every argument resolved like ```resolve::<TArgument>()```, failed argument returned as ```BuildDependencyError::ArgumentResolveError```
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_async_fn(async |db: Arc<dyn Db>| Service::connect(db).await, LifeCycle::Singleton).await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn register_async_fn<TComponent: Sync + Send + 'static, TArgs: 'static, TFunction: InjectAsyncFn<TArgs, TComponent> + 'static>(&self, function: TFunction, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_component(self.core_context.register::<TComponent>(Box::new(ComponentFromAsyncFn::<TComponent, TArgs, TFunction>::new(function)), life_cycle).await)
     }
 
     /// Register component instance as singleton
@@ -240,7 +411,301 @@ impl DependencyContext {
     /// ```
     #[inline(always)]
     pub async fn register_instance<TComponent: Sync + Send + 'static>(&self, instance: TComponent) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
-        self.core_context.register::<TComponent>(Box::new(ComponentFromInstance::new(instance)), LifeCycle::Singleton).await
+        self.track_component(self.core_context.register::<TComponent>(Box::new(ComponentFromInstance::new(instance)), LifeCycle::Singleton).await)
+    }
+
+    /// Register shared instance as singleton, resolved as ```Arc<TComponent>```
+    /// 
+    /// Every resolve return clone of registered Arc, instance is not moved into context
+    /// 
+    /// Component registered as ```TComponent```, so it can be mapped and deleted like other singleton
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let shared = Arc::new(SomeComponent::new());
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_arc(shared.clone()).await.unwrap()
+    ///     .map_as::<dyn SomeService>().await.unwrap();
+    /// 
+    /// let resolved = root_context.resolve::<Arc<SomeComponent>>().await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn register_arc<TComponent: Sync + Send + 'static>(&self, instance: Arc<TComponent>) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_component(self.core_context.register::<TComponent>(Box::new(ComponentFromSharedInstance::new(instance)), LifeCycle::Singleton).await)
+    }
+
+    /// Register shared service instance without component type, resolved as ```Arc<TService>```
+    /// 
+    /// Every resolve return clone of registered Arc, instance is not moved into context
+    /// 
+    /// Unsized service can't be singleton component, so component registered as transient ```Arc<TService>```, which ctor return registered Arc like ```register_arc```    
+    /// Interceptors and decorators are called on every resolve, use ```register_arc``` for sized component
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let shared: Arc<dyn SomeService> = Arc::new(SomeComponent::new());
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_service_instance::<dyn SomeService>(shared).await.unwrap();
+    /// 
+    /// let resolved = root_context.resolve::<Arc<dyn SomeService>>().await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn register_service_instance<TService: ?Sized + Sync + Send + 'static>(&self, instance: Arc<TService>) -> AddDependencyResult<()> {
+        self.track_component(self.core_context.register::<Arc<TService>>(Box::new(ComponentFromArc::new(instance)), LifeCycle::Transient).await).map(|_| ())
+    }
+
+    /// Register transient component, created by template clone
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_prototype(RequestOptions { timeout: 30 }).await.unwrap()
+    ///     .map_as::<dyn Options>().await.unwrap();
+    /// 
+    /// let options = root_context.resolve::<Box<dyn Options>>().await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn register_prototype<TComponent: Clone + Sync + Send + 'static>(&self, template: TComponent) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_component(self.core_context.register::<TComponent>(Box::new(ComponentFromPrototype::new(template)), LifeCycle::Transient).await)
+    }
+
+    /// Register transient component from closure with args, passed on ```resolve_with```
+    ///
+    /// Component resolved without args, or with args of other type, return ```BuildDependencyError::ArgsTypeMismatch```
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent { tenant_id: u64 }
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_closure_with_args(|_ctx, tenant_id: u64| Ok(SomeComponent { tenant_id })).await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn register_closure_with_args<TComponent: Sync + Send + 'static, TArgs: Sync + Send + 'static, TClosure: Fn(DependencyContext, TArgs) -> BuildDependencyResult<TComponent> + Sync + Send + 'static>(&self, closure: TClosure) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_component(self.core_context.register::<TComponent>(Box::new(ComponentFromClosureWithArgs::<TComponent, TArgs>::new(Box::new(closure))), LifeCycle::Transient).await)
+    }
+
+    /// Register transient component with ```ConstructorWith``` args, passed on ```resolve_with```
+    ///
+    /// Component resolved without args, or with args of other type, return ```BuildDependencyError::ArgsTypeMismatch```
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_type_with_args::<SomeComponent, (TenantId, Period)>().await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn register_type_with_args<TComponent: ConstructorWith<TArgs> + Sync + Send + 'static, TArgs: Sync + Send + 'static>(&self) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_component(self.core_context.register::<TComponent>(Box::new(ComponentFromConstructorWithArgs::<TComponent, TArgs>::new()), LifeCycle::Transient).await)
+    }
+
+    /// Register component witch implement trait Constructor as composite service
+    /// 
+    /// Composite resolved instead of other service implementations, but not returned from ```resolve_collection```    
+    /// So composite can wrap collection of all other implementations    
+    /// Return mapping error, if component deleted before mapped
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct FanOutSink { sinks: Vec<Arc<dyn EventSink>> }
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_composite::<dyn EventSink, FanOutSink>(LifeCycle::Singleton).await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn register_composite<TService: ?Sized + Sync + Send + 'static, TComponent: Constructor + Sync + Send + 'static>(&self, life_cycle: LifeCycle) -> RegisterCompositeResult<ServiceMappingBuilder<TComponent>> where TComponent: Unsize<TService> {
+        // Component can be deleted before mapped, so mapping error returned
+        self.register_type::<TComponent>(life_cycle).await
+            .map_err(|err| RegisterCompositeError::AddDependencyError { err })?
+            .map_as_composite::<TService>().await
+            .map_err(|err| RegisterCompositeError::MapComponentError { err })
+    }
+
+    /// Register component witch implement trait Constructor, or replace registered component
+    /// 
+    /// Replace swap component ctor, drop created singleton and reset loop-check links of component
+    /// 
+    /// If life cycle not changed, service mappings kept, otherwise mappings removed and component must be mapped again
+    /// 
+    /// Context dependent instances, already created in local contexts, not changed
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_type_or_replace::<SomeComponent>(LifeCycle::Transient).await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn register_type_or_replace<TComponent: Constructor + Sync + Send + 'static>(&self, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_added_component(self.core_context.register_or_replace::<TComponent>(Box::new(ComponentFromConstructor::<TComponent>::new()), life_cycle).await)
+    }
+
+    /// Register component from async closure, or replace registered component
+    /// 
+    /// Replace swap component ctor, drop created singleton and reset loop-check links of component
+    /// 
+    /// If life cycle not changed, service mappings kept, otherwise mappings removed and component must be mapped again
+    /// 
+    /// Context dependent instances, already created in local contexts, not changed
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_async_closure_or_replace(async |_ctx| Ok(SomeComponent::new()), LifeCycle::Transient).await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn register_async_closure_or_replace<TComponent, TFuture, TClosure>(&self, closure: TClosure, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>>
+    where
+        TComponent: Sync + Send + 'static,
+        TFuture: Future<Output = BuildDependencyResult<TComponent>>,
+        TFuture: Sync + Send + 'static,
+        TClosure: Fn(DependencyContext) -> TFuture,
+        TClosure: Sync + Send + 'static,
+    {
+        self.track_added_component(self.core_context.register_or_replace::<TComponent>(Box::new(ComponentFromAsyncClosure::<TComponent, TFuture, TClosure>::new(closure)), life_cycle).await)
+    }
+
+    /// Register component from closure, or replace registered component
+    /// 
+    /// Replace swap component ctor, drop created singleton and reset loop-check links of component
+    /// 
+    /// If life cycle not changed, service mappings kept, otherwise mappings removed and component must be mapped again
+    /// 
+    /// Context dependent instances, already created in local contexts, not changed
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_closure_or_replace(|_ctx| Ok(SomeComponent::new()), LifeCycle::Transient).await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn register_closure_or_replace<TComponent: Sync + Send + 'static, TClosure: Fn(DependencyContext) -> BuildDependencyResult<TComponent> + Sync + Send + 'static>(&self, closure: TClosure, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_added_component(self.core_context.register_or_replace::<TComponent>(Box::new(ComponentFromClosure::<TComponent>::new(Box::new(closure))), life_cycle).await)
+    }
+
+    /// Register component instance as singleton, or replace registered component
+    /// 
+    /// Replace swap component ctor, drop created singleton and reset loop-check links of component
+    /// 
+    /// If life cycle not changed, service mappings kept, otherwise mappings removed and component must be mapped again
+    /// 
+    /// Context dependent instances, already created in local contexts, not changed
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_instance_or_replace::<SomeComponent>(SomeComponent::new()).await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn register_instance_or_replace<TComponent: Sync + Send + 'static>(&self, instance: TComponent) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_added_component(self.core_context.register_or_replace::<TComponent>(Box::new(ComponentFromInstance::new(instance)), LifeCycle::Singleton).await)
+    }
+
+    /// Register all components, submitted by ```#[component]``` attribute, and map them to services
+    /// 
+    /// Components discovered from all linked crates, registration order not guaranteed
+    ///# Example
+    ///---
+    /// ```ignore
+    /// #[component(lifecycle = Singleton, services(dyn SomeService))]
+    /// #[derive(Constructor)]
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_discovered().await.unwrap();
+    /// ```
+    #[cfg(feature = "discovery")]
+    pub async fn register_discovered(&self) -> RegisterDiscoveredResult<()> {
+        for discovered_component in inventory::iter::<DiscoveredComponent> {
+            (discovered_component.register)(self.clone()).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Register service decorator
+    ///
+    /// Resolved service is wrapped by decorators in registration order, decorator receive previous service as inner
+    /// 
+    /// Singleton and context dependent service decorated once and cached with component instance
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_decorator::<Box<dyn Repo>, CachingRepo>().await;
+    /// ```
+    #[inline(always)]
+    pub async fn register_decorator<TService: Sync + Send + 'static, TDecorator: Decorator<TService>>(&self) {
+        self.core_context.add_decorator::<TService>(Arc::new(DecoratorFromType::<TService, TDecorator>::new())).await
+    }
+
+    /// Register service decorator from closure
+    ///
+    /// Resolved service is wrapped by decorators in registration order, decorator receive previous service as inner
+    /// 
+    /// Singleton and context dependent service decorated once and cached with component instance
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_decorator_closure(|_ctx, inner: Box<dyn Repo>| Ok(Box::new(CachingRepo::new(inner)) as Box<dyn Repo>)).await;
+    /// ```
+    #[inline(always)]
+    pub async fn register_decorator_closure<TService: Sync + Send + 'static, TClosure: Fn(DependencyContext, TService) -> BuildDependencyResult<TService> + Sync + Send + 'static>(&self, closure: TClosure) {
+        self.core_context.add_decorator::<TService>(Arc::new(DecoratorFromClosure::<TService>::new(Box::new(closure)))).await
+    }
+
+    /// Add interceptor, called around every component ctor
+    ///
+    /// Interceptor can observe construction or replace it with own instance or error
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.add_interceptor(AuditInterceptor {}).await;
+    /// ```
+    #[inline(always)]
+    pub async fn add_interceptor<TInterceptor: ResolveInterceptor>(&self, interceptor: TInterceptor) {
+        self.core_context.add_interceptor(Arc::new(interceptor)).await
+    }
+
+    /// Add resolution source, asked for service without mappings by ```resolve```, ```try_resolve``` and ```resolve_collection```
+    ///
+    /// Sources asked in adding order, source can return service instance or register component for service
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.add_resolution_source(EnvSource {}).await;
+    /// ```
+    #[inline(always)]
+    pub async fn add_resolution_source<TResolutionSource: ResolutionSource>(&self, resolution_source: TResolutionSource) {
+        self.core_context.add_resolution_source(Arc::new(resolution_source)).await
+    }
+
+    /// Add alias, which resolve current target service as alias service
+    ///
+    /// Alias follow target changes, so replaced or deleted target affect alias too
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.alias::<Arc<dyn LegacyStore>, Arc<dyn Store>>().await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn alias<TAlias: Sync + Send + 'static, TTarget: Sync + Send + 'static + CoerceUnsized<TAlias>>(&self) -> AddDependencyResult<()> {
+        self.core_context.add_alias::<TAlias, TTarget>().await
     }
 
     /// Map component as service
@@ -278,7 +743,7 @@ impl DependencyContext {
     /// ```
     #[inline(always)]
     pub async fn map_component<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static>(&self) -> MapComponentResult<Arc<CoreContext>> where TComponent: Unsize<TService> {
-        self.core_context.map_component::<TComponent, TService>().await
+        self.track_mapping(self.core_context.map_component::<TComponent, TService>().await)
     }
 
     /// Resolve first component, mapped to service
@@ -327,13 +792,79 @@ impl DependencyContext {
     /// ```
     #[inline(always)]
     pub async fn resolve<'a, TService: Sync + Send + 'static>(&'a self) -> BuildDependencyResult<TService> {
+        if let Some(service) = TService::build_implicit(self) {
+            return Ok(service);
+        }
+
         self.core_context.resolve::<TService>(self.id.clone(), self.local_context.clone()).await
     }
 
-    /// Resolve component with TypeId, mapped to service
-    /// That may be helpful in case, when you need current component, type is lost, but you can save TypeId as variable
-    /// ``` ignore
-    /// // You can resolve transient like:
+    /// Resolve service, or None if service not registered
+    ///
+    /// Unlike ```is_service_exist``` + ```resolve```, service searched once. Construction errors are propagated
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let plugin: Option<Box<dyn SomePlugin>> = root_context.try_resolve::<Box<dyn SomePlugin>>().await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn try_resolve<TService: Sync + Send + 'static>(&self) -> BuildDependencyResult<Option<TService>> {
+        if let Some(service) = TService::build_implicit(self) {
+            return Ok(Some(service));
+        }
+
+        self.core_context.try_resolve::<TService>(self.id.clone(), self.local_context.clone()).await
+    }
+
+    /// Resolve component, registered on demand, if it not registered
+    /// 
+    /// Life cycle chosen by service type: ```T``` - Transient, ```Arc<T>``` - Singleton, ```Weak<T>``` - ContextDependent    
+    /// If ```ContextSettings::auto_wired_life_cycle``` set, other life cycle return ```AddDependencyError::LifeCycleMismatch``` before registration    
+    /// Already registered component resolved as usual
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// let transient: SomeComponent = root_context.resolve_or_construct().await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn resolve_or_construct<TService: AutoWired>(&self) -> BuildDependencyResult<TService> {
+        self.register_auto_wired::<TService>().await
+            .map_err(|err| BuildDependencyError::AddDependencyError { err })?;
+
+        self.resolve::<TService>().await
+    }
+
+    // Register component of auto wired service, if it not registered
+    pub (crate) async fn register_auto_wired<TService: AutoWired>(&self) -> AddDependencyResult<()> {
+        if self.is_component_exist::<TService::Component>().await {
+            return Ok(());
+        }
+
+        // Service type define life cycle of resolved component, so other life cycle can't be registered
+        if let Some(life_cycle) = self.core_context.settings.auto_wired_life_cycle {
+            if life_cycle != TService::LIFE_CYCLE {
+                return Err(AddDependencyError::LifeCycleMismatch {
+                    component_type_info: TypeInfo::from_type::<TService::Component>(),
+                    expected_life_cycle: life_cycle,
+                    found_life_cycle: TService::LIFE_CYCLE,
+                });
+            }
+        }
+
+        // Component can be registered concurrently, then registered component resolved
+        match self.register_type::<TService::Component>(TService::LIFE_CYCLE).await {
+            Ok(_) | Err(AddDependencyError::DependencyExist { .. }) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Resolve component with TypeId, mapped to service
+    /// That may be helpful in case, when you need current component, type is lost, but you can save TypeId as variable
+    /// ``` ignore
+    /// // You can resolve transient like:
     /// ctx.resolve_by_type_id::<Box<dyn Service>>(TypeId::of::<Component>()).await.unwrap()
     /// ctx.resolve_by_type_id::<Box<Component>>(TypeId::of::<Component>()).await.unwrap()
     /// 
@@ -382,6 +913,50 @@ impl DependencyContext {
         self.core_context.resolve_by_type_id::<TService>(component_type_id, self.id.clone(), self.local_context.clone()).await
     }
 
+    /// Resolve component, mapped to service with name
+    /// 
+    /// Service must be mapped with ```map_as_named```, unnamed mappings not used
+    /// ``` ignore
+    /// // You can resolve transient like:
+    /// ctx.resolve_named::<Box<dyn Service>>("name").await.unwrap()
+    /// 
+    /// // You can resolve singleton like:
+    /// ctx.resolve_named::<Arc<dyn Service>>("name").await.unwrap()
+    /// 
+    /// // You can resolve context dependent like:
+    /// ctx.resolve_named::<Weak<dyn Service>>("name").await.unwrap()
+    /// ```
+    ///# Example
+    ///---
+    /// From root context
+    /// ```ignore
+    /// trait SomeService {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// let service: Box<dyn SomeTrait> = root_context.resolve_named::<Box<dyn SomeService>>("primary").await.unwrap();
+    /// ```
+    /// 
+    /// ---
+    /// 
+    /// From ctor context
+    /// ```ignore
+    /// trait SomeService {}
+    /// 
+    /// #[async_trait_with_sync::async_trait(Sync)]
+    /// impl Constructor for SomeOtherComponent {
+    ///     async fn ctor(ctx: crate::DependencyContext) -> BuildDependencyResult<Self> {
+    ///         // unwrap or map error to BuildDependencyError
+    ///         let service: Box<dyn SomeTrait> = ctx.resolve_named::<Box<dyn SomeService>>("primary").await.unwrap();
+    ///         Ok(Self { })
+    ///     }
+    /// }
+    /// 
+    /// ```
+    #[inline(always)]
+    pub async fn resolve_named<TService: Sync + Send + 'static>(&self, name: &str) -> BuildDependencyResult<TService> {
+        self.core_context.resolve_named::<TService>(name, self.id.clone(), self.local_context.clone()).await
+    }
+
     /// Resolve all component, mapped to service
     /// ``` ignore
     /// // You can resolve transient like:
@@ -431,6 +1006,76 @@ impl DependencyContext {
         self.core_context.resolve_collection::<TService>(self.id.clone(), self.local_context.clone()).await
     }
 
+    /// Resolve services, which mapping metadata match filter
+    ///
+    /// Filtered out components are not constructed    
+    /// Aliases and contextual bindings applied like in ```resolve_collection```, alias filter target metadata
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let http_handlers = root_context.resolve_collection_where::<Box<dyn Handler>, _>(|metadata| metadata.tag("http")).await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn resolve_collection_where<TService: Sync + Send + 'static, TFilter: Fn(&ServiceMetadata) -> bool + Sync + Send + 'static>(&self, filter: TFilter) -> BuildDependencyResult<Vec<TService>> {
+        self.core_context.resolve_collection_where::<TService, TFilter>(filter, self.id.clone(), self.local_context.clone()).await
+    }
+
+    /// Get mapping info of services, returned by ```resolve_collection```, without construction
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let handlers_info = root_context.get_collection_metadata::<Box<dyn Handler>>().await;
+    /// let routes: Vec<_> = handlers_info.iter().filter_map(|info| info.metadata.value("route")).collect();
+    /// ```
+    #[inline(always)]
+    pub async fn get_collection_metadata<TService: 'static>(&self) -> Vec<ServiceInfo> {
+        self.core_context.get_collection_metadata::<TService>().await
+    }
+
+    /// Resolve service from component, registered with args
+    ///
+    /// Args type checked with registered args type, mismatch return ```BuildDependencyError::ArgsTypeMismatch```    
+    /// Aliases and contextual bindings applied like in ```resolve```
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let report = root_context.resolve_with::<Box<dyn Report>, (TenantId, Period)>((tenant_id, period)).await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn resolve_with<TService: Sync + Send + 'static, TArgs: Sync + Send + 'static>(&self, args: TArgs) -> BuildDependencyResult<TService> {
+        self.core_context.resolve_with::<TService, TArgs>(args, self.id.clone(), self.local_context.clone()).await
+    }
+
+    /// Call function, which arguments resolved from context
+    /// 
+    /// Arguments resolved like ```resolve::<TArgument>()```, with current local context    
+    /// Failed argument returned as ```BuildDependencyError::ArgumentResolveError```, with function result type as component
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// let result = root_context.invoke(|db: Arc<dyn Db>, log: Box<dyn Log>| run_migrations(db, log)).await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn invoke<TResult: 'static, TArgs: 'static, TFunction: InjectFn<TArgs, TResult>>(&self, function: TFunction) -> BuildDependencyResult<TResult> {
+        function.call(self).await
+    }
+
+    /// Call async function, which arguments resolved from context
+    /// 
+    /// Arguments resolved like ```resolve::<TArgument>()```, with current local context    
+    /// Failed argument returned as ```BuildDependencyError::ArgumentResolveError```, with function result type as component
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// let result = root_context.invoke_async(|db: Arc<dyn Db>| async move { run_migrations(db).await }).await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn invoke_async<TResult: 'static, TArgs: 'static, TFunction: InjectAsyncFn<TArgs, TResult>>(&self, function: TFunction) -> BuildDependencyResult<TResult> {
+        function.call(self).await
+    }
+
     /// Delete component
     ///# Example
     ///---
@@ -463,6 +1108,97 @@ impl DependencyContext {
         self.core_context.delete_component::<TComponent>().await
     }
 
+    /// Delete all components, which have mapping with tag
+    ///
+    /// Return deleted components. If any component not support delete, nothing deleted
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let deleted_components = root_context.delete_components_with_tag("http").await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn delete_components_with_tag(&self, tag: &str) -> DeleteComponentResult<Vec<TypeInfo>> {
+        self.core_context.delete_components_with_tag(tag).await
+    }
+
+    /// Install module
+    /// 
+    /// Components registered and mapped by module context are saved, to delete them on uninstall
+    /// 
+    /// Module dependencies must be installed before. If install failed, registered components are deleted    
+    /// Module can't register context dependent components, they can't be deleted on uninstall
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.install_module(SomeModule::new()).await.unwrap();
+    /// ```
+    pub async fn install_module<TModule: DependencyModule>(&self, module: TModule) -> InstallModuleResult<()> {
+        let module_type_info = TypeInfo::from_type::<TModule>();
+        let dependencies = module.dependencies();
+
+        self.core_context.check_module_install(&module_type_info, &dependencies).await?;
+
+        let module_tracker = Arc::new(ModuleTracker::default());
+        let module_context = Self { module_tracker: Some(module_tracker.clone()), ..self.clone() };
+
+        let install_result = module.install(&module_context).await;
+
+        let components = std::mem::take(&mut *module_tracker.components.lock().unwrap());
+        let mappings = std::mem::take(&mut *module_tracker.mappings.lock().unwrap());
+
+        if let Err(err) = install_result {
+            self.core_context.delete_module_registrations(&components, &mappings).await;
+            return Err(err);
+        }
+
+        let installed_module = InstalledModule {
+            module: Arc::new(module),
+            module_type_info: module_type_info.clone(),
+            dependencies,
+            components: components.clone(),
+            mappings: mappings.clone(),
+        };
+
+        if let Err(err) = self.core_context.add_module(&module_type_info, installed_module).await {
+            self.core_context.delete_module_registrations(&components, &mappings).await;
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    /// Uninstall module and delete all components and mappings, added by module
+    /// 
+    /// Module can't be uninstalled, while other installed module depends on it    
+    /// Module uninstall hook is called before final check, so failed uninstall can call hook again on retry
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.install_module(SomeModule::new()).await.unwrap();
+    /// root_context.uninstall_module::<SomeModule>().await.unwrap();
+    /// ```
+    pub async fn uninstall_module<TModule: DependencyModule>(&self) -> UninstallModuleResult<()> {
+        let module_type_info = TypeInfo::from_type::<TModule>();
+
+        let module = self.core_context.get_module_for_uninstall(&module_type_info).await?;
+        module.uninstall(self).await?;
+
+        self.core_context.delete_module(&module_type_info).await
+    }
+
+    /// Check module installed
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// let is_installed = root_context.is_module_installed::<SomeModule>().await;
+    /// ```
+    pub async fn is_module_installed<TModule: DependencyModule>(&self) -> bool {
+        self.core_context.is_module_installed(TypeId::of::<TModule>()).await
+    }
+
     /// Check service existence
     /// ``` ignore
     /// // You can check transient like:
@@ -559,6 +1295,47 @@ impl DependencyContext {
         self.core_context.is_service_exist(service_type_id).await
     }
 
+    /// Check named service existence
+    /// ``` ignore
+    /// // You can check transient like:
+    /// ctx.is_named_service_exist::<Box<dyn Service>>("name").await
+    /// 
+    /// // You can check singleton like:
+    /// ctx.is_named_service_exist::<Arc<dyn Service>>("name").await
+    /// 
+    /// // You can check context dependent like:
+    /// ctx.is_named_service_exist::<Weak<dyn Service>>("name").await
+    /// ```
+    ///# Example
+    ///---
+    /// From root context
+    /// ```ignore
+    /// trait SomeService {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// let is_exist: bool = root_context.is_named_service_exist::<Box<dyn SomeService>>("primary").await;
+    /// ```
+    /// 
+    /// ---
+    /// 
+    /// From ctor context
+    /// ```ignore
+    /// trait SomeService {}
+    /// 
+    /// #[async_trait_with_sync::async_trait(Sync)]
+    /// impl Constructor for SomeOtherComponent {
+    ///     async fn ctor(ctx: crate::DependencyContext) -> BuildDependencyResult<Self> {
+    ///         let is_exist: bool = ctx.is_named_service_exist::<Box<dyn SomeService>>("primary").await;
+    ///         Ok(Self { })
+    ///     }
+    /// }
+    /// 
+    /// ```
+    #[inline(always)]
+    pub async fn is_named_service_exist<TService: 'static>(&self, name: &str) -> bool {
+        self.core_context.is_named_service_exist(TypeId::of::<TService>(), name).await
+    }
+
     /// Check component existence
     ///# Example
     ///---
@@ -662,7 +1439,7 @@ impl DependencyContext {
     pub fn blocking_register_type<TComponent: Constructor + Sync + Send + 'static>(&self, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
         let self_copy = self.clone();
         std::thread::spawn(move || {
-            self_copy.core_context.blocking_register::<TComponent>(Box::new(ComponentFromConstructor::<TComponent>::new()), life_cycle)
+            self_copy.track_component(self_copy.core_context.blocking_register::<TComponent>(Box::new(ComponentFromConstructor::<TComponent>::new()), life_cycle))
         }).join().unwrap()
     }
 
@@ -711,7 +1488,7 @@ impl DependencyContext {
     {
         let self_copy = self.clone();
         std::thread::spawn(move || {
-            self_copy.core_context.blocking_register::<TComponent>(Box::new(ComponentFromAsyncClosure::<TComponent, TFuture, TClosure>::new(closure)), life_cycle)
+            self_copy.track_component(self_copy.core_context.blocking_register::<TComponent>(Box::new(ComponentFromAsyncClosure::<TComponent, TFuture, TClosure>::new(closure)), life_cycle))
         }).join().unwrap()
     }
 
@@ -753,7 +1530,41 @@ impl DependencyContext {
     pub fn blocking_register_closure<TComponent: Sync + Send + 'static, TClosure: Fn(DependencyContext) -> BuildDependencyResult<TComponent> + Sync + Send + 'static>(&self, closure: TClosure, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
         let self_copy = self.clone();
         std::thread::spawn(move || {
-            self_copy.core_context.blocking_register::<TComponent>(Box::new(ComponentFromClosure::<TComponent>::new(Box::new(closure))), life_cycle)
+            self_copy.track_component(self_copy.core_context.blocking_register::<TComponent>(Box::new(ComponentFromClosure::<TComponent>::new(Box::new(closure))), life_cycle))
+        }).join().unwrap()
+    }
+
+    /// Register component from function, which arguments resolved from context (blocking version)
+    /// 
+    /// Every argument resolved like ```resolve::<TArgument>()```, failed argument returned as ```BuildDependencyError::ArgumentResolveError```
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_register_fn(|db: Arc<dyn Db>, log: Box<dyn Log>| Service::new(db, log), LifeCycle::Singleton).unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_register_fn<TComponent: Sync + Send + 'static, TArgs: 'static, TFunction: InjectFn<TArgs, TComponent> + 'static>(&self, function: TFunction, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.track_component(self_copy.core_context.blocking_register::<TComponent>(Box::new(ComponentFromFn::<TComponent, TArgs, TFunction>::new(function)), life_cycle))
+        }).join().unwrap()
+    }
+
+    /// Register component from async function, which arguments resolved from context (blocking version)
+    /// 
+    /// Every argument resolved like ```resolve::<TArgument>()```, failed argument returned as ```BuildDependencyError::ArgumentResolveError```
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_register_async_fn(async |db: Arc<dyn Db>| Service::connect(db).await, LifeCycle::Singleton).unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_register_async_fn<TComponent: Sync + Send + 'static, TArgs: 'static, TFunction: InjectAsyncFn<TArgs, TComponent> + 'static>(&self, function: TFunction, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.track_component(self_copy.core_context.blocking_register::<TComponent>(Box::new(ComponentFromAsyncFn::<TComponent, TArgs, TFunction>::new(function)), life_cycle))
         }).join().unwrap()
     }
 
@@ -790,80 +1601,416 @@ impl DependencyContext {
     pub fn blocking_register_instance<TComponent: Sync + Send + 'static>(&self, instance: TComponent) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
         let self_copy = self.clone();
         std::thread::spawn(move || {
-            self_copy.core_context.blocking_register::<TComponent>(Box::new(ComponentFromInstance::new(instance)), LifeCycle::Singleton)
+            self_copy.track_component(self_copy.core_context.blocking_register::<TComponent>(Box::new(ComponentFromInstance::new(instance)), LifeCycle::Singleton))
         }).join().unwrap()
     }
 
-    /// Map component as service (blocking version)
+    /// Register shared instance as singleton, resolved as ```Arc<TComponent>``` (blocking version)
+    /// 
+    /// Every resolve return clone of registered Arc, instance is not moved into context
+    /// 
+    /// Component registered as ```TComponent```, so it can be mapped and deleted like other singleton
     ///# Example
     ///---
-    /// From root context
     /// ```ignore
-    /// struct SomeComponent {}
-    /// trait SomeService {}
+    /// let shared = Arc::new(SomeComponent::new());
     /// 
     /// let root_context = DependencyContext::new_root();
-    /// //...
-    /// 
-    /// // SomeComponent must be registered, or will be error
-    /// root_context.blocking_map_component::<SomeComponent, dyn SomeService>().unwrap();
-    /// ```
-    /// 
-    /// ---
-    /// 
-    /// From ctor context
-    /// ```ignore
-    /// struct SomeComponent {}
-    /// trait SomeService {}
-    /// 
-    /// #[async_trait_with_sync::async_trait(Sync)]
-    /// impl Constructor for SomeOtherComponent {
-    ///     async fn ctor(ctx: crate::DependencyContext) -> BuildDependencyResult<Self> {
-    ///         // SomeComponent must be registered, or will be error
-    ///         // unwrap or map error to BuildDependencyError
-    ///         ctx.blocking_map_component::<SomeComponent, dyn SomeService>().unwrap();
-    ///         Ok(Self { })
-    ///     }
-    /// }
+    /// root_context.blocking_register_arc(shared.clone()).unwrap()
+    ///     .blocking_map_as::<dyn SomeService>().unwrap();
     /// 
+    /// let resolved = root_context.blocking_resolve::<Arc<SomeComponent>>().unwrap();
     /// ```
     #[inline(always)]
-    pub fn blocking_map_component<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static>(&self) -> MapComponentResult<Arc<CoreContext>> where TComponent: Unsize<TService> {
+    pub fn blocking_register_arc<TComponent: Sync + Send + 'static>(&self, instance: Arc<TComponent>) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
         let self_copy = self.clone();
         std::thread::spawn(move || {
-            self_copy.core_context.blocking_map_component::<TComponent, TService>()
+            self_copy.track_component(self_copy.core_context.blocking_register::<TComponent>(Box::new(ComponentFromSharedInstance::new(instance)), LifeCycle::Singleton))
         }).join().unwrap()
     }
 
-    /// Resolve first component, mapped to service (blocking version)
-    /// ``` ignore
-    /// // You can resolve transient like:
-    /// ctx.blocking_resolve::<Box<dyn Service>>().unwrap()
-    /// ctx.blocking_resolve::<Box<Component>>().unwrap()
+    /// Register shared service instance without component type, resolved as ```Arc<TService>``` (blocking version)
     /// 
-    /// // You can resolve singleton like:
-    /// ctx.blocking_resolve::<Arc<dyn Service>>().unwrap()
-    /// ctx.blocking_resolve::<Arc<Component>>().unwrap()
-    /// 
-    /// // You can resolve context dependent like:
-    /// ctx.blocking_resolve::<Weak<dyn Service>>().unwrap()
-    /// ctx.blocking_resolve::<Weak<Component>>().unwrap()
+    /// Every resolve return clone of registered Arc, instance is not moved into context
     /// 
-    /// // for mutable Arc and Weak, register as RwLock<Component>, map as RwLock<dyn Service>, and resolve like:
-    /// ctx.blocking_resolve::<Weak<RwLock<dyn Service>>>().unwrap()
-    ///  
-    /// ```
+    /// Unsized service can't be singleton component, so component registered as transient ```Arc<TService>```, which ctor return registered Arc like ```register_arc```    
+    /// Interceptors and decorators are called on every resolve, use ```register_arc``` for sized component
     ///# Example
     ///---
-    /// From root context
     /// ```ignore
-    /// trait SomeService {}
+    /// let shared: Arc<dyn SomeService> = Arc::new(SomeComponent::new());
     /// 
     /// let root_context = DependencyContext::new_root();
-    /// let service: Box<dyn SomeTrait> = root_context.blocking_resolve::<Box<dyn SomeService>>().unwrap();
-    /// ```
+    /// root_context.blocking_register_service_instance::<dyn SomeService>(shared).unwrap();
     /// 
-    /// ---
+    /// let resolved = root_context.blocking_resolve::<Arc<dyn SomeService>>().unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_register_service_instance<TService: ?Sized + Sync + Send + 'static>(&self, instance: Arc<TService>) -> AddDependencyResult<()> {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.track_component(self_copy.core_context.blocking_register::<Arc<TService>>(Box::new(ComponentFromArc::new(instance)), LifeCycle::Transient)).map(|_| ())
+        }).join().unwrap()
+    }
+
+    /// Register transient component, created by template clone (blocking version)
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_register_prototype(RequestOptions { timeout: 30 }).unwrap()
+    ///     .blocking_map_as::<dyn Options>().unwrap();
+    /// 
+    /// let options = root_context.blocking_resolve::<Box<dyn Options>>().unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_register_prototype<TComponent: Clone + Sync + Send + 'static>(&self, template: TComponent) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.track_component(self_copy.core_context.blocking_register::<TComponent>(Box::new(ComponentFromPrototype::new(template)), LifeCycle::Transient))
+        }).join().unwrap()
+    }
+
+    /// Register transient component from closure with args, passed on ```resolve_with``` (blocking version)
+    ///
+    /// Component resolved without args, or with args of other type, return ```BuildDependencyError::ArgsTypeMismatch```
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent { tenant_id: u64 }
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_register_closure_with_args(|_ctx, tenant_id: u64| Ok(SomeComponent { tenant_id })).unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_register_closure_with_args<TComponent: Sync + Send + 'static, TArgs: Sync + Send + 'static, TClosure: Fn(DependencyContext, TArgs) -> BuildDependencyResult<TComponent> + Sync + Send + 'static>(&self, closure: TClosure) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.track_component(self_copy.core_context.blocking_register::<TComponent>(Box::new(ComponentFromClosureWithArgs::<TComponent, TArgs>::new(Box::new(closure))), LifeCycle::Transient))
+        }).join().unwrap()
+    }
+
+    /// Register transient component with ```ConstructorWith``` args, passed on ```resolve_with``` (blocking version)
+    ///
+    /// Component resolved without args, or with args of other type, return ```BuildDependencyError::ArgsTypeMismatch```
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_register_type_with_args::<SomeComponent, (TenantId, Period)>().unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_register_type_with_args<TComponent: ConstructorWith<TArgs> + Sync + Send + 'static, TArgs: Sync + Send + 'static>(&self) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.track_component(self_copy.core_context.blocking_register::<TComponent>(Box::new(ComponentFromConstructorWithArgs::<TComponent, TArgs>::new()), LifeCycle::Transient))
+        }).join().unwrap()
+    }
+
+    /// Register component witch implement trait Constructor as composite service (blocking version)
+    /// 
+    /// Composite resolved instead of other service implementations, but not returned from ```resolve_collection```    
+    /// So composite can wrap collection of all other implementations    
+    /// Return mapping error, if component deleted before mapped
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct FanOutSink { sinks: Vec<Arc<dyn EventSink>> }
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_register_composite::<dyn EventSink, FanOutSink>(LifeCycle::Singleton).unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_register_composite<TService: ?Sized + Sync + Send + 'static, TComponent: Constructor + Sync + Send + 'static>(&self, life_cycle: LifeCycle) -> RegisterCompositeResult<ServiceMappingBuilder<TComponent>> where TComponent: Unsize<TService> {
+        // Component can be deleted before mapped, so mapping error returned
+        self.blocking_register_type::<TComponent>(life_cycle)
+            .map_err(|err| RegisterCompositeError::AddDependencyError { err })?
+            .blocking_map_as_composite::<TService>()
+            .map_err(|err| RegisterCompositeError::MapComponentError { err })
+    }
+
+    /// Register component witch implement trait Constructor, or replace registered component (blocking version)
+    /// 
+    /// Replace swap component ctor, drop created singleton and reset loop-check links of component
+    /// 
+    /// If life cycle not changed, service mappings kept, otherwise mappings removed and component must be mapped again
+    /// 
+    /// Context dependent instances, already created in local contexts, not changed
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_register_type_or_replace::<SomeComponent>(LifeCycle::Transient).unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_register_type_or_replace<TComponent: Constructor + Sync + Send + 'static>(&self, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.track_added_component(self_copy.core_context.blocking_register_or_replace::<TComponent>(Box::new(ComponentFromConstructor::<TComponent>::new()), life_cycle))
+        }).join().unwrap()
+    }
+
+    /// Register component from async closure, or replace registered component (blocking version)
+    /// 
+    /// Replace swap component ctor, drop created singleton and reset loop-check links of component
+    /// 
+    /// If life cycle not changed, service mappings kept, otherwise mappings removed and component must be mapped again
+    /// 
+    /// Context dependent instances, already created in local contexts, not changed
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_register_async_closure_or_replace(async |_ctx| Ok(SomeComponent::new()), LifeCycle::Transient).unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_register_async_closure_or_replace<TComponent, TFuture, TClosure>(&self, closure: TClosure, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>>
+    where
+        TComponent: Sync + Send + 'static,
+        TFuture: Future<Output = BuildDependencyResult<TComponent>>,
+        TFuture: Sync + Send + 'static,
+        TClosure: Fn(DependencyContext) -> TFuture,
+        TClosure: Sync + Send + 'static,
+    {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.track_added_component(self_copy.core_context.blocking_register_or_replace::<TComponent>(Box::new(ComponentFromAsyncClosure::<TComponent, TFuture, TClosure>::new(closure)), life_cycle))
+        }).join().unwrap()
+    }
+
+    /// Register component from closure, or replace registered component (blocking version)
+    /// 
+    /// Replace swap component ctor, drop created singleton and reset loop-check links of component
+    /// 
+    /// If life cycle not changed, service mappings kept, otherwise mappings removed and component must be mapped again
+    /// 
+    /// Context dependent instances, already created in local contexts, not changed
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_register_closure_or_replace(|_ctx| Ok(SomeComponent::new()), LifeCycle::Transient).unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_register_closure_or_replace<TComponent: Sync + Send + 'static, TClosure: Fn(DependencyContext) -> BuildDependencyResult<TComponent> + Sync + Send + 'static>(&self, closure: TClosure, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.track_added_component(self_copy.core_context.blocking_register_or_replace::<TComponent>(Box::new(ComponentFromClosure::<TComponent>::new(Box::new(closure))), life_cycle))
+        }).join().unwrap()
+    }
+
+    /// Register component instance as singleton, or replace registered component (blocking version)
+    /// 
+    /// Replace swap component ctor, drop created singleton and reset loop-check links of component
+    /// 
+    /// If life cycle not changed, service mappings kept, otherwise mappings removed and component must be mapped again
+    /// 
+    /// Context dependent instances, already created in local contexts, not changed
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_register_instance_or_replace::<SomeComponent>(SomeComponent::new()).unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_register_instance_or_replace<TComponent: Sync + Send + 'static>(&self, instance: TComponent) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.track_added_component(self_copy.core_context.blocking_register_or_replace::<TComponent>(Box::new(ComponentFromInstance::new(instance)), LifeCycle::Singleton))
+        }).join().unwrap()
+    }
+
+    /// Register all components, submitted by ```#[component]``` attribute, and map them to services (blocking version)
+    /// 
+    /// Components discovered from all linked crates, registration order not guaranteed
+    ///# Example
+    ///---
+    /// ```ignore
+    /// #[component(lifecycle = Singleton, services(dyn SomeService))]
+    /// #[derive(Constructor)]
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_register_discovered().unwrap();
+    /// ```
+    #[cfg(feature = "discovery")]
+    pub fn blocking_register_discovered(&self) -> RegisterDiscoveredResult<()> {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            let rt = Builder::new_current_thread().enable_all().build().unwrap();
+            rt.block_on(async move { self_copy.register_discovered().await })
+        }).join().unwrap()
+    }
+
+    /// Register service decorator (blocking version)
+    ///
+    /// Resolved service is wrapped by decorators in registration order, decorator receive previous service as inner
+    /// 
+    /// Singleton and context dependent service decorated once and cached with component instance
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_register_decorator::<Box<dyn Repo>, CachingRepo>();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_register_decorator<TService: Sync + Send + 'static, TDecorator: Decorator<TService>>(&self) {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.core_context.blocking_add_decorator::<TService>(Arc::new(DecoratorFromType::<TService, TDecorator>::new()))
+        }).join().unwrap()
+    }
+
+    /// Register service decorator from closure (blocking version)
+    ///
+    /// Resolved service is wrapped by decorators in registration order, decorator receive previous service as inner
+    /// 
+    /// Singleton and context dependent service decorated once and cached with component instance
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_register_decorator_closure(|_ctx, inner: Box<dyn Repo>| Ok(Box::new(CachingRepo::new(inner)) as Box<dyn Repo>));
+    /// ```
+    #[inline(always)]
+    pub fn blocking_register_decorator_closure<TService: Sync + Send + 'static, TClosure: Fn(DependencyContext, TService) -> BuildDependencyResult<TService> + Sync + Send + 'static>(&self, closure: TClosure) {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.core_context.blocking_add_decorator::<TService>(Arc::new(DecoratorFromClosure::<TService>::new(Box::new(closure))))
+        }).join().unwrap()
+    }
+
+    /// Add interceptor, called around every component ctor (blocking version)
+    ///
+    /// Interceptor can observe construction or replace it with own instance or error
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_add_interceptor(AuditInterceptor {});
+    /// ```
+    #[inline(always)]
+    pub fn blocking_add_interceptor<TInterceptor: ResolveInterceptor>(&self, interceptor: TInterceptor) {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.core_context.blocking_add_interceptor(Arc::new(interceptor))
+        }).join().unwrap()
+    }
+
+    /// Add resolution source, asked for service without mappings by ```resolve```, ```try_resolve``` and ```resolve_collection``` (blocking version)
+    ///
+    /// Sources asked in adding order, source can return service instance or register component for service
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_add_resolution_source(EnvSource {});
+    /// ```
+    #[inline(always)]
+    pub fn blocking_add_resolution_source<TResolutionSource: ResolutionSource>(&self, resolution_source: TResolutionSource) {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.core_context.blocking_add_resolution_source(Arc::new(resolution_source))
+        }).join().unwrap()
+    }
+
+    /// Add alias, which resolve current target service as alias service (blocking version)
+    ///
+    /// Alias follow target changes, so replaced or deleted target affect alias too
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_alias::<Arc<dyn LegacyStore>, Arc<dyn Store>>().unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_alias<TAlias: Sync + Send + 'static, TTarget: Sync + Send + 'static + CoerceUnsized<TAlias>>(&self) -> AddDependencyResult<()> {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.core_context.blocking_add_alias::<TAlias, TTarget>()
+        }).join().unwrap()
+    }
+
+    /// Map component as service (blocking version)
+    ///# Example
+    ///---
+    /// From root context
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// trait SomeService {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// //...
+    /// 
+    /// // SomeComponent must be registered, or will be error
+    /// root_context.blocking_map_component::<SomeComponent, dyn SomeService>().unwrap();
+    /// ```
+    /// 
+    /// ---
+    /// 
+    /// From ctor context
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// trait SomeService {}
+    /// 
+    /// #[async_trait_with_sync::async_trait(Sync)]
+    /// impl Constructor for SomeOtherComponent {
+    ///     async fn ctor(ctx: crate::DependencyContext) -> BuildDependencyResult<Self> {
+    ///         // SomeComponent must be registered, or will be error
+    ///         // unwrap or map error to BuildDependencyError
+    ///         ctx.blocking_map_component::<SomeComponent, dyn SomeService>().unwrap();
+    ///         Ok(Self { })
+    ///     }
+    /// }
+    /// 
+    /// ```
+    #[inline(always)]
+    pub fn blocking_map_component<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static>(&self) -> MapComponentResult<Arc<CoreContext>> where TComponent: Unsize<TService> {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.track_mapping(self_copy.core_context.blocking_map_component::<TComponent, TService>())
+        }).join().unwrap()
+    }
+
+    /// Resolve first component, mapped to service (blocking version)
+    /// ``` ignore
+    /// // You can resolve transient like:
+    /// ctx.blocking_resolve::<Box<dyn Service>>().unwrap()
+    /// ctx.blocking_resolve::<Box<Component>>().unwrap()
+    /// 
+    /// // You can resolve singleton like:
+    /// ctx.blocking_resolve::<Arc<dyn Service>>().unwrap()
+    /// ctx.blocking_resolve::<Arc<Component>>().unwrap()
+    /// 
+    /// // You can resolve context dependent like:
+    /// ctx.blocking_resolve::<Weak<dyn Service>>().unwrap()
+    /// ctx.blocking_resolve::<Weak<Component>>().unwrap()
+    /// 
+    /// // for mutable Arc and Weak, register as RwLock<Component>, map as RwLock<dyn Service>, and resolve like:
+    /// ctx.blocking_resolve::<Weak<RwLock<dyn Service>>>().unwrap()
+    ///  
+    /// ```
+    ///# Example
+    ///---
+    /// From root context
+    /// ```ignore
+    /// trait SomeService {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// let service: Box<dyn SomeTrait> = root_context.blocking_resolve::<Box<dyn SomeService>>().unwrap();
+    /// ```
+    /// 
+    /// ---
     /// 
     /// From ctor context
     /// ```ignore
@@ -881,12 +2028,81 @@ impl DependencyContext {
     /// ```
     #[inline(always)]
     pub fn blocking_resolve<TService: Sync + Send + 'static>(&self) -> BuildDependencyResult<TService> {
+        if let Some(service) = TService::build_implicit(self) {
+            return Ok(service);
+        }
+
         let self_copy = self.clone();
         std::thread::spawn(move || {
             self_copy.core_context.blocking_resolve::<TService>(self_copy.id.clone(), self_copy.local_context.clone())
         }).join().unwrap()
     }
 
+    /// Resolve service, or None if service not registered (blocking version)
+    ///
+    /// Unlike ```blocking_is_service_exist``` + ```blocking_resolve```, service searched once. Construction errors are propagated
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let plugin: Option<Box<dyn SomePlugin>> = root_context.blocking_try_resolve::<Box<dyn SomePlugin>>().unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_try_resolve<TService: Sync + Send + 'static>(&self) -> BuildDependencyResult<Option<TService>> {
+        if let Some(service) = TService::build_implicit(self) {
+            return Ok(Some(service));
+        }
+
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.core_context.blocking_try_resolve::<TService>(self_copy.id.clone(), self_copy.local_context.clone())
+        }).join().unwrap()
+    }
+
+    /// Resolve component, registered on demand, if it not registered (blocking version)
+    /// 
+    /// Life cycle chosen by service type: ```T``` - Transient, ```Arc<T>``` - Singleton, ```Weak<T>``` - ContextDependent    
+    /// If ```ContextSettings::auto_wired_life_cycle``` set, other life cycle return ```AddDependencyError::LifeCycleMismatch``` before registration    
+    /// Already registered component resolved as usual
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// let transient: SomeComponent = root_context.blocking_resolve_or_construct().unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_resolve_or_construct<TService: AutoWired>(&self) -> BuildDependencyResult<TService> {
+        self.blocking_register_auto_wired::<TService>()
+            .map_err(|err| BuildDependencyError::AddDependencyError { err })?;
+
+        self.blocking_resolve::<TService>()
+    }
+
+    // Register component of auto wired service, if it not registered
+    pub (crate) fn blocking_register_auto_wired<TService: AutoWired>(&self) -> AddDependencyResult<()> {
+        if self.blocking_is_component_exist::<TService::Component>() {
+            return Ok(());
+        }
+
+        // Service type define life cycle of resolved component, so other life cycle can't be registered
+        if let Some(life_cycle) = self.core_context.settings.auto_wired_life_cycle {
+            if life_cycle != TService::LIFE_CYCLE {
+                return Err(AddDependencyError::LifeCycleMismatch {
+                    component_type_info: TypeInfo::from_type::<TService::Component>(),
+                    expected_life_cycle: life_cycle,
+                    found_life_cycle: TService::LIFE_CYCLE,
+                });
+            }
+        }
+
+        // Component can be registered concurrently, then registered component resolved
+        match self.blocking_register_type::<TService::Component>(TService::LIFE_CYCLE) {
+            Ok(_) | Err(AddDependencyError::DependencyExist { .. }) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
     /// Resolve component with TypeId, mapped to service (blocking version)
     /// That may be helpful in case, when you need current component, type is lost, but you can save TypeId as variable
     /// ``` ignore
@@ -942,6 +2158,54 @@ impl DependencyContext {
         }).join().unwrap()
     }
 
+    /// Resolve component, mapped to service with name (blocking version)
+    /// 
+    /// Service must be mapped with ```map_as_named```, unnamed mappings not used
+    /// ``` ignore
+    /// // You can resolve transient like:
+    /// ctx.blocking_resolve_named::<Box<dyn Service>>("name").unwrap()
+    /// 
+    /// // You can resolve singleton like:
+    /// ctx.blocking_resolve_named::<Arc<dyn Service>>("name").unwrap()
+    /// 
+    /// // You can resolve context dependent like:
+    /// ctx.blocking_resolve_named::<Weak<dyn Service>>("name").unwrap()
+    /// ```
+    ///# Example
+    ///---
+    /// From root context
+    /// ```ignore
+    /// trait SomeService {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// let service: Box<dyn SomeTrait> = root_context.blocking_resolve_named::<Box<dyn SomeService>>("primary").unwrap();
+    /// ```
+    /// 
+    /// ---
+    /// 
+    /// From ctor context
+    /// ```ignore
+    /// trait SomeService {}
+    /// 
+    /// #[async_trait_with_sync::async_trait(Sync)]
+    /// impl Constructor for SomeOtherComponent {
+    ///     async fn ctor(ctx: crate::DependencyContext) -> BuildDependencyResult<Self> {
+    ///         // unwrap or map error to BuildDependencyError
+    ///         let service: Box<dyn SomeTrait> = ctx.blocking_resolve_named::<Box<dyn SomeService>>("primary").unwrap();
+    ///         Ok(Self { })
+    ///     }
+    /// }
+    /// 
+    /// ```
+    #[inline(always)]
+    pub fn blocking_resolve_named<TService: Sync + Send + 'static>(&self, name: &str) -> BuildDependencyResult<TService> {
+        let self_copy = self.clone();
+        let name = name.to_string();
+        std::thread::spawn(move || {
+            self_copy.core_context.blocking_resolve_named::<TService>(&name, self_copy.id.clone(), self_copy.local_context.clone())
+        }).join().unwrap()
+    }
+
     /// Resolve all component, mapped to service (blocking version)
     /// ``` ignore
     /// // You can resolve transient like:
@@ -967,31 +2231,118 @@ impl DependencyContext {
     /// trait SomeService {}
     /// 
     /// let root_context = DependencyContext::new_root();
-    /// let collection: Vec<Box<dyn SomeService>> = root_context.blocking_resolve_collection::<Box<dyn SomeService>>().unwrap();
+    /// let collection: Vec<Box<dyn SomeService>> = root_context.blocking_resolve_collection::<Box<dyn SomeService>>().unwrap();
+    /// ```
+    /// 
+    /// ---
+    /// 
+    /// From ctor context
+    /// ```ignore
+    /// trait SomeService {}
+    /// 
+    /// #[async_trait_with_sync::async_trait(Sync)]
+    /// impl Constructor for SomeOtherComponent {
+    ///     async fn ctor(ctx: crate::DependencyContext) -> BuildDependencyResult<Self> {
+    ///         // unwrap or map error to BuildDependencyError
+    ///         let collection: Vec<Box<dyn SomeService>> = ctx.blocking_resolve_collection::<Box<dyn SomeService>>().unwrap();
+    ///         Ok(Self { })
+    ///     }
+    /// }
+    /// 
+    /// ```
+    #[inline(always)]
+    pub fn blocking_resolve_collection<TService: Sync + Send + 'static>(&self) -> BuildDependencyResult<Vec<TService>> {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.core_context.blocking_resolve_collection::<TService>(self_copy.id.clone(), self_copy.local_context.clone())
+        }).join().unwrap()
+    }
+
+    /// Resolve services, which mapping metadata match filter (blocking version)
+    ///
+    /// Filtered out components are not constructed    
+    /// Aliases and contextual bindings applied like in ```resolve_collection```, alias filter target metadata
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let http_handlers = root_context.blocking_resolve_collection_where::<Box<dyn Handler>, _>(|metadata| metadata.tag("http")).unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_resolve_collection_where<TService: Sync + Send + 'static, TFilter: Fn(&ServiceMetadata) -> bool + Sync + Send + 'static>(&self, filter: TFilter) -> BuildDependencyResult<Vec<TService>> {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.core_context.blocking_resolve_collection_where::<TService, TFilter>(filter, self_copy.id.clone(), self_copy.local_context.clone())
+        }).join().unwrap()
+    }
+
+    /// Get mapping info of services, returned by ```resolve_collection```, without construction (blocking version)
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let handlers_info = root_context.blocking_get_collection_metadata::<Box<dyn Handler>>();
+    /// let routes: Vec<_> = handlers_info.iter().filter_map(|info| info.metadata.value("route")).collect();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_get_collection_metadata<TService: 'static>(&self) -> Vec<ServiceInfo> {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.core_context.blocking_get_collection_metadata::<TService>()
+        }).join().unwrap()
+    }
+
+    /// Resolve service from component, registered with args (blocking version)
+    ///
+    /// Args type checked with registered args type, mismatch return ```BuildDependencyError::ArgsTypeMismatch```    
+    /// Aliases and contextual bindings applied like in ```resolve```
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let report = root_context.blocking_resolve_with::<Box<dyn Report>, (TenantId, Period)>((tenant_id, period)).unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_resolve_with<TService: Sync + Send + 'static, TArgs: Sync + Send + 'static>(&self, args: TArgs) -> BuildDependencyResult<TService> {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.core_context.blocking_resolve_with::<TService, TArgs>(args, self_copy.id.clone(), self_copy.local_context.clone())
+        }).join().unwrap()
+    }
+
+    /// Call function, which arguments resolved from context (blocking version)
+    /// 
+    /// Arguments resolved like ```resolve::<TArgument>()```, with current local context    
+    /// Failed argument returned as ```BuildDependencyError::ArgumentResolveError```, with function result type as component
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// let result = root_context.blocking_invoke(|db: Arc<dyn Db>, log: Box<dyn Log>| run_migrations(db, log)).unwrap();
     /// ```
+    #[inline(always)]
+    pub fn blocking_invoke<TResult: Send + 'static, TArgs: 'static, TFunction: InjectFn<TArgs, TResult>>(&self, function: TFunction) -> BuildDependencyResult<TResult> {
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                Builder::new_current_thread().enable_all().build().unwrap().block_on(function.call(self))
+            }).join().unwrap()
+        })
+    }
+
+    /// Call async function, which arguments resolved from context (blocking version)
     /// 
-    /// ---
-    /// 
-    /// From ctor context
+    /// Arguments resolved like ```resolve::<TArgument>()```, with current local context    
+    /// Failed argument returned as ```BuildDependencyError::ArgumentResolveError```, with function result type as component
+    ///# Example
+    ///---
     /// ```ignore
-    /// trait SomeService {}
-    /// 
-    /// #[async_trait_with_sync::async_trait(Sync)]
-    /// impl Constructor for SomeOtherComponent {
-    ///     async fn ctor(ctx: crate::DependencyContext) -> BuildDependencyResult<Self> {
-    ///         // unwrap or map error to BuildDependencyError
-    ///         let collection: Vec<Box<dyn SomeService>> = ctx.blocking_resolve_collection::<Box<dyn SomeService>>().unwrap();
-    ///         Ok(Self { })
-    ///     }
-    /// }
-    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// let result = root_context.blocking_invoke_async(|db: Arc<dyn Db>| async move { run_migrations(db).await }).unwrap();
     /// ```
     #[inline(always)]
-    pub fn blocking_resolve_collection<TService: Sync + Send + 'static>(&self) -> BuildDependencyResult<Vec<TService>> {
-        let self_copy = self.clone();
-        std::thread::spawn(move || {
-            self_copy.core_context.blocking_resolve_collection::<TService>(self_copy.id.clone(), self_copy.local_context.clone())
-        }).join().unwrap()
+    pub fn blocking_invoke_async<TResult: Send + 'static, TArgs: 'static, TFunction: InjectAsyncFn<TArgs, TResult>>(&self, function: TFunction) -> BuildDependencyResult<TResult> {
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                Builder::new_current_thread().enable_all().build().unwrap().block_on(function.call(self))
+            }).join().unwrap()
+        })
     }
 
     /// Delete component (blocking version)
@@ -1029,6 +2380,76 @@ impl DependencyContext {
         }).join().unwrap()
     }
 
+    /// Delete all components, which have mapping with tag (blocking version)
+    ///
+    /// Return deleted components. If any component not support delete, nothing deleted
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let deleted_components = root_context.blocking_delete_components_with_tag("http").unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn blocking_delete_components_with_tag(&self, tag: &str) -> DeleteComponentResult<Vec<TypeInfo>> {
+        let self_copy = self.clone();
+        let tag = tag.to_string();
+        std::thread::spawn(move || {
+            self_copy.core_context.blocking_delete_components_with_tag(&tag)
+        }).join().unwrap()
+    }
+
+    /// Install module (blocking version)
+    /// 
+    /// Components registered and mapped by module context are saved, to delete them on uninstall
+    /// 
+    /// Module dependencies must be installed before. If install failed, registered components are deleted    
+    /// Module can't register context dependent components, they can't be deleted on uninstall
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_install_module(SomeModule::new()).unwrap();
+    /// ```
+    pub fn blocking_install_module<TModule: DependencyModule>(&self, module: TModule) -> InstallModuleResult<()> {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            let rt = Builder::new_current_thread().enable_all().build().unwrap();
+            rt.block_on(async move { self_copy.install_module(module).await })
+        }).join().unwrap()
+    }
+
+    /// Uninstall module and delete all components and mappings, added by module (blocking version)
+    /// 
+    /// Module can't be uninstalled, while other installed module depends on it    
+    /// Module uninstall hook is called before final check, so failed uninstall can call hook again on retry
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_install_module(SomeModule::new()).unwrap();
+    /// root_context.blocking_uninstall_module::<SomeModule>().unwrap();
+    /// ```
+    pub fn blocking_uninstall_module<TModule: DependencyModule>(&self) -> UninstallModuleResult<()> {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            let rt = Builder::new_current_thread().enable_all().build().unwrap();
+            rt.block_on(async move { self_copy.uninstall_module::<TModule>().await })
+        }).join().unwrap()
+    }
+
+    /// Check module installed (blocking version)
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// let is_installed = root_context.blocking_is_module_installed::<SomeModule>();
+    /// ```
+    pub fn blocking_is_module_installed<TModule: DependencyModule>(&self) -> bool {
+        let self_copy = self.clone();
+        std::thread::spawn(move || {
+            self_copy.core_context.blocking_is_module_installed(TypeId::of::<TModule>())
+        }).join().unwrap()
+    }
+
     /// Check service existence (blocking version)
     /// ``` ignore
     /// // You can check transient like:
@@ -1131,6 +2552,51 @@ impl DependencyContext {
         }).join().unwrap()
     }
 
+    /// Check named service existence (blocking version)
+    /// ``` ignore
+    /// // You can check transient like:
+    /// ctx.blocking_is_named_service_exist::<Box<dyn Service>>("name")
+    /// 
+    /// // You can check singleton like:
+    /// ctx.blocking_is_named_service_exist::<Arc<dyn Service>>("name")
+    /// 
+    /// // You can check context dependent like:
+    /// ctx.blocking_is_named_service_exist::<Weak<dyn Service>>("name")
+    /// ```
+    ///# Example
+    ///---
+    /// From root context
+    /// ```ignore
+    /// trait SomeService {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// let is_exist: bool = root_context.blocking_is_named_service_exist::<Box<dyn SomeService>>("primary");
+    /// ```
+    /// 
+    /// ---
+    /// 
+    /// From ctor context
+    /// ```ignore
+    /// trait SomeService {}
+    /// 
+    /// #[async_trait_with_sync::async_trait(Sync)]
+    /// impl Constructor for SomeOtherComponent {
+    ///     async fn ctor(ctx: crate::DependencyContext) -> BuildDependencyResult<Self> {
+    ///         let is_exist: bool = ctx.blocking_is_named_service_exist::<Box<dyn SomeService>>("primary");
+    ///         Ok(Self { })
+    ///     }
+    /// }
+    /// 
+    /// ```
+    #[inline(always)]
+    pub fn blocking_is_named_service_exist<TService: 'static>(&self, name: &str) -> bool {
+        let self_copy = self.clone();
+        let name = name.to_string();
+        std::thread::spawn(move || {
+            self_copy.core_context.blocking_is_named_service_exist(TypeId::of::<TService>(), &name)
+        }).join().unwrap()
+    }
+
     /// Check component existence (blocking version)
     ///# Example
     ///---
@@ -1236,79 +2702,360 @@ impl DependencyContext {
     /// 
     /// ```
     #[inline(always)]
-    pub fn register_type<TComponent: Constructor + Sync + Send + 'static>(&self, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
-        self.core_context.register::<TComponent>(Box::new(ComponentFromConstructor::<TComponent>::new()), life_cycle)
+    pub fn register_type<TComponent: Constructor + Sync + Send + 'static>(&self, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_component(self.core_context.register::<TComponent>(Box::new(ComponentFromConstructor::<TComponent>::new()), life_cycle))
+    }
+
+    /// Register component from closure
+    /// 
+    /// Remember, lifetime required smart pointers wrapper:
+    /// * if register Component as Transient, resolve as Component or Box<dyn Service>
+    /// * if register Component as Singleton, resolve as Arc<Component> or Arc<dyn Service>
+    /// * if register Component as ContextDependent, resolve as Weak<Component> or Weak<dyn Service>
+    /// 
+    /// Remember, for mutable Singleton/ContextDependent instance, you need register component, wrapped in RwLock<T> 
+    ///# Example
+    ///---
+    /// From root context
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_closure(|_ctx| Ok(SomeComponent::new()), LifeCycle::Transient).unwrap();
+    /// ```
+    /// 
+    /// ---
+    /// 
+    /// From ctor context
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// impl Constructor for SomeOtherComponent {
+    ///     fn ctor(ctx: crate::DependencyContext) -> BuildDependencyResult<Self> {
+    ///         // unwrap or map error to BuildDependencyError
+    ///         ctx.register_closure(|_ctx| Ok(SomeComponent::new()), LifeCycle::Transient).unwrap();
+    ///         Ok(Self { })
+    ///     }
+    /// }
+    /// 
+    /// ```
+    #[inline(always)]
+    pub fn register_closure<TComponent: Sync + Send + 'static, TClosure: Fn(DependencyContext) -> BuildDependencyResult<TComponent> + Sync + Send + 'static>(&self, closure: TClosure, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_component(self.core_context.register::<TComponent>(Box::new(ComponentFromClosure::<TComponent>::new(Box::new(closure))), life_cycle))
+    }
+
+    /// Register component from function, which arguments resolved from context
+    /// 
+    /// Every argument resolved like ```resolve::<TArgument>()```, failed argument returned as ```BuildDependencyError::ArgumentResolveError```
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_fn(|db: Arc<dyn Db>, log: Box<dyn Log>| Service::new(db, log), LifeCycle::Singleton).unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn register_fn<TComponent: Sync + Send + 'static, TArgs: 'static, TFunction: InjectFn<TArgs, TComponent> + 'static>(&self, function: TFunction, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_component(self.core_context.register::<TComponent>(Box::new(ComponentFromFn::<TComponent, TArgs, TFunction>::new(function)), life_cycle))
+    }
+
+    /// Register component instance as singleton
+    /// 
+    /// Remember, for mutable Singleton/ContextDependent instance, you need register component, wrapped in RwLock<T> 
+    ///# Example
+    ///---
+    /// From root context
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_instance::<SomeComponent>(SomeComponent::new()).unwrap();
+    /// ```
+    /// 
+    /// ---
+    /// 
+    /// From ctor context
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// impl Constructor for SomeOtherComponent {
+    ///     fn ctor(ctx: crate::DependencyContext) -> BuildDependencyResult<Self> {
+    ///         // unwrap or map error to BuildDependencyError
+    ///         ctx.register_instance::<SomeComponent>(SomeComponent::new()).unwrap();
+    ///         Ok(Self { })
+    ///     }
+    /// }
+    /// 
+    /// ```
+    #[inline(always)]
+    pub fn register_instance<TComponent: Sync + Send + 'static>(&self, instance: TComponent) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_component(self.core_context.register::<TComponent>(Box::new(ComponentFromInstance::new(instance)), LifeCycle::Singleton))
+    }
+
+    /// Register shared instance as singleton, resolved as ```Arc<TComponent>```
+    /// 
+    /// Every resolve return clone of registered Arc, instance is not moved into context
+    /// 
+    /// Component registered as ```TComponent```, so it can be mapped and deleted like other singleton
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let shared = Arc::new(SomeComponent::new());
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_arc(shared.clone()).unwrap()
+    ///     .map_as::<dyn SomeService>().unwrap();
+    /// 
+    /// let resolved = root_context.resolve::<Arc<SomeComponent>>().unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn register_arc<TComponent: Sync + Send + 'static>(&self, instance: Arc<TComponent>) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_component(self.core_context.register::<TComponent>(Box::new(ComponentFromSharedInstance::new(instance)), LifeCycle::Singleton))
+    }
+
+    /// Register shared service instance without component type, resolved as ```Arc<TService>```
+    /// 
+    /// Every resolve return clone of registered Arc, instance is not moved into context
+    /// 
+    /// Unsized service can't be singleton component, so component registered as transient ```Arc<TService>```, which ctor return registered Arc like ```register_arc```    
+    /// Interceptors and decorators are called on every resolve, use ```register_arc``` for sized component
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let shared: Arc<dyn SomeService> = Arc::new(SomeComponent::new());
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_service_instance::<dyn SomeService>(shared).unwrap();
+    /// 
+    /// let resolved = root_context.resolve::<Arc<dyn SomeService>>().unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn register_service_instance<TService: ?Sized + Sync + Send + 'static>(&self, instance: Arc<TService>) -> AddDependencyResult<()> {
+        self.track_component(self.core_context.register::<Arc<TService>>(Box::new(ComponentFromArc::new(instance)), LifeCycle::Transient)).map(|_| ())
+    }
+
+    /// Register transient component, created by template clone
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_prototype(RequestOptions { timeout: 30 }).unwrap()
+    ///     .map_as::<dyn Options>().unwrap();
+    /// 
+    /// let options = root_context.resolve::<Box<dyn Options>>().unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn register_prototype<TComponent: Clone + Sync + Send + 'static>(&self, template: TComponent) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_component(self.core_context.register::<TComponent>(Box::new(ComponentFromPrototype::new(template)), LifeCycle::Transient))
+    }
+
+    /// Register transient component from closure with args, passed on ```resolve_with```
+    ///
+    /// Component resolved without args, or with args of other type, return ```BuildDependencyError::ArgsTypeMismatch```
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent { tenant_id: u64 }
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_closure_with_args(|_ctx, tenant_id: u64| Ok(SomeComponent { tenant_id })).unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn register_closure_with_args<TComponent: Sync + Send + 'static, TArgs: Sync + Send + 'static, TClosure: Fn(DependencyContext, TArgs) -> BuildDependencyResult<TComponent> + Sync + Send + 'static>(&self, closure: TClosure) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_component(self.core_context.register::<TComponent>(Box::new(ComponentFromClosureWithArgs::<TComponent, TArgs>::new(Box::new(closure))), LifeCycle::Transient))
+    }
+
+    /// Register transient component with ```ConstructorWith``` args, passed on ```resolve_with```
+    ///
+    /// Component resolved without args, or with args of other type, return ```BuildDependencyError::ArgsTypeMismatch```
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_type_with_args::<SomeComponent, (TenantId, Period)>().unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn register_type_with_args<TComponent: ConstructorWith<TArgs> + Sync + Send + 'static, TArgs: Sync + Send + 'static>(&self) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_component(self.core_context.register::<TComponent>(Box::new(ComponentFromConstructorWithArgs::<TComponent, TArgs>::new()), LifeCycle::Transient))
+    }
+
+    /// Register component witch implement trait Constructor as composite service
+    /// 
+    /// Composite resolved instead of other service implementations, but not returned from ```resolve_collection```    
+    /// So composite can wrap collection of all other implementations    
+    /// Return mapping error, if component deleted before mapped
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct FanOutSink { sinks: Vec<Arc<dyn EventSink>> }
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_composite::<dyn EventSink, FanOutSink>(LifeCycle::Singleton).unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn register_composite<TService: ?Sized + Sync + Send + 'static, TComponent: Constructor + Sync + Send + 'static>(&self, life_cycle: LifeCycle) -> RegisterCompositeResult<ServiceMappingBuilder<TComponent>> where TComponent: Unsize<TService> {
+        // Component can be deleted before mapped, so mapping error returned
+        self.register_type::<TComponent>(life_cycle)
+            .map_err(|err| RegisterCompositeError::AddDependencyError { err })?
+            .map_as_composite::<TService>()
+            .map_err(|err| RegisterCompositeError::MapComponentError { err })
+    }
+
+    /// Register component witch implement trait Constructor, or replace registered component
+    /// 
+    /// Replace swap component ctor, drop created singleton and reset loop-check links of component
+    /// 
+    /// If life cycle not changed, service mappings kept, otherwise mappings removed and component must be mapped again
+    /// 
+    /// Context dependent instances, already created in local contexts, not changed
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_type_or_replace::<SomeComponent>(LifeCycle::Transient).unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn register_type_or_replace<TComponent: Constructor + Sync + Send + 'static>(&self, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_added_component(self.core_context.register_or_replace::<TComponent>(Box::new(ComponentFromConstructor::<TComponent>::new()), life_cycle))
     }
 
-    /// Register component from closure
+    /// Register component from closure, or replace registered component
     /// 
-    /// Remember, lifetime required smart pointers wrapper:
-    /// * if register Component as Transient, resolve as Component or Box<dyn Service>
-    /// * if register Component as Singleton, resolve as Arc<Component> or Arc<dyn Service>
-    /// * if register Component as ContextDependent, resolve as Weak<Component> or Weak<dyn Service>
+    /// Replace swap component ctor, drop created singleton and reset loop-check links of component
     /// 
-    /// Remember, for mutable Singleton/ContextDependent instance, you need register component, wrapped in RwLock<T> 
+    /// If life cycle not changed, service mappings kept, otherwise mappings removed and component must be mapped again
+    /// 
+    /// Context dependent instances, already created in local contexts, not changed
     ///# Example
     ///---
-    /// From root context
     /// ```ignore
     /// struct SomeComponent {}
     /// 
     /// let root_context = DependencyContext::new_root();
-    /// root_context.register_closure(|_ctx| Ok(SomeComponent::new()), LifeCycle::Transient).unwrap();
+    /// root_context.register_closure_or_replace(|_ctx| Ok(SomeComponent::new()), LifeCycle::Transient).unwrap();
     /// ```
+    #[inline(always)]
+    pub fn register_closure_or_replace<TComponent: Sync + Send + 'static, TClosure: Fn(DependencyContext) -> BuildDependencyResult<TComponent> + Sync + Send + 'static>(&self, closure: TClosure, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_added_component(self.core_context.register_or_replace::<TComponent>(Box::new(ComponentFromClosure::<TComponent>::new(Box::new(closure))), life_cycle))
+    }
+
+    /// Register component instance as singleton, or replace registered component
     /// 
-    /// ---
+    /// Replace swap component ctor, drop created singleton and reset loop-check links of component
     /// 
-    /// From ctor context
+    /// If life cycle not changed, service mappings kept, otherwise mappings removed and component must be mapped again
+    /// 
+    /// Context dependent instances, already created in local contexts, not changed
+    ///# Example
+    ///---
     /// ```ignore
     /// struct SomeComponent {}
     /// 
-    /// impl Constructor for SomeOtherComponent {
-    ///     fn ctor(ctx: crate::DependencyContext) -> BuildDependencyResult<Self> {
-    ///         // unwrap or map error to BuildDependencyError
-    ///         ctx.register_closure(|_ctx| Ok(SomeComponent::new()), LifeCycle::Transient).unwrap();
-    ///         Ok(Self { })
-    ///     }
-    /// }
-    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_instance_or_replace::<SomeComponent>(SomeComponent::new()).unwrap();
     /// ```
     #[inline(always)]
-    pub fn register_closure<TComponent: Sync + Send + 'static, TClosure: Fn(DependencyContext) -> BuildDependencyResult<TComponent> + Sync + Send + 'static>(&self, closure: TClosure, life_cycle: LifeCycle) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
-        self.core_context.register::<TComponent>(Box::new(ComponentFromClosure::<TComponent>::new(Box::new(closure))), life_cycle)
+    pub fn register_instance_or_replace<TComponent: Sync + Send + 'static>(&self, instance: TComponent) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
+        self.track_added_component(self.core_context.register_or_replace::<TComponent>(Box::new(ComponentFromInstance::new(instance)), LifeCycle::Singleton))
     }
 
-    /// Register component instance as singleton
+    /// Register all components, submitted by ```#[component]``` attribute, and map them to services
     /// 
-    /// Remember, for mutable Singleton/ContextDependent instance, you need register component, wrapped in RwLock<T> 
+    /// Components discovered from all linked crates, registration order not guaranteed
     ///# Example
     ///---
-    /// From root context
     /// ```ignore
+    /// #[component(lifecycle = Singleton, services(dyn SomeService))]
+    /// #[derive(Constructor)]
     /// struct SomeComponent {}
     /// 
     /// let root_context = DependencyContext::new_root();
-    /// root_context.register_instance::<SomeComponent>(SomeComponent::new()).unwrap();
+    /// root_context.register_discovered().unwrap();
     /// ```
+    #[cfg(feature = "discovery")]
+    pub fn register_discovered(&self) -> RegisterDiscoveredResult<()> {
+        for discovered_component in inventory::iter::<DiscoveredComponent> {
+            (discovered_component.register)(self.clone())?;
+        }
+
+        Ok(())
+    }
+
+    /// Register service decorator
+    ///
+    /// Resolved service is wrapped by decorators in registration order, decorator receive previous service as inner
     /// 
-    /// ---
-    /// 
-    /// From ctor context
+    /// Singleton and context dependent service decorated once and cached with component instance
+    ///# Example
+    ///---
     /// ```ignore
-    /// struct SomeComponent {}
-    /// 
-    /// impl Constructor for SomeOtherComponent {
-    ///     fn ctor(ctx: crate::DependencyContext) -> BuildDependencyResult<Self> {
-    ///         // unwrap or map error to BuildDependencyError
-    ///         ctx.register_instance::<SomeComponent>(SomeComponent::new()).unwrap();
-    ///         Ok(Self { })
-    ///     }
-    /// }
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_decorator::<Box<dyn Repo>, CachingRepo>();
+    /// ```
+    #[inline(always)]
+    pub fn register_decorator<TService: Sync + Send + 'static, TDecorator: Decorator<TService>>(&self) {
+        self.core_context.add_decorator::<TService>(Arc::new(DecoratorFromType::<TService, TDecorator>::new()))
+    }
+
+    /// Register service decorator from closure
+    ///
+    /// Resolved service is wrapped by decorators in registration order, decorator receive previous service as inner
     /// 
+    /// Singleton and context dependent service decorated once and cached with component instance
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_decorator_closure(|_ctx, inner: Box<dyn Repo>| Ok(Box::new(CachingRepo::new(inner)) as Box<dyn Repo>));
     /// ```
     #[inline(always)]
-    pub fn register_instance<TComponent: Sync + Send + 'static>(&self, instance: TComponent) -> AddDependencyResult<ServiceMappingBuilder<TComponent>> {
-        self.core_context.register::<TComponent>(Box::new(ComponentFromInstance::new(instance)), LifeCycle::Singleton)
+    pub fn register_decorator_closure<TService: Sync + Send + 'static, TClosure: Fn(DependencyContext, TService) -> BuildDependencyResult<TService> + Sync + Send + 'static>(&self, closure: TClosure) {
+        self.core_context.add_decorator::<TService>(Arc::new(DecoratorFromClosure::<TService>::new(Box::new(closure))))
+    }
+
+    /// Add interceptor, called around every component ctor
+    ///
+    /// Interceptor can observe construction or replace it with own instance or error
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.add_interceptor(AuditInterceptor {});
+    /// ```
+    #[inline(always)]
+    pub fn add_interceptor<TInterceptor: ResolveInterceptor>(&self, interceptor: TInterceptor) {
+        self.core_context.add_interceptor(Arc::new(interceptor))
+    }
+
+    /// Add resolution source, asked for service without mappings by ```resolve```, ```try_resolve``` and ```resolve_collection```
+    ///
+    /// Sources asked in adding order, source can return service instance or register component for service
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.add_resolution_source(EnvSource {});
+    /// ```
+    #[inline(always)]
+    pub fn add_resolution_source<TResolutionSource: ResolutionSource>(&self, resolution_source: TResolutionSource) {
+        self.core_context.add_resolution_source(Arc::new(resolution_source))
+    }
+
+    /// Add alias, which resolve current target service as alias service
+    ///
+    /// Alias follow target changes, so replaced or deleted target affect alias too
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.alias::<Arc<dyn LegacyStore>, Arc<dyn Store>>().unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn alias<TAlias: Sync + Send + 'static, TTarget: Sync + Send + 'static + CoerceUnsized<TAlias>>(&self) -> AddDependencyResult<()> {
+        self.core_context.add_alias::<TAlias, TTarget>()
     }
 
     /// Map component as service
@@ -1345,7 +3092,7 @@ impl DependencyContext {
     /// ```
     #[inline(always)]
     pub fn map_component<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static>(&self) -> MapComponentResult<Arc<CoreContext>> where TComponent: Unsize<TService> {
-        self.core_context.map_component::<TComponent, TService>()
+        self.track_mapping(self.core_context.map_component::<TComponent, TService>())
     }
 
     /// Resolve first component, mapped to service
@@ -1393,9 +3140,75 @@ impl DependencyContext {
     /// ```
     #[inline(always)]
     pub fn resolve<'a, TService: Sync + Send + 'static>(&'a self) -> BuildDependencyResult<TService> {
+        if let Some(service) = TService::build_implicit(self) {
+            return Ok(service);
+        }
+
         self.core_context.resolve::<TService>(self.id.clone(), self.local_context.clone())
     }
 
+    /// Resolve service, or None if service not registered
+    ///
+    /// Unlike ```is_service_exist``` + ```resolve```, service searched once. Construction errors are propagated
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let plugin: Option<Box<dyn SomePlugin>> = root_context.try_resolve::<Box<dyn SomePlugin>>().unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn try_resolve<TService: Sync + Send + 'static>(&self) -> BuildDependencyResult<Option<TService>> {
+        if let Some(service) = TService::build_implicit(self) {
+            return Ok(Some(service));
+        }
+
+        self.core_context.try_resolve::<TService>(self.id.clone(), self.local_context.clone())
+    }
+
+    /// Resolve component, registered on demand, if it not registered
+    /// 
+    /// Life cycle chosen by service type: ```T``` - Transient, ```Arc<T>``` - Singleton, ```Weak<T>``` - ContextDependent    
+    /// If ```ContextSettings::auto_wired_life_cycle``` set, other life cycle return ```AddDependencyError::LifeCycleMismatch``` before registration    
+    /// Already registered component resolved as usual
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// let transient: SomeComponent = root_context.resolve_or_construct().unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn resolve_or_construct<TService: AutoWired>(&self) -> BuildDependencyResult<TService> {
+        self.register_auto_wired::<TService>()
+            .map_err(|err| BuildDependencyError::AddDependencyError { err })?;
+
+        self.resolve::<TService>()
+    }
+
+    // Register component of auto wired service, if it not registered
+    pub (crate) fn register_auto_wired<TService: AutoWired>(&self) -> AddDependencyResult<()> {
+        if self.is_component_exist::<TService::Component>() {
+            return Ok(());
+        }
+
+        // Service type define life cycle of resolved component, so other life cycle can't be registered
+        if let Some(life_cycle) = self.core_context.settings.auto_wired_life_cycle {
+            if life_cycle != TService::LIFE_CYCLE {
+                return Err(AddDependencyError::LifeCycleMismatch {
+                    component_type_info: TypeInfo::from_type::<TService::Component>(),
+                    expected_life_cycle: life_cycle,
+                    found_life_cycle: TService::LIFE_CYCLE,
+                });
+            }
+        }
+
+        // Component can be registered concurrently, then registered component resolved
+        match self.register_type::<TService::Component>(TService::LIFE_CYCLE) {
+            Ok(_) | Err(AddDependencyError::DependencyExist { .. }) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
     /// Resolve component with TypeId, mapped to service
     /// That may be helpful in case, when you need current component, type is lost, but you can save TypeId as variable
     /// ``` ignore
@@ -1447,6 +3260,49 @@ impl DependencyContext {
         self.core_context.resolve_by_type_id::<TService>(component_type_id, self.id.clone(), self.local_context.clone())
     }
 
+    /// Resolve component, mapped to service with name
+    /// 
+    /// Service must be mapped with ```map_as_named```, unnamed mappings not used
+    /// ``` ignore
+    /// // You can resolve transient like:
+    /// ctx.resolve_named::<Box<dyn Service>>("name").unwrap()
+    /// 
+    /// // You can resolve singleton like:
+    /// ctx.resolve_named::<Arc<dyn Service>>("name").unwrap()
+    /// 
+    /// // You can resolve context dependent like:
+    /// ctx.resolve_named::<Weak<dyn Service>>("name").unwrap()
+    /// ```
+    ///# Example
+    ///---
+    /// From root context
+    /// ```ignore
+    /// trait SomeService {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// let service: Box<dyn SomeTrait> = root_context.resolve_named::<Box<dyn SomeService>>("primary").unwrap();
+    /// ```
+    /// 
+    /// ---
+    /// 
+    /// From ctor context
+    /// ```ignore
+    /// trait SomeService {}
+    /// 
+    /// impl Constructor for SomeOtherComponent {
+    ///     fn ctor(ctx: crate::DependencyContext) -> BuildDependencyResult<Self> {
+    ///         // unwrap or map error to BuildDependencyError
+    ///         let service: Box<dyn SomeTrait> = ctx.resolve_named::<Box<dyn SomeService>>("primary").unwrap();
+    ///         Ok(Self { })
+    ///     }
+    /// }
+    /// 
+    /// ```
+    #[inline(always)]
+    pub fn resolve_named<TService: Sync + Send + 'static>(&self, name: &str) -> BuildDependencyResult<TService> {
+        self.core_context.resolve_named::<TService>(name, self.id.clone(), self.local_context.clone())
+    }
+
     /// Resolve all component, mapped to service
     /// ``` ignore
     /// // You can resolve transient like:
@@ -1495,6 +3351,61 @@ impl DependencyContext {
         self.core_context.resolve_collection::<TService>(self.id.clone(), self.local_context.clone())
     }
 
+    /// Resolve services, which mapping metadata match filter
+    ///
+    /// Filtered out components are not constructed    
+    /// Aliases and contextual bindings applied like in ```resolve_collection```, alias filter target metadata
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let http_handlers = root_context.resolve_collection_where::<Box<dyn Handler>, _>(|metadata| metadata.tag("http")).unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn resolve_collection_where<TService: Sync + Send + 'static, TFilter: Fn(&ServiceMetadata) -> bool + Sync + Send + 'static>(&self, filter: TFilter) -> BuildDependencyResult<Vec<TService>> {
+        self.core_context.resolve_collection_where::<TService, TFilter>(filter, self.id.clone(), self.local_context.clone())
+    }
+
+    /// Get mapping info of services, returned by ```resolve_collection```, without construction
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let handlers_info = root_context.get_collection_metadata::<Box<dyn Handler>>();
+    /// let routes: Vec<_> = handlers_info.iter().filter_map(|info| info.metadata.value("route")).collect();
+    /// ```
+    #[inline(always)]
+    pub fn get_collection_metadata<TService: 'static>(&self) -> Vec<ServiceInfo> {
+        self.core_context.get_collection_metadata::<TService>()
+    }
+
+    /// Resolve service from component, registered with args
+    ///
+    /// Args type checked with registered args type, mismatch return ```BuildDependencyError::ArgsTypeMismatch```    
+    /// Aliases and contextual bindings applied like in ```resolve```
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let report = root_context.resolve_with::<Box<dyn Report>, (TenantId, Period)>((tenant_id, period)).unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn resolve_with<TService: Sync + Send + 'static, TArgs: Sync + Send + 'static>(&self, args: TArgs) -> BuildDependencyResult<TService> {
+        self.core_context.resolve_with::<TService, TArgs>(args, self.id.clone(), self.local_context.clone())
+    }
+
+    /// Call function, which arguments resolved from context
+    /// 
+    /// Arguments resolved like ```resolve::<TArgument>()```, with current local context    
+    /// Failed argument returned as ```BuildDependencyError::ArgumentResolveError```, with function result type as component
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// let result = root_context.invoke(|db: Arc<dyn Db>, log: Box<dyn Log>| run_migrations(db, log)).unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn invoke<TResult: 'static, TArgs: 'static, TFunction: InjectFn<TArgs, TResult>>(&self, function: TFunction) -> BuildDependencyResult<TResult> {
+        function.call(self)
+    }
+
     /// Delete component
     ///# Example
     ///---
@@ -1526,6 +3437,97 @@ impl DependencyContext {
         self.core_context.delete_component::<TComponent>()
     }
 
+    /// Delete all components, which have mapping with tag
+    ///
+    /// Return deleted components. If any component not support delete, nothing deleted
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let deleted_components = root_context.delete_components_with_tag("http").unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn delete_components_with_tag(&self, tag: &str) -> DeleteComponentResult<Vec<TypeInfo>> {
+        self.core_context.delete_components_with_tag(tag)
+    }
+
+    /// Install module
+    /// 
+    /// Components registered and mapped by module context are saved, to delete them on uninstall
+    /// 
+    /// Module dependencies must be installed before. If install failed, registered components are deleted    
+    /// Module can't register context dependent components, they can't be deleted on uninstall
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.install_module(SomeModule::new()).unwrap();
+    /// ```
+    pub fn install_module<TModule: DependencyModule>(&self, module: TModule) -> InstallModuleResult<()> {
+        let module_type_info = TypeInfo::from_type::<TModule>();
+        let dependencies = module.dependencies();
+
+        self.core_context.check_module_install(&module_type_info, &dependencies)?;
+
+        let module_tracker = Arc::new(ModuleTracker::default());
+        let module_context = Self { module_tracker: Some(module_tracker.clone()), ..self.clone() };
+
+        let install_result = module.install(&module_context);
+
+        let components = std::mem::take(&mut *module_tracker.components.lock().unwrap());
+        let mappings = std::mem::take(&mut *module_tracker.mappings.lock().unwrap());
+
+        if let Err(err) = install_result {
+            self.core_context.delete_module_registrations(&components, &mappings);
+            return Err(err);
+        }
+
+        let installed_module = InstalledModule {
+            module: Arc::new(module),
+            module_type_info: module_type_info.clone(),
+            dependencies,
+            components: components.clone(),
+            mappings: mappings.clone(),
+        };
+
+        if let Err(err) = self.core_context.add_module(&module_type_info, installed_module) {
+            self.core_context.delete_module_registrations(&components, &mappings);
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    /// Uninstall module and delete all components and mappings, added by module
+    /// 
+    /// Module can't be uninstalled, while other installed module depends on it    
+    /// Module uninstall hook is called before final check, so failed uninstall can call hook again on retry
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.install_module(SomeModule::new()).unwrap();
+    /// root_context.uninstall_module::<SomeModule>().unwrap();
+    /// ```
+    pub fn uninstall_module<TModule: DependencyModule>(&self) -> UninstallModuleResult<()> {
+        let module_type_info = TypeInfo::from_type::<TModule>();
+
+        let module = self.core_context.get_module_for_uninstall(&module_type_info)?;
+        module.uninstall(self)?;
+
+        self.core_context.delete_module(&module_type_info)
+    }
+
+    /// Check module installed
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let root_context = DependencyContext::new_root();
+    /// let is_installed = root_context.is_module_installed::<SomeModule>();
+    /// ```
+    pub fn is_module_installed<TModule: DependencyModule>(&self) -> bool {
+        self.core_context.is_module_installed(TypeId::of::<TModule>())
+    }
+
     /// Check service existence
     /// ``` ignore
     /// // You can check transient like:
@@ -1620,6 +3622,46 @@ impl DependencyContext {
         self.core_context.is_service_exist(service_type_id)
     }
 
+    /// Check named service existence
+    /// ``` ignore
+    /// // You can check transient like:
+    /// ctx.is_named_service_exist::<Box<dyn Service>>("name")
+    /// 
+    /// // You can check singleton like:
+    /// ctx.is_named_service_exist::<Arc<dyn Service>>("name")
+    /// 
+    /// // You can check context dependent like:
+    /// ctx.is_named_service_exist::<Weak<dyn Service>>("name")
+    /// ```
+    ///# Example
+    ///---
+    /// From root context
+    /// ```ignore
+    /// trait SomeService {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// let is_exist: bool = root_context.is_named_service_exist::<Box<dyn SomeService>>("primary");
+    /// ```
+    /// 
+    /// ---
+    /// 
+    /// From ctor context
+    /// ```ignore
+    /// trait SomeService {}
+    /// 
+    /// impl Constructor for SomeOtherComponent {
+    ///     fn ctor(ctx: crate::DependencyContext) -> BuildDependencyResult<Self> {
+    ///         let is_exist: bool = ctx.is_named_service_exist::<Box<dyn SomeService>>("primary");
+    ///         Ok(Self { })
+    ///     }
+    /// }
+    /// 
+    /// ```
+    #[inline(always)]
+    pub fn is_named_service_exist<TService: 'static>(&self, name: &str) -> bool {
+        self.core_context.is_named_service_exist(TypeId::of::<TService>(), name)
+    }
+
     /// Check component existence
     ///# Example
     ///---
@@ -0,0 +1,112 @@
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    DependencyContext,
+    service::CycledComponentServicePair,
+    types::{
+        InstallModuleResult,
+        TypeInfo,
+        UninstallModuleResult,
+    },
+};
+
+/// Group of registrations, installed and uninstalled as one unit
+///
+/// Components registered and mapped by module context in ```install``` are deleted on module uninstall    
+/// Context dependent components can't be deleted, so module with them is not installed (```InstallModuleError::NotSupportedLifeCycle```)
+///# Example
+///---
+/// ```ignore
+/// struct SomeModule {}
+///
+/// #[async_trait_with_sync::async_trait(Sync)]
+/// impl DependencyModule for SomeModule {
+///     async fn install(&self, ctx: &DependencyContext) -> InstallModuleResult<()> {
+///         ctx.register_type::<SomeComponent>(LifeCycle::Transient).await
+///             .map_err(|err| InstallModuleError::AddDependencyError { err })?;
+///         Ok(())
+///     }
+///
+///     fn dependencies(&self) -> Vec<TypeInfo> {
+///         vec![TypeInfo::from_type::<SomeOtherModule>()]
+///     }
+/// }
+/// ```
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+pub trait DependencyModule where Self: Sync + Send + 'static {
+    /// Register module components
+    async fn install(&self, ctx: &DependencyContext) -> InstallModuleResult<()>;
+
+    /// Called before module components deleted
+    /// 
+    /// Uninstall is checked again after hook, module is kept if dependent module installed or component can't be deleted meanwhile.
+    /// Then hook is called again on next uninstall, so it must be repeatable
+    async fn uninstall(&self, _ctx: &DependencyContext) -> UninstallModuleResult<()> { Ok(()) }
+
+    /// Modules, which must be installed before this module
+    fn dependencies(&self) -> Vec<TypeInfo> { Vec::new() }
+}
+
+/// Group of registrations, installed and uninstalled as one unit
+///
+/// Components registered and mapped by module context in ```install``` are deleted on module uninstall    
+/// Context dependent components can't be deleted, so module with them is not installed (```InstallModuleError::NotSupportedLifeCycle```)
+///# Example
+///---
+/// ```ignore
+/// struct SomeModule {}
+///
+/// impl DependencyModule for SomeModule {
+///     fn install(&self, ctx: &DependencyContext) -> InstallModuleResult<()> {
+///         ctx.register_type::<SomeComponent>(LifeCycle::Transient)
+///             .map_err(|err| InstallModuleError::AddDependencyError { err })?;
+///         Ok(())
+///     }
+///
+///     fn dependencies(&self) -> Vec<TypeInfo> {
+///         vec![TypeInfo::from_type::<SomeOtherModule>()]
+///     }
+/// }
+/// ```
+#[cfg(not(feature = "async-mode"))]
+pub trait DependencyModule where Self: Sync + Send + 'static {
+    /// Register module components
+    fn install(&self, ctx: &DependencyContext) -> InstallModuleResult<()>;
+
+    /// Called before module components deleted
+    /// 
+    /// Uninstall is checked again after hook, module is kept if dependent module installed or component can't be deleted meanwhile.
+    /// Then hook is called again on next uninstall, so it must be repeatable
+    fn uninstall(&self, _ctx: &DependencyContext) -> UninstallModuleResult<()> { Ok(()) }
+
+    /// Modules, which must be installed before this module
+    fn dependencies(&self) -> Vec<TypeInfo> { Vec::new() }
+}
+
+// Collect registrations, made by module context while module installed
+// Not async lock, because lock never held across await
+#[derive(Debug, Default)]
+pub (crate) struct ModuleTracker {
+    pub (crate) components: Mutex<Vec<TypeInfo>>,
+    pub (crate) mappings: Mutex<Vec<Arc<CycledComponentServicePair>>>,
+}
+
+pub (crate) struct InstalledModule {
+    pub (crate) module: Arc<dyn DependencyModule>,
+    pub (crate) module_type_info: TypeInfo,
+    pub (crate) dependencies: Vec<TypeInfo>,
+    pub (crate) components: Vec<TypeInfo>,
+    pub (crate) mappings: Vec<Arc<CycledComponentServicePair>>,
+}
+
+impl std::fmt::Debug for InstalledModule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InstalledModule")
+            .field("module_type_info", &self.module_type_info)
+            .field("dependencies", &self.dependencies)
+            .field("components", &self.components)
+            .field("mappings", &self.mappings)
+            .finish()
+    }
+}
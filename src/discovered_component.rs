@@ -0,0 +1,27 @@
+#[cfg(feature = "async-mode")]
+use std::{future::Future, pin::Pin};
+
+use crate::{
+    DependencyContext,
+    types::RegisterDiscoveredResult,
+};
+
+#[cfg(feature = "async-mode")]
+pub type DiscoveredComponentRegister = fn(DependencyContext) -> Pin<Box<dyn Future<Output = RegisterDiscoveredResult<()>> + Send>>;
+
+#[cfg(not(feature = "async-mode"))]
+pub type DiscoveredComponentRegister = fn(DependencyContext) -> RegisterDiscoveredResult<()>;
+
+/// Component registration, submitted by ```#[component]``` attribute
+#[doc(hidden)]
+pub struct DiscoveredComponent {
+    pub (crate) register: DiscoveredComponentRegister,
+}
+
+impl DiscoveredComponent {
+    pub const fn new(register: DiscoveredComponentRegister) -> Self {
+        Self { register }
+    }
+}
+
+inventory::collect!(DiscoveredComponent);
@@ -0,0 +1,79 @@
+use std::marker::PhantomData;
+
+use crate::{
+    DependencyContext,
+    ImplicitService,
+    types::BuildDependencyResult,
+};
+
+/// Cloneable handle, which resolve new service instance on each ```create``` call
+///
+/// Resolved from any context without registration. Service resolved from requesting component context,
+/// with same cycle check and local context as ```resolve```
+///# Example
+///---
+/// ```ignore
+/// struct SomeComponent {
+///     worker_factory: Factory<Box<dyn SomeWorker>>,
+/// }
+///
+/// let factory = root_context.resolve::<Factory<Box<dyn SomeWorker>>>().await.unwrap();
+/// let worker: Box<dyn SomeWorker> = factory.create().await.unwrap();
+/// ```
+#[derive(Debug)]
+pub struct Factory<T> {
+    ctx: DependencyContext,
+    service: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Factory<T> {
+    fn clone(&self) -> Self {
+        Self { ctx: self.ctx.clone(), service: PhantomData }
+    }
+}
+
+impl<T> ImplicitService for Factory<T> {
+    fn build_implicit(ctx: &DependencyContext) -> Option<Self> {
+        Some(Self { ctx: ctx.clone(), service: PhantomData })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+impl<T: Sync + Send + 'static> Factory<T> {
+    /// Resolve new service instance
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let worker: Box<dyn SomeWorker> = factory.create().await.unwrap();
+    /// ```
+    #[inline(always)]
+    pub async fn create(&self) -> BuildDependencyResult<T> {
+        self.ctx.resolve::<T>().await
+    }
+
+    /// Resolve new service instance (blocking version)
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let worker: Box<dyn SomeWorker> = factory.blocking_create().unwrap();
+    /// ```
+    #[cfg(feature = "blocking")]
+    #[inline(always)]
+    pub fn blocking_create(&self) -> BuildDependencyResult<T> {
+        self.ctx.blocking_resolve::<T>()
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl<T: Sync + Send + 'static> Factory<T> {
+    /// Resolve new service instance
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let worker: Box<dyn SomeWorker> = factory.create().unwrap();
+    /// ```
+    #[inline(always)]
+    pub fn create(&self) -> BuildDependencyResult<T> {
+        self.ctx.resolve::<T>()
+    }
+}
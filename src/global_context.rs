@@ -3,10 +3,26 @@ use derive_new::new;
 
 use crate::types::AnthillRwLock;
 
+// Shared component instance, empty while instance constructed
+pub (crate) type InstanceSlot = Arc<AnthillRwLock<Option<Arc<dyn Any + Sync + Send>>>>;
+
+// Decorated service by cycled component and service ids, cached with shared component instance
+pub (crate) type DecoratedServices = HashMap<(TypeId, TypeId), Arc<AnthillRwLock<Option<Box<dyn Any + Sync + Send>>>>>;
+
 #[derive(Default, new)]
 pub (crate) struct GlobalContext {
     #[new(default)]
-    pub (crate) singletons: HashMap<TypeId, Arc<AnthillRwLock<Option<Arc<dyn Any + Sync + Send>>>>>,
+    pub (crate) singletons: HashMap<TypeId, InstanceSlot>,
+    #[new(default)]
+    pub (crate) decorated_singletons: DecoratedServices,
+}
+
+impl GlobalContext {
+    // Singleton and its decorated services dropped together
+    pub (crate) fn remove_singleton(&mut self, cycled_component_id: &TypeId) {
+        _ = self.singletons.remove(cycled_component_id);
+        self.decorated_singletons.retain(|(decorated_cycled_component_id, _), _| decorated_cycled_component_id != cycled_component_id);
+    }
 }
 
 impl std::fmt::Debug for GlobalContext {
@@ -14,6 +30,7 @@ impl std::fmt::Debug for GlobalContext {
         f.debug_struct("GlobalContext")
             .field("singletons",
             &self.singletons.iter().map(|(id, instance)| (id, if instance.try_read().unwrap().is_none() {"None"} else {"Not Empty"})).collect::<HashMap<_, _>>())
+            .field("decorated_singletons_count", &self.decorated_singletons.len())
             .finish()
     }
 }
\ No newline at end of file
@@ -0,0 +1,12 @@
+use crate::DependencyContext;
+
+// Service, which resolved from any context without registration (Lazy<T>, etc.)
+// Resolve try build implicit service before search registered service
+pub (crate) trait ImplicitService: Sized {
+    fn build_implicit(ctx: &DependencyContext) -> Option<Self>;
+}
+
+impl<T> ImplicitService for T {
+    #[inline(always)]
+    default fn build_implicit(_: &DependencyContext) -> Option<Self> { None }
+}
@@ -0,0 +1,131 @@
+#[cfg(feature = "async-mode")]
+use std::future::Future;
+
+use crate::{
+    DependencyContext,
+    types::{
+        BuildDependencyError,
+        BuildDependencyResult,
+        TypeInfo,
+    },
+};
+
+/// Function, which arguments resolved from context
+///
+/// Implemented for ```Fn``` with up to 12 arguments, argument types resolved like ```ctx.resolve::<TArgument>()```    
+/// Used to register component with ```register_fn``` and to call function with ```invoke```
+///# Example
+///---
+/// ```ignore
+/// root_context.register_fn(|db: Arc<dyn Db>, log: Box<dyn Log>| Service::new(db, log), LifeCycle::Singleton).await.unwrap();
+/// ```
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+pub trait InjectFn<TArgs, TComponent> where Self: Sync + Send {
+    /// Resolve arguments and call function
+    async fn call(&self, ctx: &DependencyContext) -> BuildDependencyResult<TComponent>;
+}
+
+/// Async function, which arguments resolved from context
+///
+/// Implemented for ```Fn``` with up to 12 arguments, returning future    
+/// Used to register component with ```register_async_fn``` and to call function with ```invoke_async```
+///# Example
+///---
+/// ```ignore
+/// root_context.register_async_fn(async |db: Arc<dyn Db>| Service::connect(db).await, LifeCycle::Singleton).await.unwrap();
+/// ```
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+pub trait InjectAsyncFn<TArgs, TComponent> where Self: Sync + Send {
+    /// Resolve arguments, call function and await result
+    async fn call(&self, ctx: &DependencyContext) -> BuildDependencyResult<TComponent>;
+}
+
+/// Function, which arguments resolved from context
+///
+/// Implemented for ```Fn``` with up to 12 arguments, argument types resolved like ```ctx.resolve::<TArgument>()```    
+/// Used to register component with ```register_fn``` and to call function with ```invoke```
+///# Example
+///---
+/// ```ignore
+/// root_context.register_fn(|db: Arc<dyn Db>, log: Box<dyn Log>| Service::new(db, log), LifeCycle::Singleton).unwrap();
+/// ```
+#[cfg(not(feature = "async-mode"))]
+pub trait InjectFn<TArgs, TComponent> where Self: Sync + Send {
+    /// Resolve arguments and call function
+    fn call(&self, ctx: &DependencyContext) -> BuildDependencyResult<TComponent>;
+}
+
+// Wrap argument error, to show which argument failed
+fn argument_resolve_error<TComponent: 'static, TArgument: 'static>(argument_index: usize, err: BuildDependencyError) -> BuildDependencyError {
+    BuildDependencyError::ArgumentResolveError {
+        component_type_info: TypeInfo::from_type::<TComponent>(),
+        argument_index,
+        argument_type_info: TypeInfo::from_type::<TArgument>(),
+        err: Box::new(err),
+    }
+}
+
+macro_rules! impl_inject_fn {
+    ($(($idx:tt, $arg:ident)),*) => {
+        #[cfg(feature = "async-mode")]
+        #[async_trait_with_sync::async_trait(Sync)]
+        impl<TFunction, TComponent, $($arg),*> InjectFn<($($arg,)*), TComponent> for TFunction
+        where
+            TFunction: Fn($($arg),*) -> TComponent + Sync + Send,
+            TComponent: 'static,
+            $($arg: Sync + Send + 'static,)*
+        {
+            #[allow(non_snake_case, unused_variables)]
+            async fn call(&self, ctx: &DependencyContext) -> BuildDependencyResult<TComponent> {
+                $(let $arg = ctx.resolve::<$arg>().await.map_err(|err| argument_resolve_error::<TComponent, $arg>($idx, err))?;)*
+                Ok((self)($($arg),*))
+            }
+        }
+
+        #[cfg(feature = "async-mode")]
+        #[async_trait_with_sync::async_trait(Sync)]
+        impl<TFunction, TFuture, TComponent, $($arg),*> InjectAsyncFn<($($arg,)*), TComponent> for TFunction
+        where
+            TFunction: Fn($($arg),*) -> TFuture + Sync + Send,
+            TFuture: Future<Output = TComponent> + Sync + Send + 'static,
+            TComponent: 'static,
+            $($arg: Sync + Send + 'static,)*
+        {
+            #[allow(non_snake_case, unused_variables)]
+            async fn call(&self, ctx: &DependencyContext) -> BuildDependencyResult<TComponent> {
+                $(let $arg = ctx.resolve::<$arg>().await.map_err(|err| argument_resolve_error::<TComponent, $arg>($idx, err))?;)*
+                Ok((self)($($arg),*).await)
+            }
+        }
+
+        #[cfg(not(feature = "async-mode"))]
+        impl<TFunction, TComponent, $($arg),*> InjectFn<($($arg,)*), TComponent> for TFunction
+        where
+            TFunction: Fn($($arg),*) -> TComponent + Sync + Send,
+            TComponent: 'static,
+            $($arg: Sync + Send + 'static,)*
+        {
+            #[allow(non_snake_case, unused_variables)]
+            fn call(&self, ctx: &DependencyContext) -> BuildDependencyResult<TComponent> {
+                $(let $arg = ctx.resolve::<$arg>().map_err(|err| argument_resolve_error::<TComponent, $arg>($idx, err))?;)*
+                Ok((self)($($arg),*))
+            }
+        }
+    };
+}
+
+impl_inject_fn!();
+impl_inject_fn!((0, T0));
+impl_inject_fn!((0, T0), (1, T1));
+impl_inject_fn!((0, T0), (1, T1), (2, T2));
+impl_inject_fn!((0, T0), (1, T1), (2, T2), (3, T3));
+impl_inject_fn!((0, T0), (1, T1), (2, T2), (3, T3), (4, T4));
+impl_inject_fn!((0, T0), (1, T1), (2, T2), (3, T3), (4, T4), (5, T5));
+impl_inject_fn!((0, T0), (1, T1), (2, T2), (3, T3), (4, T4), (5, T5), (6, T6));
+impl_inject_fn!((0, T0), (1, T1), (2, T2), (3, T3), (4, T4), (5, T5), (6, T6), (7, T7));
+impl_inject_fn!((0, T0), (1, T1), (2, T2), (3, T3), (4, T4), (5, T5), (6, T6), (7, T7), (8, T8));
+impl_inject_fn!((0, T0), (1, T1), (2, T2), (3, T3), (4, T4), (5, T5), (6, T6), (7, T7), (8, T8), (9, T9));
+impl_inject_fn!((0, T0), (1, T1), (2, T2), (3, T3), (4, T4), (5, T5), (6, T6), (7, T7), (8, T8), (9, T9), (10, T10));
+impl_inject_fn!((0, T0), (1, T1), (2, T2), (3, T3), (4, T4), (5, T5), (6, T6), (7, T7), (8, T8), (9, T9), (10, T10), (11, T11));
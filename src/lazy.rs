@@ -0,0 +1,96 @@
+#[cfg(feature = "async-mode")]
+use tokio::sync::OnceCell;
+#[cfg(not(feature = "async-mode"))]
+use std::sync::OnceLock;
+#[cfg(feature = "blocking")]
+use tokio::runtime::Builder;
+
+use crate::{
+    DependencyContext,
+    ImplicitService,
+    types::BuildDependencyResult,
+};
+
+/// Service wrapper, which resolve service on first use and cache it
+///
+/// Resolved from any context without registration. Service resolve is not linked with requesting component,
+/// so lazy service can break construction cycle
+///# Example
+///---
+/// ```ignore
+/// struct SomeComponent {
+///     service: Lazy<Arc<dyn SomeService>>,
+/// }
+///
+/// let lazy = root_context.resolve::<Lazy<Arc<dyn SomeService>>>().await.unwrap();
+/// let service: &Arc<dyn SomeService> = lazy.get().await.unwrap();
+/// ```
+#[derive(Debug)]
+pub struct Lazy<T> {
+    ctx: DependencyContext,
+    #[cfg(feature = "async-mode")]
+    value: OnceCell<T>,
+    #[cfg(not(feature = "async-mode"))]
+    value: OnceLock<T>,
+}
+
+impl<T> ImplicitService for Lazy<T> {
+    fn build_implicit(ctx: &DependencyContext) -> Option<Self> {
+        Some(Self {
+            ctx: ctx.without_parent(),
+            value: Default::default(),
+        })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+impl<T: Sync + Send + 'static> Lazy<T> {
+    /// Resolve service on first call, next calls return cached service
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let service: &Arc<dyn SomeService> = lazy.get().await.unwrap();
+    /// ```
+    pub async fn get(&self) -> BuildDependencyResult<&T> {
+        self.value.get_or_try_init(|| self.ctx.resolve::<T>()).await
+    }
+
+    /// Resolve service on first call, next calls return cached service (blocking version)
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let service: &Arc<dyn SomeService> = lazy.blocking_get().unwrap();
+    /// ```
+    #[cfg(feature = "blocking")]
+    pub fn blocking_get(&self) -> BuildDependencyResult<&T> {
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                Builder::new_current_thread().enable_all().build().unwrap().block_on(self.get())
+            }).join().unwrap()
+        })
+    }
+
+    /// Check service resolved
+    pub fn is_resolved(&self) -> bool { self.value.initialized() }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl<T: Sync + Send + 'static> Lazy<T> {
+    /// Resolve service on first call, next calls return cached service
+    ///# Example
+    ///---
+    /// ```ignore
+    /// let service: &Arc<dyn SomeService> = lazy.get().unwrap();
+    /// ```
+    pub fn get(&self) -> BuildDependencyResult<&T> {
+        if let Some(value) = self.value.get() {
+            return Ok(value);
+        }
+
+        let value = self.ctx.resolve::<T>()?;
+        Ok(self.value.get_or_init(|| value))
+    }
+
+    /// Check service resolved
+    pub fn is_resolved(&self) -> bool { self.value.get().is_some() }
+}
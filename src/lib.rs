@@ -3,6 +3,10 @@
 #![feature(box_into_inner)]
 #![feature(coerce_unsized)]
 #![feature(trait_alias)]
+#![feature(min_specialization)]
+
+// Derive macros use absolute crate path, it required for use derive inside crate
+extern crate self as anthill_di;
 
 mod core_context;
 pub (crate) use core_context::*;
@@ -13,6 +17,9 @@ pub use dependency_context::*;
 mod life_cycle;
 pub use life_cycle::*;
 
+mod context_settings;
+pub use context_settings::*;
+
 mod component;
 pub (crate) use component::*;
 
@@ -30,6 +37,58 @@ pub use service_mapping_builder::*;
 mod constructor;
 pub use constructor::*;
 
+mod dependency_module;
+pub use dependency_module::*;
+
+mod implicit_service;
+pub (crate) use implicit_service::*;
+
+mod lazy;
+pub use lazy::*;
+
+mod factory;
+pub use factory::*;
+
+mod decorator;
+pub use decorator::*;
+
+mod resolve_interceptor;
+pub use resolve_interceptor::*;
+
+mod service_metadata;
+pub use service_metadata::*;
+
+mod inject_fn;
+pub use inject_fn::*;
+
+mod contextual_binding;
+pub use contextual_binding::*;
+
+mod resolution_source;
+pub use resolution_source::*;
+
+mod auto_wired;
+pub use auto_wired::*;
+
+#[cfg(feature = "derive")]
+pub use anthill_di_derive::Constructor;
+
+#[cfg(feature = "async-mode")]
+#[doc(hidden)]
+pub use async_trait_with_sync;
+
+#[cfg(feature = "discovery")]
+mod discovered_component;
+#[cfg(feature = "discovery")]
+pub use discovered_component::*;
+
+#[cfg(feature = "discovery")]
+pub use anthill_di_derive::component;
+
+#[cfg(feature = "discovery")]
+#[doc(hidden)]
+pub use inventory;
+
 
 mod global_context;
 pub (crate) use global_context::*;
@@ -37,6 +96,8 @@ pub (crate) use global_context::*;
 mod constructors;
 pub (crate) use constructors::*;
 
+mod decorators;
+
 pub mod types;
 pub mod service;
 pub mod cycled_components;
@@ -1,17 +1,17 @@
 use std::{
-    any::{
-        Any,
-        TypeId
-    },
+    any::TypeId,
     collections::HashMap,
-    sync::Arc
 };
 
-use crate::types::AnthillRwLock;
+use crate::{
+    types::AnthillRwLock,
+    global_context::{DecoratedServices, InstanceSlot},
+};
 
 #[derive(Default)]
 pub struct LocalContext {
-    pub (crate) local_context: AnthillRwLock<HashMap<TypeId, Arc<AnthillRwLock<Option<Arc<dyn Any + Sync + Send>>>>>>,
+    pub (crate) local_context: AnthillRwLock<HashMap<TypeId, InstanceSlot>>,
+    pub (crate) decorated_services: AnthillRwLock<DecoratedServices>,
 }
 
 impl std::fmt::Debug for LocalContext {
@@ -22,6 +22,7 @@ impl std::fmt::Debug for LocalContext {
                     .iter().map(|(id, instance)| (id.clone(), if instance.try_read().unwrap().is_none() {"None"} else {"Not Empty"}))
                     .collect::<HashMap<_,_>>()
             )
+            .field("decorated_services_count", &self.decorated_services.try_read().unwrap().len())
             .finish()
     }
 }
\ No newline at end of file
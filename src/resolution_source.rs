@@ -0,0 +1,85 @@
+use std::any::Any;
+
+use crate::{
+    DependencyContext,
+    types::{
+        BuildDependencyResult,
+        TypeInfo,
+    },
+};
+
+/// Result of resolution source for requested service
+pub enum SourceResolution {
+    /// Source can't produce service, next source asked
+    NotFound,
+    /// Service instance, must be requested service type. Instance not saved, source asked again on next resolve
+    Instance(Box<dyn Any + Sync + Send>),
+    /// Source registered component for service with chosen life cycle, service resolved from registry
+    Registered,
+}
+
+impl std::fmt::Debug for SourceResolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "NotFound"),
+            Self::Instance(_) => write!(f, "Instance"),
+            Self::Registered => write!(f, "Registered"),
+        }
+    }
+}
+
+/// Source of services without mappings
+///
+/// Sources asked in adding order only by ```resolve```, ```try_resolve``` and ```resolve_collection```, if service has no mappings    
+/// ```Lazy```, ```Factory``` and derive ```#[inject]``` resolve by ```resolve```/```try_resolve```, so they ask sources too    
+/// Source receive only service type, so ```resolve_named```, ```resolve_with```, ```resolve_collection_where``` and ```resolve_by_type_id``` never ask sources and return not found error
+///# Example
+///---
+/// ```ignore
+/// struct EnvSource {}
+///
+/// #[async_trait_with_sync::async_trait(Sync)]
+/// impl ResolutionSource for EnvSource {
+///     async fn resolve(&self, ctx: &DependencyContext, service_type_info: &TypeInfo) -> BuildDependencyResult<SourceResolution> {
+///         if service_type_info.type_id != TypeId::of::<Arc<DbSettings>>() {
+///             return Ok(SourceResolution::NotFound);
+///         }
+///
+///         ctx.register_arc(Arc::new(DbSettings::from_env())).await.unwrap();
+///         Ok(SourceResolution::Registered)
+///     }
+/// }
+/// ```
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+pub trait ResolutionSource where Self: Sync + Send + 'static {
+    /// Produce requested service or register component for it
+    async fn resolve(&self, ctx: &DependencyContext, service_type_info: &TypeInfo) -> BuildDependencyResult<SourceResolution>;
+}
+
+/// Source of services without mappings
+///
+/// Sources asked in adding order only by ```resolve```, ```try_resolve``` and ```resolve_collection```, if service has no mappings    
+/// ```Lazy```, ```Factory``` and derive ```#[inject]``` resolve by ```resolve```/```try_resolve```, so they ask sources too    
+/// Source receive only service type, so ```resolve_named```, ```resolve_with```, ```resolve_collection_where``` and ```resolve_by_type_id``` never ask sources and return not found error
+///# Example
+///---
+/// ```ignore
+/// struct EnvSource {}
+///
+/// impl ResolutionSource for EnvSource {
+///     fn resolve(&self, ctx: &DependencyContext, service_type_info: &TypeInfo) -> BuildDependencyResult<SourceResolution> {
+///         if service_type_info.type_id != TypeId::of::<Arc<DbSettings>>() {
+///             return Ok(SourceResolution::NotFound);
+///         }
+///
+///         ctx.register_arc(Arc::new(DbSettings::from_env())).unwrap();
+///         Ok(SourceResolution::Registered)
+///     }
+/// }
+/// ```
+#[cfg(not(feature = "async-mode"))]
+pub trait ResolutionSource where Self: Sync + Send + 'static {
+    /// Produce requested service or register component for it
+    fn resolve(&self, ctx: &DependencyContext, service_type_info: &TypeInfo) -> BuildDependencyResult<SourceResolution>;
+}
@@ -0,0 +1,76 @@
+use std::any::Any;
+
+use crate::{
+    LifeCycle,
+    types::{
+        BuildDependencyResult,
+        TypeInfo,
+    },
+};
+
+/// Component construction info, passed to ```ResolveInterceptor```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolveInfo {
+    /// Constructed component
+    pub component_type_info: TypeInfo,
+    /// Constructed component life cycle
+    pub life_cycle: LifeCycle,
+    /// Component, which requested constructed component. None if requested from root context
+    pub parent_type_info: Option<TypeInfo>,
+}
+
+/// Hook around every component ctor call
+///
+/// ```before_resolve``` called in registration order, ```after_resolve``` in reverse order, like middleware    
+/// ```before_resolve``` can return substitute instance (must be component type) or error, then ctor is not called    
+/// ```after_resolve``` called for every interceptor, which ```before_resolve``` called, even if ctor not called    
+/// Components with args, resolved by ```resolve_with```, intercepted too
+///# Example
+///---
+/// ```ignore
+/// struct AuditInterceptor {}
+///
+/// #[async_trait_with_sync::async_trait(Sync)]
+/// impl ResolveInterceptor for AuditInterceptor {
+///     async fn after_resolve(&self, info: &ResolveInfo, result: BuildDependencyResult<Box<dyn Any + Sync + Send>>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+///         println!("{info:?} constructed: {}", result.is_ok());
+///         result
+///     }
+/// }
+/// ```
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+pub trait ResolveInterceptor where Self: Sync + Send + 'static {
+    /// Called before ctor, return Some to skip ctor
+    async fn before_resolve(&self, _info: &ResolveInfo) -> BuildDependencyResult<Option<Box<dyn Any + Sync + Send>>> { Ok(None) }
+
+    /// Called after ctor, can replace ctor result
+    async fn after_resolve(&self, _info: &ResolveInfo, result: BuildDependencyResult<Box<dyn Any + Sync + Send>>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> { result }
+}
+
+/// Hook around every component ctor call
+///
+/// ```before_resolve``` called in registration order, ```after_resolve``` in reverse order, like middleware    
+/// ```before_resolve``` can return substitute instance (must be component type) or error, then ctor is not called    
+/// ```after_resolve``` called for every interceptor, which ```before_resolve``` called, even if ctor not called    
+/// Components with args, resolved by ```resolve_with```, intercepted too
+///# Example
+///---
+/// ```ignore
+/// struct AuditInterceptor {}
+///
+/// impl ResolveInterceptor for AuditInterceptor {
+///     fn after_resolve(&self, info: &ResolveInfo, result: BuildDependencyResult<Box<dyn Any + Sync + Send>>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+///         println!("{info:?} constructed: {}", result.is_ok());
+///         result
+///     }
+/// }
+/// ```
+#[cfg(not(feature = "async-mode"))]
+pub trait ResolveInterceptor where Self: Sync + Send + 'static {
+    /// Called before ctor, return Some to skip ctor
+    fn before_resolve(&self, _info: &ResolveInfo) -> BuildDependencyResult<Option<Box<dyn Any + Sync + Send>>> { Ok(None) }
+
+    /// Called after ctor, can replace ctor result
+    fn after_resolve(&self, _info: &ResolveInfo, result: BuildDependencyResult<Box<dyn Any + Sync + Send>>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> { result }
+}
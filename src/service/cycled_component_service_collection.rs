@@ -11,16 +11,23 @@ use std::{
     }
 };
 
+use crate::ServiceMetadata;
 #[cfg(feature = "debug-type-info")]
 use crate::types::TypeInfo;
 
-use super::{IServiceConstructor, SelfMappingService, BoxedTraitService, ArcTraitService, WeakTraitService};
+use super::{IServiceConstructor, SelfMappingService, BoxedTraitService, ArcTraitService, WeakTraitService, ServiceMappingOptions};
 
 #[derive(Debug)]
 pub (crate) struct CycledComponentServicePair {
     pub (crate) cycled_component_id: TypeId,
     pub (crate) service_id: TypeId,
     pub (crate) converter: Box<dyn IServiceConstructor>,
+    pub (crate) name: Option<String>,
+    pub (crate) is_default: bool,
+    pub (crate) order: i32,
+    pub (crate) is_fallback: bool,
+    pub (crate) is_composite: bool,
+    pub (crate) metadata: ServiceMetadata,
 
     #[allow(dead_code)]
     #[cfg(feature = "debug-type-info")]
@@ -35,7 +42,7 @@ impl Eq for CycledComponentServicePair {}
 
 impl PartialEq for CycledComponentServicePair {
     fn eq(&self, other: &Self) -> bool {
-        self.cycled_component_id == other.cycled_component_id && self.service_id == other.service_id
+        self.cycled_component_id == other.cycled_component_id && self.service_id == other.service_id && self.name == other.name
     }
 }
 
@@ -43,15 +50,22 @@ impl std::hash::Hash for CycledComponentServicePair {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.cycled_component_id.hash(state);
         self.service_id.hash(state);
+        self.name.hash(state);
     }
 }
 
 impl CycledComponentServicePair {
-    pub (crate) fn new<TComponent: 'static, TService: ?Sized + 'static>(converter: Box<dyn IServiceConstructor>) -> Self {
+    pub (crate) fn new<TComponent: 'static, TService: ?Sized + 'static>(converter: Box<dyn IServiceConstructor>, options: ServiceMappingOptions) -> Self {
         Self {
             service_id: TypeId::of::<TService>(),
             cycled_component_id: TypeId::of::<TComponent>(),
             converter,
+            name: options.name,
+            is_default: options.is_default,
+            order: options.order,
+            is_fallback: options.is_fallback,
+            is_composite: options.is_composite,
+            metadata: options.metadata,
             #[cfg(feature = "debug-type-info")]
             debug_cycled_component_type_info: TypeInfo::from_type::<TComponent>(),
             #[cfg(feature = "debug-type-info")]
@@ -62,74 +76,128 @@ impl CycledComponentServicePair {
 
 #[derive(Debug, Default)]
 pub (crate) struct CycledComponentServiceCollection {
-    pub (crate) services_search_idx: HashMap<TypeId, HashSet<Arc<CycledComponentServicePair>>>,
+    // Vec used for save registration order, sorted by mapping order
+    pub (crate) services_search_idx: HashMap<TypeId, Vec<Arc<CycledComponentServicePair>>>,
     pub (crate) cycled_components_search_idx: HashMap<TypeId, HashSet<Arc<CycledComponentServicePair>>>,
+    pub (crate) named_services_search_idx: HashMap<TypeId, HashMap<String, Arc<CycledComponentServicePair>>>,
 }
 
 impl CycledComponentServiceCollection {
     #[inline(always)]
-    pub (crate) fn add_mapping_as_self<TComponent: Sync + Send + 'static>(&mut self) {
-        let component_service_pair = Arc::new(CycledComponentServicePair::new::<TComponent, TComponent>(Box::new(SelfMappingService::<TComponent>::new())));
+    fn add_pair(&mut self, component_service_pair: Arc<CycledComponentServicePair>) {
+        // Named pair available only by name, so we dont add it in common service idx
+        match &component_service_pair.name {
+            Some(name) => _ = self.named_services_search_idx.entry(component_service_pair.service_id).or_default()
+                .insert(name.clone(), component_service_pair.clone()),
+            None => {
+                let service_idxes = self.services_search_idx.entry(component_service_pair.service_id).or_default();
+
+                // Repeated mapping with same order replace previous, but keep registration position
+                match service_idxes.iter().position(|service_idx| service_idx == &component_service_pair) {
+                    Some(position) if service_idxes[position].order == component_service_pair.order => service_idxes[position] = component_service_pair.clone(),
+                    position => {
+                        if let Some(position) = position {
+                            service_idxes.remove(position);
+                        }
 
-        let service_search_idx = self.services_search_idx.entry(TypeId::of::<TComponent>()).or_insert(HashSet::new());
-        service_search_idx.insert(component_service_pair.clone());
+                        // Insert after all services with same order, so registration order kept inside one order
+                        let position = service_idxes.partition_point(|service_idx| service_idx.order <= component_service_pair.order);
+                        service_idxes.insert(position, component_service_pair.clone());
+                    },
+                }
+            },
+        }
 
-        let component_search_idx = self.cycled_components_search_idx.entry(TypeId::of::<TComponent>()).or_insert(HashSet::new());
-        component_search_idx.insert(component_service_pair);
+        let component_search_idx = self.cycled_components_search_idx.entry(component_service_pair.cycled_component_id).or_default();
+        component_search_idx.replace(component_service_pair);
     }
 
     #[inline(always)]
-    pub (crate) fn add_mapping_as_transient<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static>(&mut self) where TComponent: Unsize<TService> {
-        let component_service_pair = Arc::new(CycledComponentServicePair::new::<TComponent, Box<TService>>(Box::new(BoxedTraitService::<TComponent, TService>::new())));
-        
-        let service_search_idx = self.services_search_idx.entry(TypeId::of::<Box<TService>>()).or_insert(HashSet::new());
-        service_search_idx.insert(component_service_pair.clone());
-
-        let component_search_idx = self.cycled_components_search_idx.entry(TypeId::of::<TComponent>()).or_insert(HashSet::new());
-        component_search_idx.insert(component_service_pair);
+    pub (crate) fn add_mapping_as_self<TComponent: Sync + Send + 'static>(&mut self) {
+        let component_service_pair = Arc::new(CycledComponentServicePair::new::<TComponent, TComponent>(Box::new(SelfMappingService::<TComponent>::new()), ServiceMappingOptions::default()));
+        self.add_pair(component_service_pair);
     }
 
+    // Self mapping of singleton or context dependent cycled component, which service can be cloned
     #[inline(always)]
-    pub (crate) fn add_mapping_as_singleton<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static>(&mut self) where TComponent: Unsize<TService> {
-        let component_service_pair = Arc::new(CycledComponentServicePair::new::<Arc<TComponent>, Arc<TService>>(Box::new(ArcTraitService::<TComponent, TService>::new())));
-        
-        let service_search_idx = self.services_search_idx.entry(TypeId::of::<Arc<TService>>()).or_insert(HashSet::new());
-        service_search_idx.insert(component_service_pair.clone());
+    pub (crate) fn add_mapping_as_shared_self<TComponent: Clone + Sync + Send + 'static>(&mut self) {
+        let component_service_pair = Arc::new(CycledComponentServicePair::new::<TComponent, TComponent>(Box::new(SelfMappingService::<TComponent>::new_shared()), ServiceMappingOptions::default()));
+        self.add_pair(component_service_pair);
+    }
 
-        let component_search_idx = self.cycled_components_search_idx.entry(TypeId::of::<Arc<TComponent>>()).or_insert(HashSet::new());
-        component_search_idx.insert(component_service_pair);
+    #[inline(always)]
+    pub (crate) fn add_mapping_as_transient<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static>(&mut self, options: ServiceMappingOptions) -> Arc<CycledComponentServicePair> where TComponent: Unsize<TService> {
+        let component_service_pair = Arc::new(CycledComponentServicePair::new::<TComponent, Box<TService>>(Box::new(BoxedTraitService::<TComponent, TService>::new()), options));
+        self.add_pair(component_service_pair.clone());
+        component_service_pair
     }
 
     #[inline(always)]
-    pub (crate) fn add_mapping_as_context_dependent<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static>(&mut self) where TComponent: Unsize<TService> {
-        let component_service_pair = Arc::new(CycledComponentServicePair::new::<Weak<TComponent>, Weak<TService>>(Box::new(WeakTraitService::<TComponent, TService>::new())));
-        
-        let service_search_idx = self.services_search_idx.entry(TypeId::of::<Weak<TService>>()).or_insert(HashSet::new());
-        service_search_idx.insert(component_service_pair.clone());
+    pub (crate) fn add_mapping_as_singleton<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static>(&mut self, options: ServiceMappingOptions) -> Arc<CycledComponentServicePair> where TComponent: Unsize<TService> {
+        let component_service_pair = Arc::new(CycledComponentServicePair::new::<Arc<TComponent>, Arc<TService>>(Box::new(ArcTraitService::<TComponent, TService>::new()), options));
+        self.add_pair(component_service_pair.clone());
+        component_service_pair
+    }
 
-        let component_search_idx = self.cycled_components_search_idx.entry(TypeId::of::<Weak<TComponent>>()).or_insert(HashSet::new());
-        component_search_idx.insert(component_service_pair);
+    #[inline(always)]
+    pub (crate) fn add_mapping_as_context_dependent<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static>(&mut self, options: ServiceMappingOptions) -> Arc<CycledComponentServicePair> where TComponent: Unsize<TService> {
+        let component_service_pair = Arc::new(CycledComponentServicePair::new::<Weak<TComponent>, Weak<TService>>(Box::new(WeakTraitService::<TComponent, TService>::new()), options));
+        self.add_pair(component_service_pair.clone());
+        component_service_pair
     }
 
+    /// Candidates for single service resolve in mapping order
+    /// 
+    /// If any component mapped as composite, return only composite mappings    
+    /// If any component mapped as default, return only default mappings. Fallback mappings used only if service has not other mappings
     #[inline(always)]
-    pub (crate) fn get_nth_by_service_type<TService: 'static>(&self, n: usize) -> Option<Arc<CycledComponentServicePair>> {
-        let idxes = self.services_search_idx.get(&TypeId::of::<TService>())?;
-        let idx = idxes.iter().nth(n)?;
+    pub (crate) fn get_default_candidates_by_service_type<TService: 'static>(&self) -> Option<Vec<Arc<CycledComponentServicePair>>> {
+        let composite_idxes: Vec<_> = self.services_search_idx.get(&TypeId::of::<TService>())?.iter().filter(|idx| idx.is_composite).cloned().collect();
 
-        Some(idx.clone())
+        if !composite_idxes.is_empty() {
+            return Some(composite_idxes);
+        }
+
+        let idxes = self.get_all_by_service_type::<TService>()?;
+
+        let default_idxes: Vec<_> = idxes.iter().filter(|idx| idx.is_default).cloned().collect();
+
+        if !default_idxes.is_empty() {
+            return Some(default_idxes);
+        }
+
+        if idxes.is_empty() {
+            return None;
+        }
+
+        Some(idxes)
     }
 
+    #[inline(always)]
+    pub (crate) fn get_by_service_type_and_name<TService: 'static>(&self, name: &str) -> Option<Arc<CycledComponentServicePair>> {
+        Some(self.named_services_search_idx.get(&TypeId::of::<TService>())?.get(name)?.clone())
+    }
+
+    /// Services in mapping order
+    /// 
+    /// Fallback mappings returned only if service has not other mappings. Composite mappings never returned, so composite can wrap collection of own service
     #[inline(always)]
     pub (crate) fn get_all_by_service_type<TService: 'static>(&self) -> Option<Vec<Arc<CycledComponentServicePair>>> {
-        let idxes = self.services_search_idx.get(&TypeId::of::<TService>())?;
-        Some(idxes.iter().map(|idx| idx.clone() ).collect())
+        let idxes: Vec<_> = self.services_search_idx.get(&TypeId::of::<TService>())?.iter().filter(|idx| !idx.is_composite).cloned().collect();
+
+        let not_fallback_idxes: Vec<_> = idxes.iter().filter(|idx| !idx.is_fallback).cloned().collect();
+
+        if !not_fallback_idxes.is_empty() {
+            return Some(not_fallback_idxes);
+        }
+
+        Some(idxes)
     }
 
     #[inline(always)]
     pub (crate) fn get_all_by_service_type_with_cycled_component_id<TService: 'static>(&self, component_id: TypeId) -> Option<Arc<CycledComponentServicePair>> {
         let service_idxes = self.services_search_idx.get(&TypeId::of::<TService>())?;
-        let component_idxes = self.cycled_components_search_idx.get(&component_id)?;
-        let idx = service_idxes.intersection(&component_idxes).nth(0)?;
+        let idx = service_idxes.iter().find(|service_idx| service_idx.cycled_component_id == component_id)?;
 
         Some(idx.clone())
     }
@@ -139,16 +207,80 @@ impl CycledComponentServiceCollection {
         let cycled_component_idxes = self.cycled_components_search_idx.remove(cycled_component_type_id)?;
 
         cycled_component_idxes.iter().for_each(|component_idx| {
-            self.services_search_idx.get_mut(&component_idx.service_id)
-                .expect("Service idx not found, but component service pair exist")
-                .retain(|service_idx| service_idx != component_idx)
+            match &component_idx.name {
+                Some(name) => {
+                    let named_service_idxes = self.named_services_search_idx.get_mut(&component_idx.service_id)
+                        .expect("Named service idx not found, but component service pair exist");
+
+                    _ = named_service_idxes.remove(name);
+
+                    if named_service_idxes.is_empty() {
+                        _ = self.named_services_search_idx.remove(&component_idx.service_id);
+                    }
+                },
+                None => {
+                    let service_idxes = self.services_search_idx.get_mut(&component_idx.service_id)
+                        .expect("Service idx not found, but component service pair exist");
+
+                    service_idxes.retain(|service_idx| service_idx != component_idx);
+
+                    if service_idxes.is_empty() {
+                        _ = self.services_search_idx.remove(&component_idx.service_id);
+                    }
+                },
+            }
         });
 
         Some(cycled_component_idxes.into_iter().collect())
     }
 
+    #[inline(always)]
+    pub (crate) fn delete_pair(&mut self, component_service_pair: &Arc<CycledComponentServicePair>) -> bool {
+        let Some(cycled_component_idxes) = self.cycled_components_search_idx.get_mut(&component_service_pair.cycled_component_id) else {
+            return false;
+        };
+
+        if !cycled_component_idxes.remove(component_service_pair) {
+            return false;
+        }
+
+        if cycled_component_idxes.is_empty() {
+            _ = self.cycled_components_search_idx.remove(&component_service_pair.cycled_component_id);
+        }
+
+        match &component_service_pair.name {
+            Some(name) => {
+                let named_service_idxes = self.named_services_search_idx.get_mut(&component_service_pair.service_id)
+                    .expect("Named service idx not found, but component service pair exist");
+
+                _ = named_service_idxes.remove(name);
+
+                if named_service_idxes.is_empty() {
+                    _ = self.named_services_search_idx.remove(&component_service_pair.service_id);
+                }
+            },
+            None => {
+                let service_idxes = self.services_search_idx.get_mut(&component_service_pair.service_id)
+                    .expect("Service idx not found, but component service pair exist");
+
+                service_idxes.retain(|service_idx| service_idx != component_service_pair);
+
+                if service_idxes.is_empty() {
+                    _ = self.services_search_idx.remove(&component_service_pair.service_id);
+                }
+            },
+        }
+
+        true
+    }
+
     #[inline(always)]
     pub (crate) fn is_service_exist(&self, type_id: &TypeId) -> bool {
         self.services_search_idx.contains_key(type_id)
     }
+
+    #[inline(always)]
+    pub (crate) fn is_named_service_exist(&self, type_id: &TypeId, name: &str) -> bool {
+        self.named_services_search_idx.get(type_id).map(|named_service_idxes| named_service_idxes.contains_key(name)).unwrap_or(false)
+    }
 }
\ No newline at end of file
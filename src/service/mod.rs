@@ -2,4 +2,10 @@ mod service_constructor;
 pub (crate) use service_constructor::*;
 
 mod cycled_component_service_collection;
-pub (crate) use cycled_component_service_collection::*;
\ No newline at end of file
+pub (crate) use cycled_component_service_collection::*;
+
+mod service_mapping_options;
+pub (crate) use service_mapping_options::*;
+
+mod service_alias;
+pub (crate) use service_alias::*;
@@ -0,0 +1,186 @@
+use std::{
+    any::Any,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::CoerceUnsized,
+    sync::Arc,
+};
+
+use crate::{
+    core_context::CoreContext,
+    DependencyContextId,
+    LocalContext,
+    ServiceMetadata,
+    types::{
+        BuildDependencyResult,
+        TypeInfo,
+    },
+};
+
+#[cfg(not(feature = "async-mode"))]
+pub (crate) trait IServiceAlias where Self: Debug + Sync + Send + 'static {
+    fn target_type_info(&self) -> TypeInfo;
+    fn resolve(&self, core_context: &Arc<CoreContext>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+    // Return Option<TAlias>
+    fn try_resolve(&self, core_context: &Arc<CoreContext>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+    // Return Vec<TAlias>
+    fn resolve_collection(&self, core_context: &Arc<CoreContext>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+    // Return TAlias, resolved with args
+    fn resolve_with(&self, core_context: &Arc<CoreContext>, args: Box<dyn Any + Sync + Send>, args_type_info: TypeInfo, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+    // Return Vec<TAlias>, filtered by target metadata
+    fn resolve_collection_where(&self, core_context: &Arc<CoreContext>, filter: &(dyn for<'a> Fn(&'a ServiceMetadata) -> bool + Sync + Send), id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+pub (crate) trait IServiceAlias where Self: Debug + Sync + Send + 'static {
+    fn target_type_info(&self) -> TypeInfo;
+    async fn resolve(&self, core_context: &Arc<CoreContext>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+    // Return Option<TAlias>
+    async fn try_resolve(&self, core_context: &Arc<CoreContext>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+    // Return Vec<TAlias>
+    async fn resolve_collection(&self, core_context: &Arc<CoreContext>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+    // Return TAlias, resolved with args
+    async fn resolve_with(&self, core_context: &Arc<CoreContext>, args: Box<dyn Any + Sync + Send>, args_type_info: TypeInfo, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+    // Return Vec<TAlias>, filtered by target metadata
+    async fn resolve_collection_where(&self, core_context: &Arc<CoreContext>, filter: &(dyn for<'a> Fn(&'a ServiceMetadata) -> bool + Sync + Send), id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+    #[cfg(feature = "blocking")]
+    fn blocking_resolve(&self, core_context: &Arc<CoreContext>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+    // Return Option<TAlias>
+    #[cfg(feature = "blocking")]
+    fn blocking_try_resolve(&self, core_context: &Arc<CoreContext>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+    // Return Vec<TAlias>
+    #[cfg(feature = "blocking")]
+    fn blocking_resolve_collection(&self, core_context: &Arc<CoreContext>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+    // Return TAlias, resolved with args
+    #[cfg(feature = "blocking")]
+    fn blocking_resolve_with(&self, core_context: &Arc<CoreContext>, args: Box<dyn Any + Sync + Send>, args_type_info: TypeInfo, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+    // Return Vec<TAlias>, filtered by target metadata
+    #[cfg(feature = "blocking")]
+    fn blocking_resolve_collection_where(&self, core_context: &Arc<CoreContext>, filter: &(dyn for<'a> Fn(&'a ServiceMetadata) -> bool + Sync + Send), id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>>;
+}
+
+// Resolve target service and convert it to alias, like Arc<dyn Store> to Arc<dyn LegacyStore>
+pub (crate) struct ServiceAlias<TAlias: Sync + Send + 'static, TTarget: Sync + Send + 'static + CoerceUnsized<TAlias>> {
+    alias_phantom_data: PhantomData<fn() -> TAlias>,
+    target_phantom_data: PhantomData<fn() -> TTarget>,
+}
+
+impl<TAlias: Sync + Send + 'static, TTarget: Sync + Send + 'static + CoerceUnsized<TAlias>> Debug for ServiceAlias<TAlias, TTarget> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServiceAlias")
+            .field("alias_type_info", &TypeInfo::from_type::<TAlias>())
+            .field("target_type_info", &TypeInfo::from_type::<TTarget>())
+            .finish()
+    }
+}
+
+impl<TAlias: Sync + Send + 'static, TTarget: Sync + Send + 'static + CoerceUnsized<TAlias>> ServiceAlias<TAlias, TTarget> {
+    pub (crate) fn new() -> Self {
+        Self {
+            alias_phantom_data: PhantomData,
+            target_phantom_data: PhantomData,
+        }
+    }
+
+    fn convert(target: TTarget) -> TAlias {
+        target
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl<TAlias: Sync + Send + 'static, TTarget: Sync + Send + 'static + CoerceUnsized<TAlias>> IServiceAlias for ServiceAlias<TAlias, TTarget> {
+    fn target_type_info(&self) -> TypeInfo { TypeInfo::from_type::<TTarget>() }
+
+    fn resolve(&self, core_context: &Arc<CoreContext>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let target: TTarget = core_context.resolve::<TTarget>(id, local_context)?;
+        Ok(Box::new(Self::convert(target)))
+    }
+
+    fn try_resolve(&self, core_context: &Arc<CoreContext>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let target: Option<TTarget> = core_context.try_resolve::<TTarget>(id, local_context)?;
+        Ok(Box::new(target.map(Self::convert)))
+    }
+
+    fn resolve_collection(&self, core_context: &Arc<CoreContext>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let targets: Vec<TTarget> = core_context.resolve_collection::<TTarget>(id, local_context)?;
+        Ok(Box::new(targets.into_iter().map(Self::convert).collect::<Vec<TAlias>>()))
+    }
+
+    fn resolve_collection_where(&self, core_context: &Arc<CoreContext>, filter: &(dyn for<'a> Fn(&'a ServiceMetadata) -> bool + Sync + Send), id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let targets: Vec<TTarget> = core_context.resolve_collection_where::<TTarget, _>(filter, id, local_context)?;
+        Ok(Box::new(targets.into_iter().map(Self::convert).collect::<Vec<TAlias>>()))
+    }
+
+    fn resolve_with(&self, core_context: &Arc<CoreContext>, args: Box<dyn Any + Sync + Send>, args_type_info: TypeInfo, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let target: TTarget = core_context.resolve_with_boxed_args::<TTarget>(args, args_type_info, id, local_context)?;
+        Ok(Box::new(Self::convert(target)))
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl<TAlias: Sync + Send + 'static, TTarget: Sync + Send + 'static + CoerceUnsized<TAlias>> IServiceAlias for ServiceAlias<TAlias, TTarget> {
+    fn target_type_info(&self) -> TypeInfo { TypeInfo::from_type::<TTarget>() }
+
+    async fn resolve(&self, core_context: &Arc<CoreContext>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let target: TTarget = core_context.resolve::<TTarget>(id, local_context).await?;
+        Ok(Box::new(Self::convert(target)))
+    }
+
+    async fn try_resolve(&self, core_context: &Arc<CoreContext>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let target: Option<TTarget> = core_context.try_resolve::<TTarget>(id, local_context).await?;
+        Ok(Box::new(target.map(Self::convert)))
+    }
+
+    async fn resolve_collection(&self, core_context: &Arc<CoreContext>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let targets: Vec<TTarget> = core_context.resolve_collection::<TTarget>(id, local_context).await?;
+        Ok(Box::new(targets.into_iter().map(Self::convert).collect::<Vec<TAlias>>()))
+    }
+
+    async fn resolve_collection_where(&self, core_context: &Arc<CoreContext>, filter: &(dyn for<'a> Fn(&'a ServiceMetadata) -> bool + Sync + Send), id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let targets: Vec<TTarget> = core_context.resolve_collection_where::<TTarget, _>(filter, id, local_context).await?;
+        Ok(Box::new(targets.into_iter().map(Self::convert).collect::<Vec<TAlias>>()))
+    }
+
+    async fn resolve_with(&self, core_context: &Arc<CoreContext>, args: Box<dyn Any + Sync + Send>, args_type_info: TypeInfo, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let target: TTarget = core_context.resolve_with_boxed_args::<TTarget>(args, args_type_info, id, local_context).await?;
+        Ok(Box::new(Self::convert(target)))
+    }
+
+    #[cfg(feature = "blocking")]
+    fn blocking_resolve(&self, core_context: &Arc<CoreContext>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let target: TTarget = core_context.blocking_resolve::<TTarget>(id, local_context)?;
+        Ok(Box::new(Self::convert(target)))
+    }
+
+    #[cfg(feature = "blocking")]
+    fn blocking_try_resolve(&self, core_context: &Arc<CoreContext>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let target: Option<TTarget> = core_context.blocking_try_resolve::<TTarget>(id, local_context)?;
+        Ok(Box::new(target.map(Self::convert)))
+    }
+
+    #[cfg(feature = "blocking")]
+    fn blocking_resolve_collection(&self, core_context: &Arc<CoreContext>, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let targets: Vec<TTarget> = core_context.blocking_resolve_collection::<TTarget>(id, local_context)?;
+        Ok(Box::new(targets.into_iter().map(Self::convert).collect::<Vec<TAlias>>()))
+    }
+
+    #[cfg(feature = "blocking")]
+    fn blocking_resolve_collection_where(&self, core_context: &Arc<CoreContext>, filter: &(dyn for<'a> Fn(&'a ServiceMetadata) -> bool + Sync + Send), id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let targets: Vec<TTarget> = core_context.blocking_resolve_collection_where::<TTarget, _>(filter, id, local_context)?;
+        Ok(Box::new(targets.into_iter().map(Self::convert).collect::<Vec<TAlias>>()))
+    }
+
+    #[cfg(feature = "blocking")]
+    fn blocking_resolve_with(&self, core_context: &Arc<CoreContext>, args: Box<dyn Any + Sync + Send>, args_type_info: TypeInfo, id: DependencyContextId, local_context: Arc<LocalContext>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        let target: TTarget = core_context.blocking_resolve_with_boxed_args::<TTarget>(args, args_type_info, id, local_context)?;
+        Ok(Box::new(Self::convert(target)))
+    }
+}
+
+// Alias result created by alias with same service type
+pub (crate) fn downcast_alias_result<TResult: 'static>(result: Box<dyn Any + Sync + Send>) -> TResult {
+    Box::into_inner(result.downcast::<TResult>()
+        .unwrap_or_else(|_| panic!("Alias registered for service, expected type_info:[{:?}]", TypeInfo::from_type::<TResult>())))
+}
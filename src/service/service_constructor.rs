@@ -3,6 +3,15 @@ use std::{any::{Any, TypeId, type_name}, marker::{PhantomData, Unsize}, sync::{A
 
 pub (crate) trait IServiceConstructor where Self: Debug + Sync + Send + 'static {
     fn build(&self, component: Box<dyn Any + Sync + Send>) -> Box<dyn Any + Sync + Send>;
+    // Clone service of singleton or context dependent component, None for transient service
+    fn clone_service(&self, service: &(dyn Any + Sync + Send)) -> Option<Box<dyn Any + Sync + Send>>;
+}
+
+// Shared service is Arc or Weak, so clone return same instance
+fn clone_shared_service<TService: Clone + Sync + Send + 'static>(service: &(dyn Any + Sync + Send)) -> Box<dyn Any + Sync + Send> {
+    Box::new(service.downcast_ref::<TService>()
+        .unwrap_or_else(|| panic!("Service error, unextected service type [{type_id:?}] [{type_name:?}]", type_id = TypeId::of::<TService>(), type_name = type_name::<TService>()))
+        .clone())
 }
 
 pub (crate) struct BoxedTraitService<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static> where TComponent: Unsize<TService> {
@@ -55,6 +64,10 @@ impl<TComponent: Sync + Send + 'static, TService:  ?Sized + Sync + Send + 'stati
         let service = component as Box<TService>;
         return Box::new(service) as Box<dyn Any + Sync + Send>;
     }
+
+    fn clone_service(&self, _: &(dyn Any + Sync + Send)) -> Option<Box<dyn Any + Sync + Send>> {
+        None
+    }
 }
 
 pub (crate) struct ArcTraitService<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static> where TComponent: Unsize<TService> {
@@ -107,6 +120,10 @@ impl<TComponent: Sync + Send + 'static, TService:  ?Sized + Sync + Send + 'stati
         let service = Box::into_inner(component) as Arc<TService>;
         return Box::new(service) as Box<dyn Any + Sync + Send>;
     }
+
+    fn clone_service(&self, service: &(dyn Any + Sync + Send)) -> Option<Box<dyn Any + Sync + Send>> {
+        Some(clone_shared_service::<Arc<TService>>(service))
+    }
 }
 
 pub (crate) struct WeakTraitService<TComponent: Sync + Send + 'static, TService: ?Sized + Sync + Send + 'static> where TComponent: Unsize<TService> {
@@ -159,10 +176,18 @@ impl<TComponent: Sync + Send + 'static, TService:  ?Sized + Sync + Send + 'stati
         let service = Box::into_inner(component) as Weak<TService>;
         return Box::new(service) as Box<dyn Any + Sync + Send>;
     }
+
+    fn clone_service(&self, service: &(dyn Any + Sync + Send)) -> Option<Box<dyn Any + Sync + Send>> {
+        Some(clone_shared_service::<Weak<TService>>(service))
+    }
 }
 
+type CloneService = fn(&(dyn Any + Sync + Send)) -> Box<dyn Any + Sync + Send>;
+
 pub (crate) struct SelfMappingService<TComponent: 'static> {
     component_phantom_data: PhantomData<TComponent>,
+    // Set only for Arc or Weak cycled component
+    clone_service: Option<CloneService>,
 
     #[cfg(feature = "debug-type-info")]
     debug_type_info: TypeInfo,
@@ -171,7 +196,8 @@ pub (crate) struct SelfMappingService<TComponent: 'static> {
 impl<TComponent: 'static> Debug for SelfMappingService<TComponent> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut debug_struct = f.debug_struct("SelfMappingService");
-        debug_struct.field("component_phantom_data", &self.component_phantom_data);
+        debug_struct.field("component_phantom_data", &self.component_phantom_data)
+            .field("is_shared", &self.clone_service.is_some());
 
         #[cfg(feature = "debug-type-info")]
         debug_struct.field("debug_type_info", &self.debug_type_info);
@@ -184,6 +210,18 @@ impl<TComponent: 'static> SelfMappingService<TComponent> {
     pub (crate) fn new() -> Self {
         Self {
             component_phantom_data: Default::default(),
+            clone_service: None,
+            #[cfg(feature = "debug-type-info")]
+            debug_type_info: TypeInfo::from_type::<TComponent>()
+        }
+    }
+}
+
+impl<TComponent: Clone + Sync + Send + 'static> SelfMappingService<TComponent> {
+    pub (crate) fn new_shared() -> Self {
+        Self {
+            component_phantom_data: Default::default(),
+            clone_service: Some(clone_shared_service::<TComponent>),
             #[cfg(feature = "debug-type-info")]
             debug_type_info: TypeInfo::from_type::<TComponent>()
         }
@@ -194,4 +232,8 @@ impl<TComponent: Sync + Send + 'static> IServiceConstructor for SelfMappingServi
     fn build(&self, component: Box<dyn Any + Sync + Send>) -> Box<dyn Any + Sync + Send> {
         return component;
     }
+
+    fn clone_service(&self, service: &(dyn Any + Sync + Send)) -> Option<Box<dyn Any + Sync + Send>> {
+        self.clone_service.map(|clone_service| clone_service(service))
+    }
 }
\ No newline at end of file
@@ -0,0 +1,36 @@
+use crate::ServiceMetadata;
+
+#[derive(Debug, Clone, Default)]
+pub (crate) struct ServiceMappingOptions {
+    pub (crate) name: Option<String>,
+    pub (crate) is_default: bool,
+    // Services with lower order resolved first
+    pub (crate) order: i32,
+    // Fallback resolved only if service has not other mappings
+    pub (crate) is_fallback: bool,
+    // Composite resolved instead of other mappings, but not returned from collection, which it wraps
+    pub (crate) is_composite: bool,
+    pub (crate) metadata: ServiceMetadata,
+}
+
+impl ServiceMappingOptions {
+    pub (crate) fn with_name(mut self, name: Option<String>) -> Self {
+        self.name = name;
+        self
+    }
+
+    pub (crate) fn with_order(mut self, order: i32) -> Self {
+        self.order = order;
+        self
+    }
+
+    pub (crate) fn with_fallback(mut self, is_fallback: bool) -> Self {
+        self.is_fallback = is_fallback;
+        self
+    }
+
+    pub (crate) fn with_composite(mut self, is_composite: bool) -> Self {
+        self.is_composite = is_composite;
+        self
+    }
+}
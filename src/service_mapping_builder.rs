@@ -1,12 +1,14 @@
 use std::marker::Unsize;
-use std::{sync::Arc, marker::PhantomData, any::TypeId};
+use std::{sync::{Arc, Weak}, marker::PhantomData, any::TypeId};
 
 use crate::LifeCycle;
+use crate::service::{CycledComponentServiceCollection, ServiceMappingOptions};
 use crate::types::TypeInfo;
 use crate::{core_context::CoreContext, types::{MapComponentError, MapComponentResult}};
 
 pub struct ServiceMappingBuilder<TComponent: Sync + Send + 'static> {
     core_context: Arc<CoreContext>,
+    options: ServiceMappingOptions,
     pd: PhantomData<TComponent>
 }
 
@@ -15,10 +17,74 @@ impl<TComponent: Sync + Send + 'static> ServiceMappingBuilder<TComponent> {
     pub (crate) fn new(core_context: Arc<CoreContext>) -> Self {
         Self {
             core_context,
+            options: Default::default(),
             pd: PhantomData,
         }
     }
 
+    /// Mark next mappings as default
+    /// 
+    /// If service has several components, default component resolved instead of first registered
+    /// 
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_type::<SomeComponent>(LifeCycle::Transient).await
+    ///     .unwrap()
+    ///     .as_default()
+    ///     .map_as::<dyn SomeService>().await
+    ///     .unwrap();
+    /// ```
+    pub fn as_default(mut self) -> Self {
+        self.options.is_default = true;
+        self
+    }
+
+    /// Add tag to next mappings
+    /// 
+    /// Tags are available in ```resolve_collection_where``` filter and ```get_collection_metadata```
+    /// 
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_type::<SomeComponent>(LifeCycle::Transient).await
+    ///     .unwrap()
+    ///     .with_tag("http")
+    ///     .map_as::<dyn SomeService>().await
+    ///     .unwrap();
+    /// ```
+    pub fn with_tag(mut self, tag: &str) -> Self {
+        self.options.metadata.add_tag(tag.to_string());
+        self
+    }
+
+    /// Add key/value metadata to next mappings
+    /// 
+    /// Metadata is available in ```resolve_collection_where``` filter and ```get_collection_metadata```
+    /// 
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_type::<SomeComponent>(LifeCycle::Transient).await
+    ///     .unwrap()
+    ///     .with_metadata("route", "/users")
+    ///     .map_as::<dyn SomeService>().await
+    ///     .unwrap();
+    /// ```
+    pub fn with_metadata(mut self, key: &str, value: &str) -> Self {
+        self.options.metadata.add_value(key.to_string(), value.to_string());
+        self
+    }
+
     /// Map component as service
     /// 
     ///# Example
@@ -34,6 +100,100 @@ impl<TComponent: Sync + Send + 'static> ServiceMappingBuilder<TComponent> {
     /// ```
     #[cfg(feature = "async-mode")]
     pub async fn map_as<TService: ?Sized + Sync + Send + 'static>(self) -> MapComponentResult<Self> where TComponent: Unsize<TService> {
+        self.add_mapping::<TService>(self.options.clone()).await?;
+        Ok(self)
+    }
+
+    /// Map component as named service
+    /// 
+    /// Named service resolved only by name, it not returned from ```resolve```/```resolve_collection```
+    /// 
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_type::<SomeComponent>(LifeCycle::Transient).await
+    ///     .unwrap()
+    ///     .map_as_named::<dyn SomeService>("primary").await
+    ///     .unwrap();
+    /// 
+    /// let service = root_context.resolve_named::<Box<dyn SomeService>>("primary").await.unwrap();
+    /// ```
+    #[cfg(feature = "async-mode")]
+    pub async fn map_as_named<TService: ?Sized + Sync + Send + 'static>(self, name: &str) -> MapComponentResult<Self> where TComponent: Unsize<TService> {
+        self.add_mapping::<TService>(self.options.clone().with_name(Some(name.to_string()))).await?;
+        Ok(self)
+    }
+
+    /// Map component as service with order
+    /// 
+    /// ```resolve_collection``` return services ordered by ascending order, services with same order returned in registration order. Default order is 0
+    /// 
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_type::<SomeComponent>(LifeCycle::Transient).await
+    ///     .unwrap()
+    ///     .map_as_with_order::<dyn SomeService>(10).await
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "async-mode")]
+    pub async fn map_as_with_order<TService: ?Sized + Sync + Send + 'static>(self, order: i32) -> MapComponentResult<Self> where TComponent: Unsize<TService> {
+        self.add_mapping::<TService>(self.options.clone().with_order(order)).await?;
+        Ok(self)
+    }
+
+    /// Map component as fallback service
+    /// 
+    /// Fallback resolved only if service has not other mappings, it not returned from ```resolve_collection``` with other mappings    
+    /// Useful for default implementation in library, which application can override
+    /// 
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct NoopMetrics {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_type::<NoopMetrics>(LifeCycle::Singleton).await
+    ///     .unwrap()
+    ///     .map_as_fallback::<dyn Metrics>().await
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "async-mode")]
+    pub async fn map_as_fallback<TService: ?Sized + Sync + Send + 'static>(self) -> MapComponentResult<Self> where TComponent: Unsize<TService> {
+        self.add_mapping::<TService>(self.options.clone().with_fallback(true)).await?;
+        Ok(self)
+    }
+
+    /// Map component as composite service
+    /// 
+    /// Composite resolved instead of other service mappings, but not returned from ```resolve_collection```    
+    /// So composite can resolve collection of own service in ctor, without cyclic reference
+    /// 
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct FanOutSink { sinks: Vec<Arc<dyn EventSink>> }
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_type::<FanOutSink>(LifeCycle::Singleton).await
+    ///     .unwrap()
+    ///     .map_as_composite::<dyn EventSink>().await
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "async-mode")]
+    pub async fn map_as_composite<TService: ?Sized + Sync + Send + 'static>(self) -> MapComponentResult<Self> where TComponent: Unsize<TService> {
+        self.add_mapping::<TService>(self.options.clone().with_composite(true)).await?;
+        Ok(self)
+    }
+
+    #[cfg(feature = "async-mode")]
+    async fn add_mapping<TService: ?Sized + Sync + Send + 'static>(&self, options: ServiceMappingOptions) -> MapComponentResult<()> where TComponent: Unsize<TService> {
         let component_id = TypeId::of::<TComponent>();
 
         let components_read_guard = self.core_context.components.read().await;
@@ -48,15 +208,23 @@ impl<TComponent: Sync + Send + 'static> ServiceMappingBuilder<TComponent> {
 
         let component = component.unwrap();
 
+        let mut cycled_component_service_collection_write_guard = self.core_context.cycled_component_service_collection.write().await;
+
+        match component.life_cycle_type {
+            LifeCycle::Transient => check_name::<TComponent, Box<TService>>(&cycled_component_service_collection_write_guard, &options.name)?,
+            LifeCycle::Singleton => check_name::<TComponent, Arc<TService>>(&cycled_component_service_collection_write_guard, &options.name)?,
+            LifeCycle::ContextDependent => check_name::<TComponent, Weak<TService>>(&cycled_component_service_collection_write_guard, &options.name)?,
+        };
+
         match component.life_cycle_type {
-            LifeCycle::Transient => self.core_context.cycled_component_service_collection.write().await.add_mapping_as_transient::<TComponent, TService>(),
-            LifeCycle::Singleton => self.core_context.cycled_component_service_collection.write().await.add_mapping_as_singleton::<TComponent, TService>(),
-            LifeCycle::ContextDependent =>  self.core_context.cycled_component_service_collection.write().await.add_mapping_as_context_dependent::<TComponent, TService>()
+            LifeCycle::Transient => cycled_component_service_collection_write_guard.add_mapping_as_transient::<TComponent, TService>(options.clone()),
+            LifeCycle::Singleton => cycled_component_service_collection_write_guard.add_mapping_as_singleton::<TComponent, TService>(options.clone()),
+            LifeCycle::ContextDependent => cycled_component_service_collection_write_guard.add_mapping_as_context_dependent::<TComponent, TService>(options.clone()),
         };
 
         drop(components_read_guard);
 
-        Ok(self)
+        Ok(())
     }
 
     /// Map component as service (blocking version)
@@ -75,32 +243,143 @@ impl<TComponent: Sync + Send + 'static> ServiceMappingBuilder<TComponent> {
     #[cfg(feature = "blocking")]
     pub fn blocking_map_as<TService: ?Sized + Sync + Send + 'static>(self) -> MapComponentResult<Self> where TComponent: Unsize<TService> {
         std::thread::spawn(move || {
-            let component_id = TypeId::of::<TComponent>();
-
-            let components_read_guard = self.core_context.components.blocking_read();
-            let component = components_read_guard.get(&component_id);
-
-            if component.is_none() {
-                return Err(MapComponentError::ComponentNotFound{
-                    component_type_info: TypeInfo::from_type::<TComponent>(),
-                    service_type_info: TypeInfo::from_type::<TService>(),
-                });
-            }
+            self.blocking_add_mapping::<TService>(self.options.clone())?;
+            Ok(self)
+        }).join().unwrap()
+    }
 
-            let component = component.unwrap();
+    /// Map component as named service (blocking version)
+    /// 
+    /// Named service resolved only by name, it not returned from ```resolve```/```resolve_collection```
+    /// 
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_register_type::<SomeComponent>(LifeCycle::Transient)
+    ///     .unwrap()
+    ///     .blocking_map_as_named::<dyn SomeService>("primary")
+    ///     .unwrap();
+    /// 
+    /// let service = root_context.blocking_resolve_named::<Box<dyn SomeService>>("primary").unwrap();
+    /// ```
+    #[cfg(feature = "blocking")]
+    pub fn blocking_map_as_named<TService: ?Sized + Sync + Send + 'static>(self, name: &str) -> MapComponentResult<Self> where TComponent: Unsize<TService> {
+        let name = name.to_string();
+        std::thread::spawn(move || {
+            self.blocking_add_mapping::<TService>(self.options.clone().with_name(Some(name)))?;
+            Ok(self)
+        }).join().unwrap()
+    }
 
-            match component.life_cycle_type {
-                LifeCycle::Transient => self.core_context.cycled_component_service_collection.blocking_write().add_mapping_as_transient::<TComponent, TService>(),
-                LifeCycle::Singleton => self.core_context.cycled_component_service_collection.blocking_write().add_mapping_as_singleton::<TComponent, TService>(),
-                LifeCycle::ContextDependent =>  self.core_context.cycled_component_service_collection.blocking_write().add_mapping_as_context_dependent::<TComponent, TService>()
-            };
+    /// Map component as service with order (blocking version)
+    /// 
+    /// ```resolve_collection``` return services ordered by ascending order, services with same order returned in registration order. Default order is 0
+    /// 
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_register_type::<SomeComponent>(LifeCycle::Transient)
+    ///     .unwrap()
+    ///     .blocking_map_as_with_order::<dyn SomeService>(10)
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "blocking")]
+    pub fn blocking_map_as_with_order<TService: ?Sized + Sync + Send + 'static>(self, order: i32) -> MapComponentResult<Self> where TComponent: Unsize<TService> {
+        std::thread::spawn(move || {
+            self.blocking_add_mapping::<TService>(self.options.clone().with_order(order))?;
+            Ok(self)
+        }).join().unwrap()
+    }
 
-            drop(components_read_guard);
+    /// Map component as fallback service (blocking version)
+    /// 
+    /// Fallback resolved only if service has not other mappings, it not returned from ```resolve_collection``` with other mappings    
+    /// Useful for default implementation in library, which application can override
+    /// 
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct NoopMetrics {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_register_type::<NoopMetrics>(LifeCycle::Singleton)
+    ///     .unwrap()
+    ///     .blocking_map_as_fallback::<dyn Metrics>()
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "blocking")]
+    pub fn blocking_map_as_fallback<TService: ?Sized + Sync + Send + 'static>(self) -> MapComponentResult<Self> where TComponent: Unsize<TService> {
+        std::thread::spawn(move || {
+            self.blocking_add_mapping::<TService>(self.options.clone().with_fallback(true))?;
+            Ok(self)
+        }).join().unwrap()
+    }
 
+    /// Map component as composite service (blocking version)
+    /// 
+    /// Composite resolved instead of other service mappings, but not returned from ```resolve_collection```    
+    /// So composite can resolve collection of own service in ctor, without cyclic reference
+    /// 
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct FanOutSink { sinks: Vec<Arc<dyn EventSink>> }
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.blocking_register_type::<FanOutSink>(LifeCycle::Singleton)
+    ///     .unwrap()
+    ///     .blocking_map_as_composite::<dyn EventSink>()
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "blocking")]
+    pub fn blocking_map_as_composite<TService: ?Sized + Sync + Send + 'static>(self) -> MapComponentResult<Self> where TComponent: Unsize<TService> {
+        std::thread::spawn(move || {
+            self.blocking_add_mapping::<TService>(self.options.clone().with_composite(true))?;
             Ok(self)
         }).join().unwrap()
     }
 
+    #[cfg(feature = "blocking")]
+    fn blocking_add_mapping<TService: ?Sized + Sync + Send + 'static>(&self, options: ServiceMappingOptions) -> MapComponentResult<()> where TComponent: Unsize<TService> {
+        let component_id = TypeId::of::<TComponent>();
+
+        let components_read_guard = self.core_context.components.blocking_read();
+        let component = components_read_guard.get(&component_id);
+
+        if component.is_none() {
+            return Err(MapComponentError::ComponentNotFound{
+                component_type_info: TypeInfo::from_type::<TComponent>(),
+                service_type_info: TypeInfo::from_type::<TService>(),
+            });
+        }
+
+        let component = component.unwrap();
+
+        let mut cycled_component_service_collection_write_guard = self.core_context.cycled_component_service_collection.blocking_write();
+
+        match component.life_cycle_type {
+            LifeCycle::Transient => check_name::<TComponent, Box<TService>>(&cycled_component_service_collection_write_guard, &options.name)?,
+            LifeCycle::Singleton => check_name::<TComponent, Arc<TService>>(&cycled_component_service_collection_write_guard, &options.name)?,
+            LifeCycle::ContextDependent => check_name::<TComponent, Weak<TService>>(&cycled_component_service_collection_write_guard, &options.name)?,
+        };
+
+        match component.life_cycle_type {
+            LifeCycle::Transient => cycled_component_service_collection_write_guard.add_mapping_as_transient::<TComponent, TService>(options.clone()),
+            LifeCycle::Singleton => cycled_component_service_collection_write_guard.add_mapping_as_singleton::<TComponent, TService>(options.clone()),
+            LifeCycle::ContextDependent => cycled_component_service_collection_write_guard.add_mapping_as_context_dependent::<TComponent, TService>(options.clone()),
+        };
+
+        drop(components_read_guard);
+
+        Ok(())
+    }
+
     /// Map component as service
     /// 
     ///# Example
@@ -116,6 +395,100 @@ impl<TComponent: Sync + Send + 'static> ServiceMappingBuilder<TComponent> {
     /// ```
     #[cfg(not(feature = "async-mode"))]
     pub fn map_as<TService: ?Sized + Sync + Send + 'static>(self) -> MapComponentResult<Self> where TComponent: Unsize<TService> {
+        self.add_mapping::<TService>(self.options.clone())?;
+        Ok(self)
+    }
+
+    /// Map component as named service
+    /// 
+    /// Named service resolved only by name, it not returned from ```resolve```/```resolve_collection```
+    /// 
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_type::<SomeComponent>(LifeCycle::Transient)
+    ///     .unwrap()
+    ///     .map_as_named::<dyn SomeService>("primary")
+    ///     .unwrap();
+    /// 
+    /// let service = root_context.resolve_named::<Box<dyn SomeService>>("primary").unwrap();
+    /// ```
+    #[cfg(not(feature = "async-mode"))]
+    pub fn map_as_named<TService: ?Sized + Sync + Send + 'static>(self, name: &str) -> MapComponentResult<Self> where TComponent: Unsize<TService> {
+        self.add_mapping::<TService>(self.options.clone().with_name(Some(name.to_string())))?;
+        Ok(self)
+    }
+
+    /// Map component as service with order
+    /// 
+    /// ```resolve_collection``` return services ordered by ascending order, services with same order returned in registration order. Default order is 0
+    /// 
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct SomeComponent {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_type::<SomeComponent>(LifeCycle::Transient)
+    ///     .unwrap()
+    ///     .map_as_with_order::<dyn SomeService>(10)
+    ///     .unwrap();
+    /// ```
+    #[cfg(not(feature = "async-mode"))]
+    pub fn map_as_with_order<TService: ?Sized + Sync + Send + 'static>(self, order: i32) -> MapComponentResult<Self> where TComponent: Unsize<TService> {
+        self.add_mapping::<TService>(self.options.clone().with_order(order))?;
+        Ok(self)
+    }
+
+    /// Map component as fallback service
+    /// 
+    /// Fallback resolved only if service has not other mappings, it not returned from ```resolve_collection``` with other mappings    
+    /// Useful for default implementation in library, which application can override
+    /// 
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct NoopMetrics {}
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_type::<NoopMetrics>(LifeCycle::Singleton)
+    ///     .unwrap()
+    ///     .map_as_fallback::<dyn Metrics>()
+    ///     .unwrap();
+    /// ```
+    #[cfg(not(feature = "async-mode"))]
+    pub fn map_as_fallback<TService: ?Sized + Sync + Send + 'static>(self) -> MapComponentResult<Self> where TComponent: Unsize<TService> {
+        self.add_mapping::<TService>(self.options.clone().with_fallback(true))?;
+        Ok(self)
+    }
+
+    /// Map component as composite service
+    /// 
+    /// Composite resolved instead of other service mappings, but not returned from ```resolve_collection```    
+    /// So composite can resolve collection of own service in ctor, without cyclic reference
+    /// 
+    ///# Example
+    ///---
+    /// ```ignore
+    /// struct FanOutSink { sinks: Vec<Arc<dyn EventSink>> }
+    /// 
+    /// let root_context = DependencyContext::new_root();
+    /// root_context.register_type::<FanOutSink>(LifeCycle::Singleton)
+    ///     .unwrap()
+    ///     .map_as_composite::<dyn EventSink>()
+    ///     .unwrap();
+    /// ```
+    #[cfg(not(feature = "async-mode"))]
+    pub fn map_as_composite<TService: ?Sized + Sync + Send + 'static>(self) -> MapComponentResult<Self> where TComponent: Unsize<TService> {
+        self.add_mapping::<TService>(self.options.clone().with_composite(true))?;
+        Ok(self)
+    }
+
+    #[cfg(not(feature = "async-mode"))]
+    fn add_mapping<TService: ?Sized + Sync + Send + 'static>(&self, options: ServiceMappingOptions) -> MapComponentResult<()> where TComponent: Unsize<TService> {
         let component_id = TypeId::of::<TComponent>();
 
         let components_read_guard = self.core_context.components.read().unwrap();
@@ -130,14 +503,35 @@ impl<TComponent: Sync + Send + 'static> ServiceMappingBuilder<TComponent> {
 
         let component = component.unwrap();
 
+        let mut cycled_component_service_collection_write_guard = self.core_context.cycled_component_service_collection.write().unwrap();
+
         match component.life_cycle_type {
-            LifeCycle::Transient => self.core_context.cycled_component_service_collection.write().unwrap().add_mapping_as_transient::<TComponent, TService>(),
-            LifeCycle::Singleton => self.core_context.cycled_component_service_collection.write().unwrap().add_mapping_as_singleton::<TComponent, TService>(),
-            LifeCycle::ContextDependent =>  self.core_context.cycled_component_service_collection.write().unwrap().add_mapping_as_context_dependent::<TComponent, TService>()
+            LifeCycle::Transient => check_name::<TComponent, Box<TService>>(&cycled_component_service_collection_write_guard, &options.name)?,
+            LifeCycle::Singleton => check_name::<TComponent, Arc<TService>>(&cycled_component_service_collection_write_guard, &options.name)?,
+            LifeCycle::ContextDependent => check_name::<TComponent, Weak<TService>>(&cycled_component_service_collection_write_guard, &options.name)?,
+        };
+
+        match component.life_cycle_type {
+            LifeCycle::Transient => cycled_component_service_collection_write_guard.add_mapping_as_transient::<TComponent, TService>(options.clone()),
+            LifeCycle::Singleton => cycled_component_service_collection_write_guard.add_mapping_as_singleton::<TComponent, TService>(options.clone()),
+            LifeCycle::ContextDependent => cycled_component_service_collection_write_guard.add_mapping_as_context_dependent::<TComponent, TService>(options.clone()),
         };
 
         drop(components_read_guard);
 
-        Ok(self)
+        Ok(())
     }
-}
\ No newline at end of file
+}
+
+// Name must be unique for service, otherwise we cant choose component on resolve
+#[inline(always)]
+fn check_name<TComponent: 'static, TService: ?Sized + 'static>(cycled_component_service_collection: &CycledComponentServiceCollection, name: &Option<String>) -> MapComponentResult<()> {
+    match name {
+        Some(name) if cycled_component_service_collection.is_named_service_exist(&TypeId::of::<TService>(), name) => Err(MapComponentError::NameExist {
+            component_type_info: TypeInfo::from_type::<TComponent>(),
+            service_type_info: TypeInfo::from_type::<TService>(),
+            name: name.clone(),
+        }),
+        _ => Ok(()),
+    }
+}
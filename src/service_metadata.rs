@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use crate::types::TypeInfo;
+
+/// Tags and key/value pairs, attached to service mapping
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ServiceMetadata {
+    tags: Vec<String>,
+    values: HashMap<String, String>,
+}
+
+impl ServiceMetadata {
+    /// Check mapping has tag
+    pub fn tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|mapping_tag| mapping_tag == tag)
+    }
+
+    /// All mapping tags in adding order
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Get value by key
+    pub fn value(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    /// All mapping key/value pairs
+    pub fn values(&self) -> &HashMap<String, String> {
+        &self.values
+    }
+
+    pub (crate) fn add_tag(&mut self, tag: String) {
+        if !self.tags.contains(&tag) {
+            self.tags.push(tag);
+        }
+    }
+
+    pub (crate) fn add_value(&mut self, key: String, value: String) {
+        self.values.insert(key, value);
+    }
+}
+
+/// Service mapping info, available without service construction
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceInfo {
+    /// Component, mapped as service
+    pub component_type_info: TypeInfo,
+    /// Mapping metadata
+    pub metadata: ServiceMetadata,
+}
@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use crate::{
+    Constructor,
+    types::BuildDependencyResult
+};
+
+struct ConsoleSink {}
+
+struct FileSink {}
+
+struct FanOutSink {
+    sinks: Vec<Arc<dyn EventSink>>,
+}
+
+trait EventSink: Sync + Send {
+    fn name(&self) -> String;
+}
+
+impl EventSink for ConsoleSink {
+    fn name(&self) -> String { "console".to_string() }
+}
+
+impl EventSink for FileSink {
+    fn name(&self) -> String { "file".to_string() }
+}
+
+impl EventSink for FanOutSink {
+    fn name(&self) -> String { self.sinks.iter().map(|sink| sink.name()).collect::<Vec<_>>().join(",") }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for ConsoleSink {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for ConsoleSink {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for FileSink {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for FileSink {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for FanOutSink {
+    fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { sinks: ctx.resolve_collection::<Arc<dyn EventSink>>()? })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for FanOutSink {
+    async fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { sinks: ctx.resolve_collection::<Arc<dyn EventSink>>().await? })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn composite_service() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{
+            AddDependencyError,
+            RegisterCompositeError,
+            TypeInfo,
+        },
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<ConsoleSink>(LifeCycle::Singleton).unwrap()
+        .map_as::<dyn EventSink>().unwrap();
+    root_context.register_composite::<dyn EventSink, FanOutSink>(LifeCycle::Singleton).unwrap();
+
+    // Registered composite returned as error
+    let result = root_context.register_composite::<dyn EventSink, FanOutSink>(LifeCycle::Singleton);
+    assert_eq!(result.err(), Some(RegisterCompositeError::AddDependencyError { err: AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<FanOutSink>() } }));
+
+    root_context.register_type::<FileSink>(LifeCycle::Singleton).unwrap()
+        .map_as::<dyn EventSink>().unwrap();
+
+    // Composite resolved instead of implementations, and wrap all of them
+    assert_eq!(root_context.resolve::<Arc<dyn EventSink>>().unwrap().name(), "console,file".to_string());
+
+    // Composite not returned from collection
+    let collection = root_context.resolve_collection::<Arc<dyn EventSink>>().unwrap();
+    assert_eq!(collection.iter().map(|sink| sink.name()).collect::<Vec<_>>(), vec!["console".to_string(), "file".to_string()]);
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn composite_service() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{
+            AddDependencyError,
+            RegisterCompositeError,
+            TypeInfo,
+        },
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<ConsoleSink>(LifeCycle::Singleton).await.unwrap()
+        .map_as::<dyn EventSink>().await.unwrap();
+    root_context.register_composite::<dyn EventSink, FanOutSink>(LifeCycle::Singleton).await.unwrap();
+
+    // Registered composite returned as error
+    let result = root_context.register_composite::<dyn EventSink, FanOutSink>(LifeCycle::Singleton).await;
+    assert_eq!(result.err(), Some(RegisterCompositeError::AddDependencyError { err: AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<FanOutSink>() } }));
+
+    root_context.register_type::<FileSink>(LifeCycle::Singleton).await.unwrap()
+        .map_as::<dyn EventSink>().await.unwrap();
+
+    // Composite resolved instead of implementations, and wrap all of them
+    assert_eq!(root_context.resolve::<Arc<dyn EventSink>>().await.unwrap().name(), "console,file".to_string());
+
+    // Composite not returned from collection
+    let collection = root_context.resolve_collection::<Arc<dyn EventSink>>().await.unwrap();
+    assert_eq!(collection.iter().map(|sink| sink.name()).collect::<Vec<_>>(), vec!["console".to_string(), "file".to_string()]);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn composite_service_sync() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{
+            AddDependencyError,
+            RegisterCompositeError,
+            TypeInfo,
+        },
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<ConsoleSink>(LifeCycle::Singleton).unwrap()
+        .blocking_map_as::<dyn EventSink>().unwrap();
+    root_context.blocking_register_composite::<dyn EventSink, FanOutSink>(LifeCycle::Singleton).unwrap();
+
+    // Registered composite returned as error
+    let result = root_context.blocking_register_composite::<dyn EventSink, FanOutSink>(LifeCycle::Singleton);
+    assert_eq!(result.err(), Some(RegisterCompositeError::AddDependencyError { err: AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<FanOutSink>() } }));
+
+    root_context.blocking_register_type::<FileSink>(LifeCycle::Singleton).unwrap()
+        .blocking_map_as::<dyn EventSink>().unwrap();
+
+    // Composite resolved instead of implementations, and wrap all of them
+    assert_eq!(root_context.blocking_resolve::<Arc<dyn EventSink>>().unwrap().name(), "console,file".to_string());
+
+    // Composite not returned from collection
+    let collection = root_context.blocking_resolve_collection::<Arc<dyn EventSink>>().unwrap();
+    assert_eq!(collection.iter().map(|sink| sink.name()).collect::<Vec<_>>(), vec!["console".to_string(), "file".to_string()]);
+}
@@ -0,0 +1,196 @@
+use std::any::TypeId;
+
+use crate::{
+    Constructor,
+    types::BuildDependencyResult
+};
+
+struct ConsoleLogger {}
+
+struct AuditLogger {}
+
+trait Logger: Sync + Send {
+    fn name(&self) -> String;
+}
+
+impl Logger for ConsoleLogger {
+    fn name(&self) -> String { "console".to_string() }
+}
+
+impl Logger for AuditLogger {
+    fn name(&self) -> String { "audit".to_string() }
+}
+
+struct BillingService {
+    logger: Box<dyn Logger>,
+    loggers: Vec<Box<dyn Logger>>,
+    console_logger_resolved: bool,
+}
+
+struct ShippingService {
+    logger: Box<dyn Logger>,
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for ConsoleLogger {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for ConsoleLogger {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for AuditLogger {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for AuditLogger {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for BillingService {
+    fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {
+            logger: ctx.resolve()?,
+            loggers: ctx.resolve_collection()?,
+            console_logger_resolved: ctx.resolve_by_type_id::<Box<dyn Logger>>(TypeId::of::<ConsoleLogger>()).is_ok(),
+        })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for BillingService {
+    async fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {
+            logger: ctx.resolve().await?,
+            loggers: ctx.resolve_collection().await?,
+            console_logger_resolved: ctx.resolve_by_type_id::<Box<dyn Logger>>(TypeId::of::<ConsoleLogger>()).await.is_ok(),
+        })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for ShippingService {
+    fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { logger: ctx.resolve()? })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for ShippingService {
+    async fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { logger: ctx.resolve().await? })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn contextual_binding() {
+    use crate::{
+        DependencyContext,
+        LifeCycle
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<ConsoleLogger>(LifeCycle::Transient).unwrap()
+        .map_as::<dyn Logger>().unwrap();
+    root_context.register_type::<AuditLogger>(LifeCycle::Transient).unwrap()
+        .map_as::<dyn Logger>().unwrap();
+    root_context.register_type::<BillingService>(LifeCycle::Transient).unwrap();
+    root_context.register_type::<ShippingService>(LifeCycle::Transient).unwrap();
+
+    root_context.when_injected_into::<BillingService>()
+        .map::<dyn Logger, AuditLogger>();
+
+    let billing_service = root_context.resolve::<BillingService>().unwrap();
+    assert_eq!(billing_service.logger.name(), "audit".to_string());
+    assert_eq!(billing_service.loggers.iter().map(|logger| logger.name()).collect::<Vec<_>>(), vec!["audit".to_string()]);
+    assert!(!billing_service.console_logger_resolved);
+
+    // Other components and root context resolve service as usual
+    let shipping_service = root_context.resolve::<ShippingService>().unwrap();
+    assert_eq!(shipping_service.logger.name(), "console".to_string());
+    assert_eq!(root_context.resolve::<Box<dyn Logger>>().unwrap().name(), "console".to_string());
+
+    assert!(format!("{root_context:?}").contains("contextual_bindings"));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn contextual_binding() {
+    use crate::{
+        DependencyContext,
+        LifeCycle
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<ConsoleLogger>(LifeCycle::Transient).await.unwrap()
+        .map_as::<dyn Logger>().await.unwrap();
+    root_context.register_type::<AuditLogger>(LifeCycle::Transient).await.unwrap()
+        .map_as::<dyn Logger>().await.unwrap();
+    root_context.register_type::<BillingService>(LifeCycle::Transient).await.unwrap();
+    root_context.register_type::<ShippingService>(LifeCycle::Transient).await.unwrap();
+
+    root_context.when_injected_into::<BillingService>()
+        .map::<dyn Logger, AuditLogger>().await;
+
+    let billing_service = root_context.resolve::<BillingService>().await.unwrap();
+    assert_eq!(billing_service.logger.name(), "audit".to_string());
+    assert_eq!(billing_service.loggers.iter().map(|logger| logger.name()).collect::<Vec<_>>(), vec!["audit".to_string()]);
+    assert!(!billing_service.console_logger_resolved);
+
+    // Other components and root context resolve service as usual
+    let shipping_service = root_context.resolve::<ShippingService>().await.unwrap();
+    assert_eq!(shipping_service.logger.name(), "console".to_string());
+    assert_eq!(root_context.resolve::<Box<dyn Logger>>().await.unwrap().name(), "console".to_string());
+
+    assert!(format!("{root_context:?}").contains("contextual_bindings"));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn contextual_binding_sync() {
+    use crate::{
+        DependencyContext,
+        LifeCycle
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<ConsoleLogger>(LifeCycle::Transient).unwrap()
+        .blocking_map_as::<dyn Logger>().unwrap();
+    root_context.blocking_register_type::<AuditLogger>(LifeCycle::Transient).unwrap()
+        .blocking_map_as::<dyn Logger>().unwrap();
+    root_context.blocking_register_type::<BillingService>(LifeCycle::Transient).unwrap();
+    root_context.blocking_register_type::<ShippingService>(LifeCycle::Transient).unwrap();
+
+    root_context.when_injected_into::<BillingService>()
+        .blocking_map::<dyn Logger, AuditLogger>();
+
+    let billing_service = root_context.blocking_resolve::<BillingService>().unwrap();
+    assert_eq!(billing_service.logger.name(), "audit".to_string());
+    assert_eq!(billing_service.loggers.iter().map(|logger| logger.name()).collect::<Vec<_>>(), vec!["audit".to_string()]);
+    assert!(!billing_service.console_logger_resolved);
+
+    // Other components and root context resolve service as usual
+    let shipping_service = root_context.blocking_resolve::<ShippingService>().unwrap();
+    assert_eq!(shipping_service.logger.name(), "console".to_string());
+    assert_eq!(root_context.blocking_resolve::<Box<dyn Logger>>().unwrap().name(), "console".to_string());
+
+    assert!(format!("{root_context:?}").contains("contextual_bindings"));
+}
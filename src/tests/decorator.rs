@@ -0,0 +1,329 @@
+use std::sync::Arc;
+
+use crate::{
+    Constructor,
+    Decorator,
+    DependencyContext,
+    types::BuildDependencyResult
+};
+
+#[allow(dead_code)]
+struct TransientDependency1 {
+    pub str: String,
+}
+
+struct PrefixDecorator {
+    inner: Box<dyn GetStr>,
+}
+
+struct SuffixDecorator {
+    inner: Box<dyn GetStr>,
+}
+
+struct SharedPrefixDecorator {
+    inner: Arc<dyn GetStr>,
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for TransientDependency1 {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for TransientDependency1 {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Decorator<Box<dyn GetStr>> for PrefixDecorator {
+    fn decorate(_: DependencyContext, inner: Box<dyn GetStr>) ->  BuildDependencyResult<Box<dyn GetStr>> {
+        Ok(Box::new(Self { inner }))
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Decorator<Box<dyn GetStr>> for PrefixDecorator {
+    async fn decorate(_: DependencyContext, inner: Box<dyn GetStr>) ->  BuildDependencyResult<Box<dyn GetStr>> {
+        Ok(Box::new(Self { inner }))
+    }
+}
+
+trait GetStr: Sync + Send {
+    fn get(&self) -> String;
+}
+
+impl GetStr for TransientDependency1 {
+    fn get(&self) -> String {
+        self.str.clone()
+    }
+}
+
+impl GetStr for PrefixDecorator {
+    fn get(&self) -> String {
+        format!("prefix {}", self.inner.get())
+    }
+}
+
+impl GetStr for SharedPrefixDecorator {
+    fn get(&self) -> String {
+        format!("prefix {}", self.inner.get())
+    }
+}
+
+impl GetStr for SuffixDecorator {
+    fn get(&self) -> String {
+        format!("{} suffix", self.inner.get())
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn decorator() {
+    use crate::LifeCycle;
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).unwrap()
+        .map_as::<dyn GetStr>().unwrap();
+
+    root_context.register_decorator::<Box<dyn GetStr>, PrefixDecorator>();
+    root_context.register_decorator_closure(|_, inner: Box<dyn GetStr>| Ok(Box::new(SuffixDecorator { inner }) as Box<dyn GetStr>));
+
+    assert_eq!(root_context.resolve::<Box<dyn GetStr>>().unwrap().get(), "prefix test1 suffix".to_string());
+    assert_eq!(root_context.resolve_collection::<Box<dyn GetStr>>().unwrap()[0].get(), "prefix test1 suffix".to_string());
+
+    // Only decorated service wrapped
+    assert_eq!(root_context.resolve::<TransientDependency1>().unwrap().get(), "test1".to_string());
+
+    // Decorators kept for replaced implementation
+    root_context.register_closure_or_replace(|_| Ok(TransientDependency1 { str: "replaced".to_string() }), LifeCycle::Transient).unwrap();
+
+    assert_eq!(root_context.resolve::<Box<dyn GetStr>>().unwrap().get(), "prefix replaced suffix".to_string());
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn decorator() {
+    use crate::LifeCycle;
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap()
+        .map_as::<dyn GetStr>().await.unwrap();
+
+    root_context.register_decorator::<Box<dyn GetStr>, PrefixDecorator>().await;
+    root_context.register_decorator_closure(|_, inner: Box<dyn GetStr>| Ok(Box::new(SuffixDecorator { inner }) as Box<dyn GetStr>)).await;
+
+    assert_eq!(root_context.resolve::<Box<dyn GetStr>>().await.unwrap().get(), "prefix test1 suffix".to_string());
+    assert_eq!(root_context.resolve_collection::<Box<dyn GetStr>>().await.unwrap()[0].get(), "prefix test1 suffix".to_string());
+
+    // Only decorated service wrapped
+    assert_eq!(root_context.resolve::<TransientDependency1>().await.unwrap().get(), "test1".to_string());
+
+    // Decorators kept for replaced implementation
+    root_context.register_closure_or_replace(|_| Ok(TransientDependency1 { str: "replaced".to_string() }), LifeCycle::Transient).await.unwrap();
+
+    assert_eq!(root_context.resolve::<Box<dyn GetStr>>().await.unwrap().get(), "prefix replaced suffix".to_string());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn decorator_sync() {
+    use crate::LifeCycle;
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+
+    root_context.blocking_register_decorator::<Box<dyn GetStr>, PrefixDecorator>();
+    root_context.blocking_register_decorator_closure(|_, inner: Box<dyn GetStr>| Ok(Box::new(SuffixDecorator { inner }) as Box<dyn GetStr>));
+
+    assert_eq!(root_context.blocking_resolve::<Box<dyn GetStr>>().unwrap().get(), "prefix test1 suffix".to_string());
+    assert_eq!(root_context.blocking_resolve_collection::<Box<dyn GetStr>>().unwrap()[0].get(), "prefix test1 suffix".to_string());
+
+    // Only decorated service wrapped
+    assert_eq!(root_context.blocking_resolve::<TransientDependency1>().unwrap().get(), "test1".to_string());
+
+    // Decorators kept for replaced implementation
+    root_context.blocking_register_closure_or_replace(|_| Ok(TransientDependency1 { str: "replaced".to_string() }), LifeCycle::Transient).unwrap();
+
+    assert_eq!(root_context.blocking_resolve::<Box<dyn GetStr>>().unwrap().get(), "prefix replaced suffix".to_string());
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn decorator_singleton() {
+    use crate::LifeCycle;
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Singleton).unwrap()
+        .map_as::<dyn GetStr>().unwrap();
+
+    root_context.register_decorator_closure(|_, inner: Arc<dyn GetStr>| Ok(Arc::new(SharedPrefixDecorator { inner }) as Arc<dyn GetStr>));
+
+    // Singleton service decorated once
+    let service1 = root_context.resolve::<Arc<dyn GetStr>>().unwrap();
+    let service2 = root_context.resolve::<Arc<dyn GetStr>>().unwrap();
+
+    assert_eq!(service1.get(), "prefix test1".to_string());
+    assert!(Arc::ptr_eq(&service1, &service2));
+    assert_eq!(root_context.resolve_collection::<Arc<dyn GetStr>>().unwrap()[0].get(), "prefix test1".to_string());
+
+    // New decorator applied to singleton service
+    root_context.register_decorator_closure(|_, inner: Arc<dyn GetStr>| Ok(Arc::new(SharedPrefixDecorator { inner }) as Arc<dyn GetStr>));
+
+    let service3 = root_context.resolve::<Arc<dyn GetStr>>().unwrap();
+    assert_eq!(service3.get(), "prefix prefix test1".to_string());
+    assert!(Arc::ptr_eq(&service3, &root_context.resolve::<Arc<dyn GetStr>>().unwrap()));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn decorator_singleton() {
+    use crate::LifeCycle;
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Singleton).await.unwrap()
+        .map_as::<dyn GetStr>().await.unwrap();
+
+    root_context.register_decorator_closure(|_, inner: Arc<dyn GetStr>| Ok(Arc::new(SharedPrefixDecorator { inner }) as Arc<dyn GetStr>)).await;
+
+    // Singleton service decorated once
+    let service1 = root_context.resolve::<Arc<dyn GetStr>>().await.unwrap();
+    let service2 = root_context.resolve::<Arc<dyn GetStr>>().await.unwrap();
+
+    assert_eq!(service1.get(), "prefix test1".to_string());
+    assert!(Arc::ptr_eq(&service1, &service2));
+    assert_eq!(root_context.resolve_collection::<Arc<dyn GetStr>>().await.unwrap()[0].get(), "prefix test1".to_string());
+
+    // New decorator applied to singleton service
+    root_context.register_decorator_closure(|_, inner: Arc<dyn GetStr>| Ok(Arc::new(SharedPrefixDecorator { inner }) as Arc<dyn GetStr>)).await;
+
+    let service3 = root_context.resolve::<Arc<dyn GetStr>>().await.unwrap();
+    assert_eq!(service3.get(), "prefix prefix test1".to_string());
+    assert!(Arc::ptr_eq(&service3, &root_context.resolve::<Arc<dyn GetStr>>().await.unwrap()));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn decorator_singleton_sync() {
+    use crate::LifeCycle;
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Singleton).unwrap()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+
+    root_context.blocking_register_decorator_closure(|_, inner: Arc<dyn GetStr>| Ok(Arc::new(SharedPrefixDecorator { inner }) as Arc<dyn GetStr>));
+
+    // Singleton service decorated once
+    let service1 = root_context.blocking_resolve::<Arc<dyn GetStr>>().unwrap();
+    let service2 = root_context.blocking_resolve::<Arc<dyn GetStr>>().unwrap();
+
+    assert_eq!(service1.get(), "prefix test1".to_string());
+    assert!(Arc::ptr_eq(&service1, &service2));
+    assert_eq!(root_context.blocking_resolve_collection::<Arc<dyn GetStr>>().unwrap()[0].get(), "prefix test1".to_string());
+
+    // New decorator applied to singleton service
+    root_context.blocking_register_decorator_closure(|_, inner: Arc<dyn GetStr>| Ok(Arc::new(SharedPrefixDecorator { inner }) as Arc<dyn GetStr>));
+
+    let service3 = root_context.blocking_resolve::<Arc<dyn GetStr>>().unwrap();
+    assert_eq!(service3.get(), "prefix prefix test1".to_string());
+    assert!(Arc::ptr_eq(&service3, &root_context.blocking_resolve::<Arc<dyn GetStr>>().unwrap()));
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn decorator_singleton_resolve_in_decorator() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use crate::LifeCycle;
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Singleton).unwrap()
+        .map_as::<dyn GetStr>().unwrap();
+
+    // Decorator resolve decorated service once, cache not locked while decorators run
+    let nested_resolved = AtomicBool::new(false);
+    root_context.register_decorator_closure(move |ctx, inner: Arc<dyn GetStr>| {
+        if !nested_resolved.swap(true, Ordering::SeqCst) {
+            _ = ctx.resolve::<Arc<dyn GetStr>>();
+        }
+
+        Ok(Arc::new(SharedPrefixDecorator { inner }) as Arc<dyn GetStr>)
+    });
+
+    let service1 = root_context.resolve::<Arc<dyn GetStr>>().unwrap();
+    let service2 = root_context.resolve::<Arc<dyn GetStr>>().unwrap();
+
+    assert_eq!(service1.get(), "prefix test1".to_string());
+    assert!(Arc::ptr_eq(&service1, &service2));
+}
+
+#[cfg(feature = "async-mode")]
+static NESTED_RESOLVED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+#[cfg(feature = "async-mode")]
+struct ResolveInDecorator {}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Decorator<Arc<dyn GetStr>> for ResolveInDecorator {
+    async fn decorate(ctx: DependencyContext, inner: Arc<dyn GetStr>) ->  BuildDependencyResult<Arc<dyn GetStr>> {
+        if !NESTED_RESOLVED.swap(true, std::sync::atomic::Ordering::SeqCst) {
+            _ = ctx.resolve::<Arc<dyn GetStr>>().await;
+        }
+
+        Ok(Arc::new(SharedPrefixDecorator { inner }))
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn decorator_singleton_resolve_in_decorator() {
+    use crate::LifeCycle;
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Singleton).await.unwrap()
+        .map_as::<dyn GetStr>().await.unwrap();
+
+    // Decorator resolve decorated service once, cache not locked while decorators run
+    root_context.register_decorator::<Arc<dyn GetStr>, ResolveInDecorator>().await;
+
+    let service1 = root_context.resolve::<Arc<dyn GetStr>>().await.unwrap();
+    let service2 = root_context.resolve::<Arc<dyn GetStr>>().await.unwrap();
+
+    assert_eq!(service1.get(), "prefix test1".to_string());
+    assert!(Arc::ptr_eq(&service1, &service2));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn decorator_singleton_resolve_in_decorator_sync() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use crate::LifeCycle;
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Singleton).unwrap()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+
+    // Decorator resolve decorated service once, cache not locked while decorators run
+    let nested_resolved = AtomicBool::new(false);
+    root_context.blocking_register_decorator_closure(move |ctx, inner: Arc<dyn GetStr>| {
+        if !nested_resolved.swap(true, Ordering::SeqCst) {
+            _ = ctx.blocking_resolve::<Arc<dyn GetStr>>();
+        }
+
+        Ok(Arc::new(SharedPrefixDecorator { inner }) as Arc<dyn GetStr>)
+    });
+
+    let service1 = root_context.blocking_resolve::<Arc<dyn GetStr>>().unwrap();
+    let service2 = root_context.blocking_resolve::<Arc<dyn GetStr>>().unwrap();
+
+    assert_eq!(service1.get(), "prefix test1".to_string());
+    assert!(Arc::ptr_eq(&service1, &service2));
+}
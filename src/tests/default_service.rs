@@ -0,0 +1,215 @@
+use crate::{
+    Constructor,
+    types::BuildDependencyResult
+};
+
+#[allow(dead_code)]
+struct TransientDependency1 {
+    pub str: String,
+}
+
+#[allow(dead_code)]
+struct TransientDependency2 {
+    pub str: String,
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for TransientDependency1 {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for TransientDependency1 {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[allow(dead_code)]
+struct TransientDependency3 {
+    pub str: String,
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for TransientDependency3 {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test3".to_string() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for TransientDependency3 {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test3".to_string() })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for TransientDependency2 {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test2".to_string() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for TransientDependency2 {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test2".to_string() })
+    }
+}
+
+trait GetStr: Sync + Send {
+    fn get(&self) -> String;
+}
+
+impl GetStr for TransientDependency1 {
+    fn get(&self) -> String {
+        self.str.clone()
+    }
+}
+
+impl GetStr for TransientDependency2 {
+    fn get(&self) -> String {
+        self.str.clone()
+    }
+}
+
+impl GetStr for TransientDependency3 {
+    fn get(&self) -> String {
+        self.str.clone()
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn default_service() {
+    use crate::{
+        ContextSettings,
+        DependencyContext,
+        LifeCycle,
+        types::{BuildDependencyError, TypeInfo},
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).unwrap()
+        .map_as::<dyn GetStr>().unwrap();
+    root_context.register_type::<TransientDependency2>(LifeCycle::Transient).unwrap()
+        .map_as::<dyn GetStr>().unwrap();
+
+    assert_eq!(root_context.resolve::<Box<dyn GetStr>>().unwrap().get(), "test1".to_string());
+
+    root_context.register_type::<TransientDependency3>(LifeCycle::Transient).unwrap()
+        .as_default()
+        .map_as::<dyn GetStr>().unwrap();
+
+    assert_eq!(root_context.resolve::<Box<dyn GetStr>>().unwrap().get(), "test3".to_string());
+
+    let root_context = DependencyContext::new_root_with_settings(ContextSettings { strict_resolve: true, ..Default::default() });
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).unwrap()
+        .map_as::<dyn GetStr>().unwrap();
+    root_context.register_type::<TransientDependency2>(LifeCycle::Transient).unwrap()
+        .map_as::<dyn GetStr>().unwrap();
+
+    let result = root_context.resolve::<Box<dyn GetStr>>();
+    assert_eq!(result.err(), Some(BuildDependencyError::Ambiguous {
+        service: TypeInfo::from_type::<Box<dyn GetStr>>(),
+        candidates: vec![TypeInfo::from_type::<TransientDependency1>(), TypeInfo::from_type::<TransientDependency2>()],
+    }));
+
+    root_context.register_type::<TransientDependency3>(LifeCycle::Transient).unwrap()
+        .as_default()
+        .map_as::<dyn GetStr>().unwrap();
+
+    assert_eq!(root_context.resolve::<Box<dyn GetStr>>().unwrap().get(), "test3".to_string());
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn default_service() {
+    use crate::{
+        ContextSettings,
+        DependencyContext,
+        LifeCycle,
+        types::{BuildDependencyError, TypeInfo},
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap()
+        .map_as::<dyn GetStr>().await.unwrap();
+    root_context.register_type::<TransientDependency2>(LifeCycle::Transient).await.unwrap()
+        .map_as::<dyn GetStr>().await.unwrap();
+
+    assert_eq!(root_context.resolve::<Box<dyn GetStr>>().await.unwrap().get(), "test1".to_string());
+
+    root_context.register_type::<TransientDependency3>(LifeCycle::Transient).await.unwrap()
+        .as_default()
+        .map_as::<dyn GetStr>().await.unwrap();
+
+    assert_eq!(root_context.resolve::<Box<dyn GetStr>>().await.unwrap().get(), "test3".to_string());
+
+    let root_context = DependencyContext::new_root_with_settings(ContextSettings { strict_resolve: true, ..Default::default() });
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap()
+        .map_as::<dyn GetStr>().await.unwrap();
+    root_context.register_type::<TransientDependency2>(LifeCycle::Transient).await.unwrap()
+        .map_as::<dyn GetStr>().await.unwrap();
+
+    let result = root_context.resolve::<Box<dyn GetStr>>().await;
+    assert_eq!(result.err(), Some(BuildDependencyError::Ambiguous {
+        service: TypeInfo::from_type::<Box<dyn GetStr>>(),
+        candidates: vec![TypeInfo::from_type::<TransientDependency1>(), TypeInfo::from_type::<TransientDependency2>()],
+    }));
+
+    root_context.register_type::<TransientDependency3>(LifeCycle::Transient).await.unwrap()
+        .as_default()
+        .map_as::<dyn GetStr>().await.unwrap();
+
+    assert_eq!(root_context.resolve::<Box<dyn GetStr>>().await.unwrap().get(), "test3".to_string());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn default_service_sync() {
+    use crate::{
+        ContextSettings,
+        DependencyContext,
+        LifeCycle,
+        types::{BuildDependencyError, TypeInfo},
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+    root_context.blocking_register_type::<TransientDependency2>(LifeCycle::Transient).unwrap()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+
+    assert_eq!(root_context.blocking_resolve::<Box<dyn GetStr>>().unwrap().get(), "test1".to_string());
+
+    root_context.blocking_register_type::<TransientDependency3>(LifeCycle::Transient).unwrap()
+        .as_default()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+
+    assert_eq!(root_context.blocking_resolve::<Box<dyn GetStr>>().unwrap().get(), "test3".to_string());
+
+    let root_context = DependencyContext::new_root_with_settings(ContextSettings { strict_resolve: true, ..Default::default() });
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+    root_context.blocking_register_type::<TransientDependency2>(LifeCycle::Transient).unwrap()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+
+    let result = root_context.blocking_resolve::<Box<dyn GetStr>>();
+    assert_eq!(result.err(), Some(BuildDependencyError::Ambiguous {
+        service: TypeInfo::from_type::<Box<dyn GetStr>>(),
+        candidates: vec![TypeInfo::from_type::<TransientDependency1>(), TypeInfo::from_type::<TransientDependency2>()],
+    }));
+
+    root_context.blocking_register_type::<TransientDependency3>(LifeCycle::Transient).unwrap()
+        .as_default()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+
+    assert_eq!(root_context.blocking_resolve::<Box<dyn GetStr>>().unwrap().get(), "test3".to_string());
+}
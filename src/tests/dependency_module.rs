@@ -0,0 +1,481 @@
+use crate::{
+    Constructor,
+    DependencyContext,
+    DependencyModule,
+    LifeCycle,
+    types::{
+        BuildDependencyResult,
+        InstallModuleError,
+        InstallModuleResult,
+        TypeInfo,
+    },
+};
+
+#[allow(dead_code)]
+struct TransientDependency1 {
+    pub str: String,
+}
+
+#[allow(dead_code)]
+struct TransientDependency2 {
+    pub str: String,
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for TransientDependency1 {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for TransientDependency1 {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for TransientDependency2 {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test2".to_string() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for TransientDependency2 {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test2".to_string() })
+    }
+}
+
+trait GetStr: Sync + Send {
+    fn get(&self) -> String;
+}
+
+impl GetStr for TransientDependency1 {
+    fn get(&self) -> String {
+        self.str.clone()
+    }
+}
+
+trait OtherGetStr: Sync + Send {
+    fn get(&self) -> String;
+}
+
+impl OtherGetStr for TransientDependency1 {
+    fn get(&self) -> String {
+        self.str.clone()
+    }
+}
+
+struct BaseModule;
+
+struct ExtensionModule;
+
+struct FailedModule;
+
+struct ReplaceModule;
+
+struct ContextDependentModule;
+
+#[cfg(not(feature = "async-mode"))]
+impl DependencyModule for BaseModule {
+    fn install(&self, ctx: &DependencyContext) -> InstallModuleResult<()> {
+        ctx.register_type::<TransientDependency1>(LifeCycle::Transient)
+            .map_err(|err| InstallModuleError::AddDependencyError { err })?
+            .map_as::<dyn GetStr>()
+            .map_err(|err| InstallModuleError::MapComponentError { err })?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl DependencyModule for BaseModule {
+    async fn install(&self, ctx: &DependencyContext) -> InstallModuleResult<()> {
+        ctx.register_type::<TransientDependency1>(LifeCycle::Transient).await
+            .map_err(|err| InstallModuleError::AddDependencyError { err })?
+            .map_as::<dyn GetStr>().await
+            .map_err(|err| InstallModuleError::MapComponentError { err })?;
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl DependencyModule for ExtensionModule {
+    fn install(&self, ctx: &DependencyContext) -> InstallModuleResult<()> {
+        ctx.register_type::<TransientDependency2>(LifeCycle::Transient)
+            .map_err(|err| InstallModuleError::AddDependencyError { err })?;
+        ctx.map_component::<TransientDependency1, dyn OtherGetStr>()
+            .map_err(|err| InstallModuleError::MapComponentError { err })?;
+        Ok(())
+    }
+
+    fn dependencies(&self) -> Vec<TypeInfo> {
+        vec![TypeInfo::from_type::<BaseModule>()]
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl DependencyModule for ExtensionModule {
+    async fn install(&self, ctx: &DependencyContext) -> InstallModuleResult<()> {
+        ctx.register_type::<TransientDependency2>(LifeCycle::Transient).await
+            .map_err(|err| InstallModuleError::AddDependencyError { err })?;
+        ctx.map_component::<TransientDependency1, dyn OtherGetStr>().await
+            .map_err(|err| InstallModuleError::MapComponentError { err })?;
+        Ok(())
+    }
+
+    fn dependencies(&self) -> Vec<TypeInfo> {
+        vec![TypeInfo::from_type::<BaseModule>()]
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl DependencyModule for FailedModule {
+    fn install(&self, ctx: &DependencyContext) -> InstallModuleResult<()> {
+        ctx.register_type::<TransientDependency2>(LifeCycle::Transient)
+            .map_err(|err| InstallModuleError::AddDependencyError { err })?;
+        Err(InstallModuleError::Custom { err: anyhow::anyhow!("failed") })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl DependencyModule for FailedModule {
+    async fn install(&self, ctx: &DependencyContext) -> InstallModuleResult<()> {
+        ctx.register_type::<TransientDependency2>(LifeCycle::Transient).await
+            .map_err(|err| InstallModuleError::AddDependencyError { err })?;
+        Err(InstallModuleError::Custom { err: anyhow::anyhow!("failed") })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl DependencyModule for ReplaceModule {
+    fn install(&self, ctx: &DependencyContext) -> InstallModuleResult<()> {
+        ctx.register_type_or_replace::<TransientDependency2>(LifeCycle::Singleton)
+            .map_err(|err| InstallModuleError::AddDependencyError { err })?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl DependencyModule for ReplaceModule {
+    async fn install(&self, ctx: &DependencyContext) -> InstallModuleResult<()> {
+        ctx.register_type_or_replace::<TransientDependency2>(LifeCycle::Singleton).await
+            .map_err(|err| InstallModuleError::AddDependencyError { err })?;
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl DependencyModule for ContextDependentModule {
+    fn install(&self, ctx: &DependencyContext) -> InstallModuleResult<()> {
+        ctx.register_type::<TransientDependency2>(LifeCycle::ContextDependent)
+            .map_err(|err| InstallModuleError::AddDependencyError { err })?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl DependencyModule for ContextDependentModule {
+    async fn install(&self, ctx: &DependencyContext) -> InstallModuleResult<()> {
+        ctx.register_type::<TransientDependency2>(LifeCycle::ContextDependent).await
+            .map_err(|err| InstallModuleError::AddDependencyError { err })?;
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn dependency_module() {
+    use crate::types::UninstallModuleError;
+
+    let root_context = DependencyContext::new_root();
+
+    let result = root_context.install_module(ExtensionModule);
+    assert_eq!(result.err(), Some(InstallModuleError::DependencyNotInstalled {
+        module_type_info: TypeInfo::from_type::<ExtensionModule>(),
+        dependency_type_info: TypeInfo::from_type::<BaseModule>(),
+    }));
+
+    root_context.install_module(BaseModule).unwrap();
+    root_context.install_module(ExtensionModule).unwrap();
+
+    assert!(root_context.is_module_installed::<BaseModule>());
+    assert_eq!(root_context.resolve::<Box<dyn GetStr>>().unwrap().get(), "test1".to_string());
+    assert_eq!(root_context.resolve::<Box<dyn OtherGetStr>>().unwrap().get(), "test1".to_string());
+    assert_eq!(root_context.resolve::<TransientDependency2>().unwrap().str, "test2".to_string());
+
+    let result = root_context.install_module(BaseModule);
+    assert_eq!(result.err(), Some(InstallModuleError::ModuleExist { module_type_info: TypeInfo::from_type::<BaseModule>() }));
+
+    let result = root_context.uninstall_module::<BaseModule>();
+    assert_eq!(result.err(), Some(UninstallModuleError::ModuleRequired {
+        module_type_info: TypeInfo::from_type::<BaseModule>(),
+        dependent_module_type_info: TypeInfo::from_type::<ExtensionModule>(),
+    }));
+
+    root_context.uninstall_module::<ExtensionModule>().unwrap();
+
+    assert!(!root_context.is_module_installed::<ExtensionModule>());
+    assert!(!root_context.is_component_exist::<TransientDependency2>());
+    assert!(!root_context.is_service_exist::<Box<dyn OtherGetStr>>());
+    assert!(root_context.is_service_exist::<Box<dyn GetStr>>());
+
+    root_context.uninstall_module::<BaseModule>().unwrap();
+
+    assert!(!root_context.is_component_exist::<TransientDependency1>());
+    assert!(!root_context.is_service_exist::<Box<dyn GetStr>>());
+
+    let result = root_context.uninstall_module::<BaseModule>();
+    assert_eq!(result.err(), Some(UninstallModuleError::ModuleNotFound { module_type_info: TypeInfo::from_type::<BaseModule>() }));
+
+    // Failed install rollback registrations
+    assert!(root_context.install_module(FailedModule).is_err());
+    assert!(!root_context.is_module_installed::<FailedModule>());
+    assert!(!root_context.is_component_exist::<TransientDependency2>());
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn dependency_module() {
+    use crate::types::UninstallModuleError;
+
+    let root_context = DependencyContext::new_root();
+
+    let result = root_context.install_module(ExtensionModule).await;
+    assert_eq!(result.err(), Some(InstallModuleError::DependencyNotInstalled {
+        module_type_info: TypeInfo::from_type::<ExtensionModule>(),
+        dependency_type_info: TypeInfo::from_type::<BaseModule>(),
+    }));
+
+    root_context.install_module(BaseModule).await.unwrap();
+    root_context.install_module(ExtensionModule).await.unwrap();
+
+    assert!(root_context.is_module_installed::<BaseModule>().await);
+    assert_eq!(root_context.resolve::<Box<dyn GetStr>>().await.unwrap().get(), "test1".to_string());
+    assert_eq!(root_context.resolve::<Box<dyn OtherGetStr>>().await.unwrap().get(), "test1".to_string());
+    assert_eq!(root_context.resolve::<TransientDependency2>().await.unwrap().str, "test2".to_string());
+
+    let result = root_context.install_module(BaseModule).await;
+    assert_eq!(result.err(), Some(InstallModuleError::ModuleExist { module_type_info: TypeInfo::from_type::<BaseModule>() }));
+
+    let result = root_context.uninstall_module::<BaseModule>().await;
+    assert_eq!(result.err(), Some(UninstallModuleError::ModuleRequired {
+        module_type_info: TypeInfo::from_type::<BaseModule>(),
+        dependent_module_type_info: TypeInfo::from_type::<ExtensionModule>(),
+    }));
+
+    root_context.uninstall_module::<ExtensionModule>().await.unwrap();
+
+    assert!(!root_context.is_module_installed::<ExtensionModule>().await);
+    assert!(!root_context.is_component_exist::<TransientDependency2>().await);
+    assert!(!root_context.is_service_exist::<Box<dyn OtherGetStr>>().await);
+    assert!(root_context.is_service_exist::<Box<dyn GetStr>>().await);
+
+    root_context.uninstall_module::<BaseModule>().await.unwrap();
+
+    assert!(!root_context.is_component_exist::<TransientDependency1>().await);
+    assert!(!root_context.is_service_exist::<Box<dyn GetStr>>().await);
+
+    let result = root_context.uninstall_module::<BaseModule>().await;
+    assert_eq!(result.err(), Some(UninstallModuleError::ModuleNotFound { module_type_info: TypeInfo::from_type::<BaseModule>() }));
+
+    // Failed install rollback registrations
+    assert!(root_context.install_module(FailedModule).await.is_err());
+    assert!(!root_context.is_module_installed::<FailedModule>().await);
+    assert!(!root_context.is_component_exist::<TransientDependency2>().await);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn dependency_module_sync() {
+    use crate::types::UninstallModuleError;
+
+    let root_context = DependencyContext::new_root();
+
+    let result = root_context.blocking_install_module(ExtensionModule);
+    assert_eq!(result.err(), Some(InstallModuleError::DependencyNotInstalled {
+        module_type_info: TypeInfo::from_type::<ExtensionModule>(),
+        dependency_type_info: TypeInfo::from_type::<BaseModule>(),
+    }));
+
+    root_context.blocking_install_module(BaseModule).unwrap();
+    root_context.blocking_install_module(ExtensionModule).unwrap();
+
+    assert!(root_context.blocking_is_module_installed::<BaseModule>());
+    assert_eq!(root_context.blocking_resolve::<Box<dyn GetStr>>().unwrap().get(), "test1".to_string());
+    assert_eq!(root_context.blocking_resolve::<Box<dyn OtherGetStr>>().unwrap().get(), "test1".to_string());
+    assert_eq!(root_context.blocking_resolve::<TransientDependency2>().unwrap().str, "test2".to_string());
+
+    let result = root_context.blocking_install_module(BaseModule);
+    assert_eq!(result.err(), Some(InstallModuleError::ModuleExist { module_type_info: TypeInfo::from_type::<BaseModule>() }));
+
+    let result = root_context.blocking_uninstall_module::<BaseModule>();
+    assert_eq!(result.err(), Some(UninstallModuleError::ModuleRequired {
+        module_type_info: TypeInfo::from_type::<BaseModule>(),
+        dependent_module_type_info: TypeInfo::from_type::<ExtensionModule>(),
+    }));
+
+    root_context.blocking_uninstall_module::<ExtensionModule>().unwrap();
+
+    assert!(!root_context.blocking_is_module_installed::<ExtensionModule>());
+    assert!(!root_context.blocking_is_component_exist::<TransientDependency2>());
+    assert!(!root_context.blocking_is_service_exist::<Box<dyn OtherGetStr>>());
+    assert!(root_context.blocking_is_service_exist::<Box<dyn GetStr>>());
+
+    root_context.blocking_uninstall_module::<BaseModule>().unwrap();
+
+    assert!(!root_context.blocking_is_component_exist::<TransientDependency1>());
+    assert!(!root_context.blocking_is_service_exist::<Box<dyn GetStr>>());
+
+    let result = root_context.blocking_uninstall_module::<BaseModule>();
+    assert_eq!(result.err(), Some(UninstallModuleError::ModuleNotFound { module_type_info: TypeInfo::from_type::<BaseModule>() }));
+
+    // Failed install rollback registrations
+    assert!(root_context.blocking_install_module(FailedModule).is_err());
+    assert!(!root_context.blocking_is_module_installed::<FailedModule>());
+    assert!(!root_context.blocking_is_component_exist::<TransientDependency2>());
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn dependency_module_replaced_component() {
+    let root_context = DependencyContext::new_root();
+
+    // Component replaced by module is not owned by module
+    root_context.register_type::<TransientDependency2>(LifeCycle::Transient).unwrap();
+    root_context.install_module(ReplaceModule).unwrap();
+    root_context.uninstall_module::<ReplaceModule>().unwrap();
+
+    assert!(root_context.is_component_exist::<TransientDependency2>());
+
+    // Module component replaced by context is not owned by module
+    root_context.install_module(BaseModule).unwrap();
+    root_context.register_type_or_replace::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.uninstall_module::<BaseModule>().unwrap();
+
+    assert!(root_context.is_component_exist::<TransientDependency1>());
+    assert_eq!(root_context.resolve::<TransientDependency1>().unwrap().str, "test1".to_string());
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn dependency_module_replaced_component() {
+    let root_context = DependencyContext::new_root();
+
+    // Component replaced by module is not owned by module
+    root_context.register_type::<TransientDependency2>(LifeCycle::Transient).await.unwrap();
+    root_context.install_module(ReplaceModule).await.unwrap();
+    root_context.uninstall_module::<ReplaceModule>().await.unwrap();
+
+    assert!(root_context.is_component_exist::<TransientDependency2>().await);
+
+    // Module component replaced by context is not owned by module
+    root_context.install_module(BaseModule).await.unwrap();
+    root_context.register_type_or_replace::<TransientDependency1>(LifeCycle::Transient).await.unwrap();
+    root_context.uninstall_module::<BaseModule>().await.unwrap();
+
+    assert!(root_context.is_component_exist::<TransientDependency1>().await);
+    assert_eq!(root_context.resolve::<TransientDependency1>().await.unwrap().str, "test1".to_string());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn dependency_module_replaced_component_sync() {
+    let root_context = DependencyContext::new_root();
+
+    // Component replaced by module is not owned by module
+    root_context.blocking_register_type::<TransientDependency2>(LifeCycle::Transient).unwrap();
+    root_context.blocking_install_module(ReplaceModule).unwrap();
+    root_context.blocking_uninstall_module::<ReplaceModule>().unwrap();
+
+    assert!(root_context.blocking_is_component_exist::<TransientDependency2>());
+
+    // Module component replaced by context is not owned by module
+    root_context.blocking_install_module(BaseModule).unwrap();
+    root_context.blocking_register_type_or_replace::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.blocking_uninstall_module::<BaseModule>().unwrap();
+
+    assert!(root_context.blocking_is_component_exist::<TransientDependency1>());
+    assert_eq!(root_context.blocking_resolve::<TransientDependency1>().unwrap().str, "test1".to_string());
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn dependency_module_context_dependent() {
+    let root_context = DependencyContext::new_root();
+
+    // Context dependent component can't be deleted on uninstall, so install rejected and rolled back
+    let result = root_context.install_module(ContextDependentModule);
+    assert_eq!(result.err(), Some(InstallModuleError::NotSupportedLifeCycle {
+        module_type_info: TypeInfo::from_type::<ContextDependentModule>(),
+        component_type_info: TypeInfo::from_type::<TransientDependency2>(),
+        life_cycle: LifeCycle::ContextDependent,
+    }));
+    assert!(!root_context.is_module_installed::<ContextDependentModule>());
+    assert!(!root_context.is_component_exist::<TransientDependency2>());
+
+    // Manually deleted module component not block uninstall
+    root_context.install_module(BaseModule).unwrap();
+    root_context.delete_component::<TransientDependency1>().unwrap();
+    root_context.uninstall_module::<BaseModule>().unwrap();
+
+    assert!(!root_context.is_module_installed::<BaseModule>());
+    assert!(!root_context.is_service_exist::<Box<dyn GetStr>>());
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn dependency_module_context_dependent() {
+    let root_context = DependencyContext::new_root();
+
+    // Context dependent component can't be deleted on uninstall, so install rejected and rolled back
+    let result = root_context.install_module(ContextDependentModule).await;
+    assert_eq!(result.err(), Some(InstallModuleError::NotSupportedLifeCycle {
+        module_type_info: TypeInfo::from_type::<ContextDependentModule>(),
+        component_type_info: TypeInfo::from_type::<TransientDependency2>(),
+        life_cycle: LifeCycle::ContextDependent,
+    }));
+    assert!(!root_context.is_module_installed::<ContextDependentModule>().await);
+    assert!(!root_context.is_component_exist::<TransientDependency2>().await);
+
+    // Manually deleted module component not block uninstall
+    root_context.install_module(BaseModule).await.unwrap();
+    root_context.delete_component::<TransientDependency1>().await.unwrap();
+    root_context.uninstall_module::<BaseModule>().await.unwrap();
+
+    assert!(!root_context.is_module_installed::<BaseModule>().await);
+    assert!(!root_context.is_service_exist::<Box<dyn GetStr>>().await);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn dependency_module_context_dependent_sync() {
+    let root_context = DependencyContext::new_root();
+
+    // Context dependent component can't be deleted on uninstall, so install rejected and rolled back
+    let result = root_context.blocking_install_module(ContextDependentModule);
+    assert_eq!(result.err(), Some(InstallModuleError::NotSupportedLifeCycle {
+        module_type_info: TypeInfo::from_type::<ContextDependentModule>(),
+        component_type_info: TypeInfo::from_type::<TransientDependency2>(),
+        life_cycle: LifeCycle::ContextDependent,
+    }));
+    assert!(!root_context.blocking_is_module_installed::<ContextDependentModule>());
+    assert!(!root_context.blocking_is_component_exist::<TransientDependency2>());
+
+    // Manually deleted module component not block uninstall
+    root_context.blocking_install_module(BaseModule).unwrap();
+    root_context.blocking_delete_component::<TransientDependency1>().unwrap();
+    root_context.blocking_uninstall_module::<BaseModule>().unwrap();
+
+    assert!(!root_context.blocking_is_module_installed::<BaseModule>());
+    assert!(!root_context.blocking_is_service_exist::<Box<dyn GetStr>>());
+}
@@ -0,0 +1,161 @@
+use crate::{
+    Constructor,
+    DependencyContext,
+};
+
+#[derive(Constructor)]
+struct TransientDependency {
+    #[inject(default)]
+    pub str: String,
+}
+
+trait GetStr: Sync + Send {
+    fn get(&self) -> String;
+}
+
+impl GetStr for TransientDependency {
+    fn get(&self) -> String {
+        self.str.clone()
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Constructor)]
+struct DerivedDependency {
+    #[inject]
+    pub transient: TransientDependency,
+    pub not_annotated: Box<dyn GetStr>,
+    #[inject(named = "named")]
+    pub named: Box<dyn GetStr>,
+    pub optional: Option<Box<dyn GetStr>>,
+    pub missing: Option<std::sync::Arc<dyn GetStr>>,
+    #[inject(default)]
+    pub counter: u32,
+    #[inject(ctx)]
+    pub ctx: DependencyContext,
+    #[inject(construct)]
+    pub constructed: ConstructedDependency,
+}
+
+#[derive(Constructor)]
+struct TupleDependency(#[inject] TransientDependency, #[inject(default)] u32);
+
+#[derive(Constructor)]
+struct UnitDependency;
+
+#[derive(Constructor)]
+struct ConstructedDependency;
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn derive_constructor() {
+    use crate::LifeCycle;
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency>(LifeCycle::Transient).unwrap()
+        .map_as::<dyn GetStr>().unwrap()
+        .map_as_named::<dyn GetStr>("named").unwrap();
+    root_context.register_type::<DerivedDependency>(LifeCycle::Transient).unwrap();
+    root_context.register_type::<TupleDependency>(LifeCycle::Transient).unwrap();
+    root_context.register_type::<UnitDependency>(LifeCycle::Transient).unwrap();
+
+    let dependency = root_context.resolve::<DerivedDependency>().unwrap();
+
+    assert_eq!(dependency.transient.str, String::new());
+    assert_eq!(dependency.not_annotated.get(), String::new());
+    assert_eq!(dependency.named.get(), String::new());
+    assert_eq!(dependency.optional.unwrap().get(), String::new());
+    assert!(dependency.missing.is_none());
+    assert_eq!(dependency.counter, 0);
+    assert!(dependency.ctx.is_component_exist::<DerivedDependency>());
+    assert!(dependency.ctx.is_component_exist::<ConstructedDependency>());
+
+    let dependency = root_context.resolve::<TupleDependency>().unwrap();
+
+    assert_eq!(dependency.0.str, String::new());
+    assert_eq!(dependency.1, 0);
+
+    assert!(root_context.resolve::<UnitDependency>().is_ok());
+
+    // Resolve error returned from derived ctor
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TupleDependency>(LifeCycle::Transient).unwrap();
+
+    assert!(root_context.resolve::<TupleDependency>().is_err());
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn derive_constructor() {
+    use crate::LifeCycle;
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency>(LifeCycle::Transient).await.unwrap()
+        .map_as::<dyn GetStr>().await.unwrap()
+        .map_as_named::<dyn GetStr>("named").await.unwrap();
+    root_context.register_type::<DerivedDependency>(LifeCycle::Transient).await.unwrap();
+    root_context.register_type::<TupleDependency>(LifeCycle::Transient).await.unwrap();
+    root_context.register_type::<UnitDependency>(LifeCycle::Transient).await.unwrap();
+
+    let dependency = root_context.resolve::<DerivedDependency>().await.unwrap();
+
+    assert_eq!(dependency.transient.str, String::new());
+    assert_eq!(dependency.not_annotated.get(), String::new());
+    assert_eq!(dependency.named.get(), String::new());
+    assert_eq!(dependency.optional.unwrap().get(), String::new());
+    assert!(dependency.missing.is_none());
+    assert_eq!(dependency.counter, 0);
+    assert!(dependency.ctx.is_component_exist::<DerivedDependency>().await);
+    assert!(dependency.ctx.is_component_exist::<ConstructedDependency>().await);
+
+    let dependency = root_context.resolve::<TupleDependency>().await.unwrap();
+
+    assert_eq!(dependency.0.str, String::new());
+    assert_eq!(dependency.1, 0);
+
+    assert!(root_context.resolve::<UnitDependency>().await.is_ok());
+
+    // Resolve error returned from derived ctor
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TupleDependency>(LifeCycle::Transient).await.unwrap();
+
+    assert!(root_context.resolve::<TupleDependency>().await.is_err());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn derive_constructor_sync() {
+    use crate::LifeCycle;
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency>(LifeCycle::Transient).unwrap()
+        .blocking_map_as::<dyn GetStr>().unwrap()
+        .blocking_map_as_named::<dyn GetStr>("named").unwrap();
+    root_context.blocking_register_type::<DerivedDependency>(LifeCycle::Transient).unwrap();
+    root_context.blocking_register_type::<TupleDependency>(LifeCycle::Transient).unwrap();
+    root_context.blocking_register_type::<UnitDependency>(LifeCycle::Transient).unwrap();
+
+    let dependency = root_context.blocking_resolve::<DerivedDependency>().unwrap();
+
+    assert_eq!(dependency.transient.str, String::new());
+    assert_eq!(dependency.not_annotated.get(), String::new());
+    assert_eq!(dependency.named.get(), String::new());
+    assert_eq!(dependency.optional.unwrap().get(), String::new());
+    assert!(dependency.missing.is_none());
+    assert_eq!(dependency.counter, 0);
+    assert!(dependency.ctx.blocking_is_component_exist::<DerivedDependency>());
+    assert!(dependency.ctx.blocking_is_component_exist::<ConstructedDependency>());
+
+    let dependency = root_context.blocking_resolve::<TupleDependency>().unwrap();
+
+    assert_eq!(dependency.0.str, String::new());
+    assert_eq!(dependency.1, 0);
+
+    assert!(root_context.blocking_resolve::<UnitDependency>().is_ok());
+
+    // Resolve error returned from derived ctor
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TupleDependency>(LifeCycle::Transient).unwrap();
+
+    assert!(root_context.blocking_resolve::<TupleDependency>().is_err());
+}
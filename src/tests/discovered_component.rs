@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use crate::{
+    component,
+    Constructor,
+};
+
+trait Cache: Sync + Send {
+    fn get(&self) -> String;
+}
+
+trait Health: Sync + Send {
+    fn is_alive(&self) -> bool;
+}
+
+#[component(lifecycle = Singleton, services(dyn Cache, dyn Health))]
+#[derive(Constructor)]
+struct DiscoveredSingleton {
+    #[inject(default)]
+    pub str: String,
+}
+
+impl Cache for DiscoveredSingleton {
+    fn get(&self) -> String {
+        self.str.clone()
+    }
+}
+
+impl Health for DiscoveredSingleton {
+    fn is_alive(&self) -> bool {
+        true
+    }
+}
+
+#[component]
+#[derive(Constructor)]
+struct DiscoveredTransient;
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn discovered_component() {
+    use crate::{
+        DependencyContext,
+        types::RegisterDiscoveredError,
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_discovered().unwrap();
+
+    assert_eq!(root_context.resolve::<Arc<dyn Cache>>().unwrap().get(), String::new());
+    assert!(root_context.resolve::<Arc<dyn Health>>().unwrap().is_alive());
+    assert!(root_context.resolve::<DiscoveredTransient>().is_ok());
+
+    let result = root_context.register_discovered();
+    assert!(matches!(result, Err(RegisterDiscoveredError::AddDependencyError { .. })));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn discovered_component() {
+    use crate::{
+        DependencyContext,
+        types::RegisterDiscoveredError,
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_discovered().await.unwrap();
+
+    assert_eq!(root_context.resolve::<Arc<dyn Cache>>().await.unwrap().get(), String::new());
+    assert!(root_context.resolve::<Arc<dyn Health>>().await.unwrap().is_alive());
+    assert!(root_context.resolve::<DiscoveredTransient>().await.is_ok());
+
+    let result = root_context.register_discovered().await;
+    assert!(matches!(result, Err(RegisterDiscoveredError::AddDependencyError { .. })));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn discovered_component_sync() {
+    use crate::{
+        DependencyContext,
+        types::RegisterDiscoveredError,
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_discovered().unwrap();
+
+    assert_eq!(root_context.blocking_resolve::<Arc<dyn Cache>>().unwrap().get(), String::new());
+    assert!(root_context.blocking_resolve::<Arc<dyn Health>>().unwrap().is_alive());
+    assert!(root_context.blocking_resolve::<DiscoveredTransient>().is_ok());
+
+    let result = root_context.blocking_register_discovered();
+    assert!(matches!(result, Err(RegisterDiscoveredError::AddDependencyError { .. })));
+}
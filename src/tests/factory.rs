@@ -0,0 +1,184 @@
+use crate::{
+    Constructor,
+    Factory,
+    types::BuildDependencyResult
+};
+
+#[allow(dead_code)]
+struct TransientDependency1 {
+    pub str: String,
+}
+
+// Factory create service with requesting component context, so cycle is checked
+#[allow(dead_code)]
+struct CycledDependency1 {
+    pub factory: Factory<CycledDependency2>,
+}
+
+#[allow(dead_code)]
+struct CycledDependency2 {
+    pub dependency: Box<CycledDependency1>,
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for TransientDependency1 {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for TransientDependency1 {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for CycledDependency1 {
+    fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { factory: ctx.resolve()? })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for CycledDependency1 {
+    async fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { factory: ctx.resolve().await? })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for CycledDependency2 {
+    fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { dependency: Box::new(ctx.resolve()?) })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for CycledDependency2 {
+    async fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { dependency: Box::new(ctx.resolve().await?) })
+    }
+}
+
+trait GetStr: Sync + Send {
+    fn get(&self) -> String;
+}
+
+impl GetStr for TransientDependency1 {
+    fn get(&self) -> String {
+        self.str.clone()
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn factory_service() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).unwrap()
+        .map_as::<dyn GetStr>().unwrap();
+
+    let factory = root_context.resolve::<Factory<Box<dyn GetStr>>>().unwrap();
+
+    assert_eq!(factory.create().unwrap().get(), "test1".to_string());
+    assert_eq!(factory.clone().create().unwrap().get(), "test1".to_string());
+
+    root_context.register_type::<CycledDependency1>(LifeCycle::Transient).unwrap();
+    root_context.register_type::<CycledDependency2>(LifeCycle::Transient).unwrap();
+
+    let dependency = root_context.resolve::<CycledDependency1>().unwrap();
+
+    #[cfg(feature = "loop-check")]
+    {
+        use crate::types::{BuildDependencyError, TypeInfo};
+
+        assert_eq!(dependency.factory.create().err(), Some(BuildDependencyError::CyclicReference {
+            child_type_info: TypeInfo::from_type::<CycledDependency1>(),
+            parent_type_info: TypeInfo::from_type::<CycledDependency2>(),
+        }));
+    }
+
+    #[cfg(not(feature = "loop-check"))]
+    assert!(dependency.factory.create().is_ok());
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn factory_service() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap()
+        .map_as::<dyn GetStr>().await.unwrap();
+
+    let factory = root_context.resolve::<Factory<Box<dyn GetStr>>>().await.unwrap();
+
+    assert_eq!(factory.create().await.unwrap().get(), "test1".to_string());
+    assert_eq!(factory.clone().create().await.unwrap().get(), "test1".to_string());
+
+    root_context.register_type::<CycledDependency1>(LifeCycle::Transient).await.unwrap();
+    root_context.register_type::<CycledDependency2>(LifeCycle::Transient).await.unwrap();
+
+    let dependency = root_context.resolve::<CycledDependency1>().await.unwrap();
+
+    #[cfg(feature = "loop-check")]
+    {
+        use crate::types::{BuildDependencyError, TypeInfo};
+
+        assert_eq!(dependency.factory.create().await.err(), Some(BuildDependencyError::CyclicReference {
+            child_type_info: TypeInfo::from_type::<CycledDependency1>(),
+            parent_type_info: TypeInfo::from_type::<CycledDependency2>(),
+        }));
+    }
+
+    #[cfg(not(feature = "loop-check"))]
+    assert!(dependency.factory.create().await.is_ok());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn factory_service_sync() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+
+    let factory = root_context.blocking_resolve::<Factory<Box<dyn GetStr>>>().unwrap();
+
+    assert_eq!(factory.blocking_create().unwrap().get(), "test1".to_string());
+    assert_eq!(factory.clone().blocking_create().unwrap().get(), "test1".to_string());
+
+    root_context.blocking_register_type::<CycledDependency1>(LifeCycle::Transient).unwrap();
+    root_context.blocking_register_type::<CycledDependency2>(LifeCycle::Transient).unwrap();
+
+    let dependency = root_context.blocking_resolve::<CycledDependency1>().unwrap();
+
+    #[cfg(feature = "loop-check")]
+    {
+        use crate::types::{BuildDependencyError, TypeInfo};
+
+        assert_eq!(dependency.factory.blocking_create().err(), Some(BuildDependencyError::CyclicReference {
+            child_type_info: TypeInfo::from_type::<CycledDependency1>(),
+            parent_type_info: TypeInfo::from_type::<CycledDependency2>(),
+        }));
+    }
+
+    #[cfg(not(feature = "loop-check"))]
+    assert!(dependency.factory.blocking_create().is_ok());
+}
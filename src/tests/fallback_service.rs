@@ -0,0 +1,140 @@
+use crate::{
+    Constructor,
+    types::BuildDependencyResult
+};
+
+struct NoopMetrics {}
+
+struct AppMetrics {}
+
+trait Metrics: Sync + Send {
+    fn name(&self) -> String;
+}
+
+impl Metrics for NoopMetrics {
+    fn name(&self) -> String { "noop".to_string() }
+}
+
+impl Metrics for AppMetrics {
+    fn name(&self) -> String { "app".to_string() }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for NoopMetrics {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for NoopMetrics {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for AppMetrics {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for AppMetrics {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn fallback_service() {
+    use crate::{
+        DependencyContext,
+        LifeCycle
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<NoopMetrics>(LifeCycle::Transient).unwrap()
+        .map_as_fallback::<dyn Metrics>().unwrap();
+
+    // Fallback is single candidate
+    assert_eq!(root_context.resolve::<Box<dyn Metrics>>().unwrap().name(), "noop".to_string());
+    let collection = root_context.resolve_collection::<Box<dyn Metrics>>().unwrap();
+    assert_eq!(collection.iter().map(|metrics| metrics.name()).collect::<Vec<_>>(), vec!["noop".to_string()]);
+
+    root_context.register_type::<AppMetrics>(LifeCycle::Transient).unwrap()
+        .map_as::<dyn Metrics>().unwrap();
+
+    // Fallback overridden
+    assert_eq!(root_context.resolve::<Box<dyn Metrics>>().unwrap().name(), "app".to_string());
+    let collection = root_context.resolve_collection::<Box<dyn Metrics>>().unwrap();
+    assert_eq!(collection.iter().map(|metrics| metrics.name()).collect::<Vec<_>>(), vec!["app".to_string()]);
+
+    root_context.delete_component::<AppMetrics>().unwrap();
+
+    assert_eq!(root_context.resolve::<Box<dyn Metrics>>().unwrap().name(), "noop".to_string());
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn fallback_service() {
+    use crate::{
+        DependencyContext,
+        LifeCycle
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<NoopMetrics>(LifeCycle::Transient).await.unwrap()
+        .map_as_fallback::<dyn Metrics>().await.unwrap();
+
+    // Fallback is single candidate
+    assert_eq!(root_context.resolve::<Box<dyn Metrics>>().await.unwrap().name(), "noop".to_string());
+    let collection = root_context.resolve_collection::<Box<dyn Metrics>>().await.unwrap();
+    assert_eq!(collection.iter().map(|metrics| metrics.name()).collect::<Vec<_>>(), vec!["noop".to_string()]);
+
+    root_context.register_type::<AppMetrics>(LifeCycle::Transient).await.unwrap()
+        .map_as::<dyn Metrics>().await.unwrap();
+
+    // Fallback overridden
+    assert_eq!(root_context.resolve::<Box<dyn Metrics>>().await.unwrap().name(), "app".to_string());
+    let collection = root_context.resolve_collection::<Box<dyn Metrics>>().await.unwrap();
+    assert_eq!(collection.iter().map(|metrics| metrics.name()).collect::<Vec<_>>(), vec!["app".to_string()]);
+
+    root_context.delete_component::<AppMetrics>().await.unwrap();
+
+    assert_eq!(root_context.resolve::<Box<dyn Metrics>>().await.unwrap().name(), "noop".to_string());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn fallback_service_sync() {
+    use crate::{
+        DependencyContext,
+        LifeCycle
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<NoopMetrics>(LifeCycle::Transient).unwrap()
+        .blocking_map_as_fallback::<dyn Metrics>().unwrap();
+
+    // Fallback is single candidate
+    assert_eq!(root_context.blocking_resolve::<Box<dyn Metrics>>().unwrap().name(), "noop".to_string());
+    let collection = root_context.blocking_resolve_collection::<Box<dyn Metrics>>().unwrap();
+    assert_eq!(collection.iter().map(|metrics| metrics.name()).collect::<Vec<_>>(), vec!["noop".to_string()]);
+
+    root_context.blocking_register_type::<AppMetrics>(LifeCycle::Transient).unwrap()
+        .blocking_map_as::<dyn Metrics>().unwrap();
+
+    // Fallback overridden
+    assert_eq!(root_context.blocking_resolve::<Box<dyn Metrics>>().unwrap().name(), "app".to_string());
+    let collection = root_context.blocking_resolve_collection::<Box<dyn Metrics>>().unwrap();
+    assert_eq!(collection.iter().map(|metrics| metrics.name()).collect::<Vec<_>>(), vec!["app".to_string()]);
+
+    root_context.blocking_delete_component::<AppMetrics>().unwrap();
+
+    assert_eq!(root_context.blocking_resolve::<Box<dyn Metrics>>().unwrap().name(), "noop".to_string());
+}
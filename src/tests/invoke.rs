@@ -0,0 +1,177 @@
+use std::sync::Weak;
+
+use crate::{
+    Constructor,
+    DependencyContext,
+    LifeCycle,
+    types::{
+        BuildDependencyError,
+        BuildDependencyResult,
+        TypeInfo,
+    },
+};
+
+struct TransientDependency1 {
+    pub str: String,
+}
+
+#[allow(dead_code)]
+struct ContextDependentDependency1 {
+    pub str: String,
+}
+
+trait MissingService: Sync + Send {}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for TransientDependency1 {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for TransientDependency1 {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for ContextDependentDependency1 {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "context".to_string() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for ContextDependentDependency1 {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "context".to_string() })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn invoke() {
+    let mut root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.register_type::<ContextDependentDependency1>(LifeCycle::ContextDependent).unwrap();
+
+    // Function can borrow local variables
+    let prefix = "invoked".to_string();
+    let result = root_context.invoke(|dependency: TransientDependency1| format!("{prefix} {}", dependency.str)).unwrap();
+    assert_eq!(result, "invoked test1".to_string());
+
+    // Arguments resolved with current local context
+    let dependency = root_context.resolve::<Weak<ContextDependentDependency1>>().unwrap();
+    let same_context = root_context.invoke(|invoked: Weak<ContextDependentDependency1>| Weak::ptr_eq(&invoked, &dependency)).unwrap();
+    assert!(same_context);
+
+    let _old_context = root_context.get_context();
+    let _new_context = root_context.set_empty_context();
+    let same_context = root_context.invoke(|invoked: Weak<ContextDependentDependency1>| Weak::ptr_eq(&invoked, &dependency)).unwrap();
+    assert!(!same_context);
+
+    assert_eq!(root_context.invoke(|dependency: TransientDependency1, _: Box<dyn MissingService>| dependency.str).err(), Some(BuildDependencyError::ArgumentResolveError {
+        component_type_info: TypeInfo::from_type::<String>(),
+        argument_index: 1,
+        argument_type_info: TypeInfo::from_type::<Box<dyn MissingService>>(),
+        err: Box::new(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Box<dyn MissingService>>() }),
+    }));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn invoke() {
+    let mut root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap();
+    root_context.register_type::<ContextDependentDependency1>(LifeCycle::ContextDependent).await.unwrap();
+
+    // Function can borrow local variables
+    let prefix = "invoked".to_string();
+    let result = root_context.invoke(|dependency: TransientDependency1| format!("{prefix} {}", dependency.str)).await.unwrap();
+    assert_eq!(result, "invoked test1".to_string());
+
+    // Arguments resolved with current local context
+    let dependency = root_context.resolve::<Weak<ContextDependentDependency1>>().await.unwrap();
+    let same_context = root_context.invoke(|invoked: Weak<ContextDependentDependency1>| Weak::ptr_eq(&invoked, &dependency)).await.unwrap();
+    assert!(same_context);
+
+    let _old_context = root_context.get_context();
+    let _new_context = root_context.set_empty_context();
+    let same_context = root_context.invoke(|invoked: Weak<ContextDependentDependency1>| Weak::ptr_eq(&invoked, &dependency)).await.unwrap();
+    assert!(!same_context);
+
+    assert_eq!(root_context.invoke(|dependency: TransientDependency1, _: Box<dyn MissingService>| dependency.str).await.err(), Some(BuildDependencyError::ArgumentResolveError {
+        component_type_info: TypeInfo::from_type::<String>(),
+        argument_index: 1,
+        argument_type_info: TypeInfo::from_type::<Box<dyn MissingService>>(),
+        err: Box::new(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Box<dyn MissingService>>() }),
+    }));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn invoke_sync() {
+    let mut root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.blocking_register_type::<ContextDependentDependency1>(LifeCycle::ContextDependent).unwrap();
+
+    // Function can borrow local variables
+    let prefix = "invoked".to_string();
+    let result = root_context.blocking_invoke(|dependency: TransientDependency1| format!("{prefix} {}", dependency.str)).unwrap();
+    assert_eq!(result, "invoked test1".to_string());
+
+    // Arguments resolved with current local context
+    let dependency = root_context.blocking_resolve::<Weak<ContextDependentDependency1>>().unwrap();
+    let same_context = root_context.blocking_invoke(|invoked: Weak<ContextDependentDependency1>| Weak::ptr_eq(&invoked, &dependency)).unwrap();
+    assert!(same_context);
+
+    let _old_context = root_context.get_context();
+    let _new_context = root_context.set_empty_context();
+    let same_context = root_context.blocking_invoke(|invoked: Weak<ContextDependentDependency1>| Weak::ptr_eq(&invoked, &dependency)).unwrap();
+    assert!(!same_context);
+
+    assert_eq!(root_context.blocking_invoke(|dependency: TransientDependency1, _: Box<dyn MissingService>| dependency.str).err(), Some(BuildDependencyError::ArgumentResolveError {
+        component_type_info: TypeInfo::from_type::<String>(),
+        argument_index: 1,
+        argument_type_info: TypeInfo::from_type::<Box<dyn MissingService>>(),
+        err: Box::new(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Box<dyn MissingService>>() }),
+    }));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn invoke_async() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap();
+
+    let result = root_context.invoke_async(|dependency: TransientDependency1| async move { std::sync::Arc::new(dependency.str) }).await.unwrap();
+    assert_eq!(*result, "test1".to_string());
+
+    assert_eq!(root_context.invoke_async(|_: Box<dyn MissingService>| async move {}).await.err(), Some(BuildDependencyError::ArgumentResolveError {
+        component_type_info: TypeInfo::from_type::<()>(),
+        argument_index: 0,
+        argument_type_info: TypeInfo::from_type::<Box<dyn MissingService>>(),
+        err: Box::new(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Box<dyn MissingService>>() }),
+    }));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn invoke_async_sync() {
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+
+    let result = root_context.blocking_invoke_async(|dependency: TransientDependency1| async move { std::sync::Arc::new(dependency.str) }).unwrap();
+    assert_eq!(*result, "test1".to_string());
+
+    assert_eq!(root_context.blocking_invoke_async(|_: Box<dyn MissingService>| async move {}).err(), Some(BuildDependencyError::ArgumentResolveError {
+        component_type_info: TypeInfo::from_type::<()>(),
+        argument_index: 0,
+        argument_type_info: TypeInfo::from_type::<Box<dyn MissingService>>(),
+        err: Box::new(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Box<dyn MissingService>>() }),
+    }));
+}
@@ -0,0 +1,181 @@
+use crate::{
+    Constructor,
+    Lazy,
+    types::BuildDependencyResult
+};
+
+#[allow(dead_code)]
+struct TransientDependency1 {
+    pub str: String,
+}
+
+// Dependency cycle, broken with lazy
+#[allow(dead_code)]
+struct CycledDependency1 {
+    pub dependency: Lazy<CycledDependency2>,
+}
+
+#[allow(dead_code)]
+struct CycledDependency2 {
+    pub dependency: Box<CycledDependency1>,
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for TransientDependency1 {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for TransientDependency1 {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for CycledDependency1 {
+    fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { dependency: ctx.resolve()? })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for CycledDependency1 {
+    async fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { dependency: ctx.resolve().await? })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for CycledDependency2 {
+    fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { dependency: Box::new(ctx.resolve()?) })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for CycledDependency2 {
+    async fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { dependency: Box::new(ctx.resolve().await?) })
+    }
+}
+
+trait GetStr: Sync + Send {
+    fn get(&self) -> String;
+}
+
+impl GetStr for TransientDependency1 {
+    fn get(&self) -> String {
+        self.str.clone()
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn lazy_service() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{BuildDependencyError, TypeInfo},
+    };
+
+    let root_context = DependencyContext::new_root();
+
+    // Service resolved on first use, so it can be registered after lazy resolve
+    let lazy = root_context.resolve::<Lazy<Box<dyn GetStr>>>().unwrap();
+    assert!(!lazy.is_resolved());
+
+    assert_eq!(lazy.get().err(), Some(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Box<dyn GetStr>>() }));
+
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).unwrap()
+        .map_as::<dyn GetStr>().unwrap();
+
+    let service = lazy.get().unwrap();
+    assert_eq!(service.get(), "test1".to_string());
+    assert!(lazy.is_resolved());
+
+    // Next call return cached service
+    assert!(std::ptr::eq(service, lazy.get().unwrap()));
+
+    root_context.register_type::<CycledDependency1>(LifeCycle::Transient).unwrap();
+    root_context.register_type::<CycledDependency2>(LifeCycle::Transient).unwrap();
+
+    let dependency = root_context.resolve::<CycledDependency1>().unwrap();
+    let cycled_dependency = dependency.dependency.get().unwrap();
+    assert!(!cycled_dependency.dependency.dependency.is_resolved());
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn lazy_service() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{BuildDependencyError, TypeInfo},
+    };
+
+    let root_context = DependencyContext::new_root();
+
+    // Service resolved on first use, so it can be registered after lazy resolve
+    let lazy = root_context.resolve::<Lazy<Box<dyn GetStr>>>().await.unwrap();
+    assert!(!lazy.is_resolved());
+
+    assert_eq!(lazy.get().await.err(), Some(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Box<dyn GetStr>>() }));
+
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap()
+        .map_as::<dyn GetStr>().await.unwrap();
+
+    let service = lazy.get().await.unwrap();
+    assert_eq!(service.get(), "test1".to_string());
+    assert!(lazy.is_resolved());
+
+    // Next call return cached service
+    assert!(std::ptr::eq(service, lazy.get().await.unwrap()));
+
+    root_context.register_type::<CycledDependency1>(LifeCycle::Transient).await.unwrap();
+    root_context.register_type::<CycledDependency2>(LifeCycle::Transient).await.unwrap();
+
+    let dependency = root_context.resolve::<CycledDependency1>().await.unwrap();
+    let cycled_dependency = dependency.dependency.get().await.unwrap();
+    assert!(!cycled_dependency.dependency.dependency.is_resolved());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn lazy_service_sync() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{BuildDependencyError, TypeInfo},
+    };
+
+    let root_context = DependencyContext::new_root();
+
+    // Service resolved on first use, so it can be registered after lazy resolve
+    let lazy = root_context.blocking_resolve::<Lazy<Box<dyn GetStr>>>().unwrap();
+    assert!(!lazy.is_resolved());
+
+    assert_eq!(lazy.blocking_get().err(), Some(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Box<dyn GetStr>>() }));
+
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+
+    let service = lazy.blocking_get().unwrap();
+    assert_eq!(service.get(), "test1".to_string());
+    assert!(lazy.is_resolved());
+
+    // Next call return cached service
+    assert!(std::ptr::eq(service, lazy.blocking_get().unwrap()));
+
+    root_context.blocking_register_type::<CycledDependency1>(LifeCycle::Transient).unwrap();
+    root_context.blocking_register_type::<CycledDependency2>(LifeCycle::Transient).unwrap();
+
+    let dependency = root_context.blocking_resolve::<CycledDependency1>().unwrap();
+    let cycled_dependency = dependency.dependency.blocking_get().unwrap();
+    assert!(!cycled_dependency.dependency.dependency.is_resolved());
+}
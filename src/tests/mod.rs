@@ -26,4 +26,31 @@ mod deep_nested_dependency_context_dependent;
 mod deep_nested_dependency_cycle_reference;
 mod add_dependency_from_dependency;
 mod single_context_dependent_closure;
-mod multiple_service_realization;
\ No newline at end of file
+mod multiple_service_realization;
+mod named_service;
+mod default_service;
+mod ordered_service;
+mod replace_component;
+#[cfg(feature = "derive")]
+mod derive_constructor;
+#[cfg(feature = "discovery")]
+mod discovered_component;
+mod dependency_module;
+mod lazy;
+mod factory;
+mod resolve_with_args;
+mod try_resolve;
+mod decorator;
+mod resolve_interceptor;
+mod service_metadata;
+mod register_fn;
+mod invoke;
+mod register_arc;
+mod register_prototype;
+mod fallback_service;
+mod service_alias;
+mod composite_service;
+mod contextual_binding;
+mod resolution_path;
+mod resolution_source;
+mod resolve_or_construct;
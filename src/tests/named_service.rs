@@ -0,0 +1,177 @@
+use crate::{
+    Constructor,
+    types::BuildDependencyResult
+};
+
+#[allow(dead_code)]
+struct TransientDependency1 {
+    pub str: String,
+}
+
+#[allow(dead_code)]
+struct TransientDependency2 {
+    pub str: String,
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for TransientDependency1 {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for TransientDependency1 {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for TransientDependency2 {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test2".to_string() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for TransientDependency2 {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test2".to_string() })
+    }
+}
+
+trait GetStr: Sync + Send {
+    fn get(&self) -> String;
+}
+
+impl GetStr for TransientDependency1 {
+    fn get(&self) -> String {
+        self.str.clone()
+    }
+}
+
+impl GetStr for TransientDependency2 {
+    fn get(&self) -> String {
+        self.str.clone()
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn named_service() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{BuildDependencyError, MapComponentError, TypeInfo},
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).unwrap()
+        .map_as_named::<dyn GetStr>("first").unwrap();
+
+    let result = root_context.register_type::<TransientDependency2>(LifeCycle::Transient).unwrap()
+        .map_as_named::<dyn GetStr>("second").unwrap()
+        .map_as_named::<dyn GetStr>("first");
+
+    assert_eq!(result.err(), Some(MapComponentError::NameExist {
+        component_type_info: TypeInfo::from_type::<TransientDependency2>(),
+        service_type_info: TypeInfo::from_type::<Box<dyn GetStr>>(),
+        name: "first".to_string(),
+    }));
+
+    assert_eq!(root_context.resolve_named::<Box<dyn GetStr>>("first").unwrap().get(), "test1".to_string());
+    assert_eq!(root_context.resolve_named::<Box<dyn GetStr>>("second").unwrap().get(), "test2".to_string());
+
+    let result = root_context.resolve_named::<Box<dyn GetStr>>("third");
+    assert_eq!(result.err(), Some(BuildDependencyError::NamedNotFound { type_info: TypeInfo::from_type::<Box<dyn GetStr>>(), name: "third".to_string() }));
+
+    assert!(root_context.is_named_service_exist::<Box<dyn GetStr>>("first"));
+    assert!(!root_context.is_named_service_exist::<Box<dyn GetStr>>("third"));
+    assert!(!root_context.is_service_exist::<Box<dyn GetStr>>());
+
+    root_context.delete_component::<TransientDependency1>().unwrap();
+
+    assert!(!root_context.is_named_service_exist::<Box<dyn GetStr>>("first"));
+    assert!(root_context.is_named_service_exist::<Box<dyn GetStr>>("second"));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn named_service() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{BuildDependencyError, MapComponentError, TypeInfo},
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap()
+        .map_as_named::<dyn GetStr>("first").await.unwrap();
+
+    let result = root_context.register_type::<TransientDependency2>(LifeCycle::Transient).await.unwrap()
+        .map_as_named::<dyn GetStr>("second").await.unwrap()
+        .map_as_named::<dyn GetStr>("first").await;
+
+    assert_eq!(result.err(), Some(MapComponentError::NameExist {
+        component_type_info: TypeInfo::from_type::<TransientDependency2>(),
+        service_type_info: TypeInfo::from_type::<Box<dyn GetStr>>(),
+        name: "first".to_string(),
+    }));
+
+    assert_eq!(root_context.resolve_named::<Box<dyn GetStr>>("first").await.unwrap().get(), "test1".to_string());
+    assert_eq!(root_context.resolve_named::<Box<dyn GetStr>>("second").await.unwrap().get(), "test2".to_string());
+
+    let result = root_context.resolve_named::<Box<dyn GetStr>>("third").await;
+    assert_eq!(result.err(), Some(BuildDependencyError::NamedNotFound { type_info: TypeInfo::from_type::<Box<dyn GetStr>>(), name: "third".to_string() }));
+
+    assert!(root_context.is_named_service_exist::<Box<dyn GetStr>>("first").await);
+    assert!(!root_context.is_named_service_exist::<Box<dyn GetStr>>("third").await);
+    assert!(!root_context.is_service_exist::<Box<dyn GetStr>>().await);
+
+    root_context.delete_component::<TransientDependency1>().await.unwrap();
+
+    assert!(!root_context.is_named_service_exist::<Box<dyn GetStr>>("first").await);
+    assert!(root_context.is_named_service_exist::<Box<dyn GetStr>>("second").await);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn named_service_sync() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{BuildDependencyError, MapComponentError, TypeInfo},
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap()
+        .blocking_map_as_named::<dyn GetStr>("first").unwrap();
+
+    let result = root_context.blocking_register_type::<TransientDependency2>(LifeCycle::Transient).unwrap()
+        .blocking_map_as_named::<dyn GetStr>("second").unwrap()
+        .blocking_map_as_named::<dyn GetStr>("first");
+
+    assert_eq!(result.err(), Some(MapComponentError::NameExist {
+        component_type_info: TypeInfo::from_type::<TransientDependency2>(),
+        service_type_info: TypeInfo::from_type::<Box<dyn GetStr>>(),
+        name: "first".to_string(),
+    }));
+
+    assert_eq!(root_context.blocking_resolve_named::<Box<dyn GetStr>>("first").unwrap().get(), "test1".to_string());
+    assert_eq!(root_context.blocking_resolve_named::<Box<dyn GetStr>>("second").unwrap().get(), "test2".to_string());
+
+    let result = root_context.blocking_resolve_named::<Box<dyn GetStr>>("third");
+    assert_eq!(result.err(), Some(BuildDependencyError::NamedNotFound { type_info: TypeInfo::from_type::<Box<dyn GetStr>>(), name: "third".to_string() }));
+
+    assert!(root_context.blocking_is_named_service_exist::<Box<dyn GetStr>>("first"));
+    assert!(!root_context.blocking_is_named_service_exist::<Box<dyn GetStr>>("third"));
+    assert!(!root_context.blocking_is_service_exist::<Box<dyn GetStr>>());
+
+    root_context.blocking_delete_component::<TransientDependency1>().unwrap();
+
+    assert!(!root_context.blocking_is_named_service_exist::<Box<dyn GetStr>>("first"));
+    assert!(root_context.blocking_is_named_service_exist::<Box<dyn GetStr>>("second"));
+}
\ No newline at end of file
@@ -0,0 +1,374 @@
+use std::sync::Arc;
+
+use crate::{
+    DependencyContext,
+    LifeCycle,
+    Constructor,
+    types::{
+        AddDependencyError,
+        BuildDependencyResult,
+        TypeInfo,
+    },
+};
+
+struct SharedComponent {
+    pub str: String,
+}
+
+trait GetStr: Sync + Send {
+    fn get(&self) -> String;
+}
+
+impl GetStr for SharedComponent {
+    fn get(&self) -> String {
+        self.str.clone()
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for SharedComponent {
+    fn ctor(_: crate::DependencyContext) -> BuildDependencyResult<Self> {
+        Ok(Self { str: "constructed".to_string() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for SharedComponent {
+    async fn ctor(_: crate::DependencyContext) -> BuildDependencyResult<Self> {
+        Ok(Self { str: "constructed".to_string() })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn register_arc() {
+    let shared = Arc::new(SharedComponent { str: "shared".to_string() });
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_arc(shared.clone()).unwrap();
+
+    let resolved1 = root_context.resolve::<Arc<SharedComponent>>().unwrap();
+    let resolved2 = root_context.resolve::<Arc<SharedComponent>>().unwrap();
+
+    assert_eq!(resolved1.str, "shared".to_string());
+    assert!(Arc::ptr_eq(&shared, &resolved1));
+    assert!(Arc::ptr_eq(&shared, &resolved2));
+
+    // Delete and register again, registered Arc is not consumed
+    root_context.delete_component::<SharedComponent>().unwrap();
+    assert!(root_context.resolve::<Arc<SharedComponent>>().is_err());
+
+    root_context.register_arc(shared.clone()).unwrap();
+    assert!(Arc::ptr_eq(&shared, &root_context.resolve::<Arc<SharedComponent>>().unwrap()));
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn register_service_instance() {
+    let shared: Arc<dyn GetStr> = Arc::new(SharedComponent { str: "shared".to_string() });
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_service_instance::<dyn GetStr>(shared.clone()).unwrap();
+
+    let resolved = root_context.resolve::<Arc<dyn GetStr>>().unwrap();
+    assert_eq!(resolved.get(), "shared".to_string());
+    assert!(Arc::ptr_eq(&shared, &resolved));
+    assert!(Arc::ptr_eq(&shared, &root_context.resolve::<Arc<dyn GetStr>>().unwrap()));
+
+    root_context.delete_component::<Arc<dyn GetStr>>().unwrap();
+    root_context.register_service_instance::<dyn GetStr>(shared.clone()).unwrap();
+    assert!(Arc::ptr_eq(&shared, &root_context.resolve::<Arc<dyn GetStr>>().unwrap()));
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn register_arc_delete_and_register_again() {
+    let shared = Arc::new(SharedComponent { str: "shared".to_string() });
+    let other: Arc<dyn GetStr> = Arc::new(SharedComponent { str: "other".to_string() });
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_arc(shared.clone()).unwrap()
+        .map_as::<dyn GetStr>().unwrap();
+    assert!(Arc::ptr_eq(&(shared.clone() as Arc<dyn GetStr>), &root_context.resolve::<Arc<dyn GetStr>>().unwrap()));
+
+    // Service registered by other strategy after delete
+    root_context.delete_component::<SharedComponent>().unwrap();
+    root_context.register_service_instance::<dyn GetStr>(other.clone()).unwrap();
+    assert!(Arc::ptr_eq(&other, &root_context.resolve::<Arc<dyn GetStr>>().unwrap()));
+
+    root_context.delete_component::<Arc<dyn GetStr>>().unwrap();
+    root_context.register_arc(shared.clone()).unwrap()
+        .map_as::<dyn GetStr>().unwrap();
+    assert!(Arc::ptr_eq(&(shared.clone() as Arc<dyn GetStr>), &root_context.resolve::<Arc<dyn GetStr>>().unwrap()));
+    assert!(Arc::ptr_eq(&shared, &root_context.resolve::<Arc<SharedComponent>>().unwrap()));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn register_arc() {
+    let shared = Arc::new(SharedComponent { str: "shared".to_string() });
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_arc(shared.clone()).await.unwrap();
+
+    let resolved1 = root_context.resolve::<Arc<SharedComponent>>().await.unwrap();
+    let resolved2 = root_context.resolve::<Arc<SharedComponent>>().await.unwrap();
+
+    assert_eq!(resolved1.str, "shared".to_string());
+    assert!(Arc::ptr_eq(&shared, &resolved1));
+    assert!(Arc::ptr_eq(&shared, &resolved2));
+
+    // Delete and register again, registered Arc is not consumed
+    root_context.delete_component::<SharedComponent>().await.unwrap();
+    assert!(root_context.resolve::<Arc<SharedComponent>>().await.is_err());
+
+    root_context.register_arc(shared.clone()).await.unwrap();
+    assert!(Arc::ptr_eq(&shared, &root_context.resolve::<Arc<SharedComponent>>().await.unwrap()));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn register_service_instance() {
+    let shared: Arc<dyn GetStr> = Arc::new(SharedComponent { str: "shared".to_string() });
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_service_instance::<dyn GetStr>(shared.clone()).await.unwrap();
+
+    let resolved = root_context.resolve::<Arc<dyn GetStr>>().await.unwrap();
+    assert_eq!(resolved.get(), "shared".to_string());
+    assert!(Arc::ptr_eq(&shared, &resolved));
+    assert!(Arc::ptr_eq(&shared, &root_context.resolve::<Arc<dyn GetStr>>().await.unwrap()));
+
+    root_context.delete_component::<Arc<dyn GetStr>>().await.unwrap();
+    root_context.register_service_instance::<dyn GetStr>(shared.clone()).await.unwrap();
+    assert!(Arc::ptr_eq(&shared, &root_context.resolve::<Arc<dyn GetStr>>().await.unwrap()));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn register_arc_delete_and_register_again() {
+    let shared = Arc::new(SharedComponent { str: "shared".to_string() });
+    let other: Arc<dyn GetStr> = Arc::new(SharedComponent { str: "other".to_string() });
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_arc(shared.clone()).await.unwrap()
+        .map_as::<dyn GetStr>().await.unwrap();
+    assert!(Arc::ptr_eq(&(shared.clone() as Arc<dyn GetStr>), &root_context.resolve::<Arc<dyn GetStr>>().await.unwrap()));
+
+    // Service registered by other strategy after delete
+    root_context.delete_component::<SharedComponent>().await.unwrap();
+    root_context.register_service_instance::<dyn GetStr>(other.clone()).await.unwrap();
+    assert!(Arc::ptr_eq(&other, &root_context.resolve::<Arc<dyn GetStr>>().await.unwrap()));
+
+    root_context.delete_component::<Arc<dyn GetStr>>().await.unwrap();
+    root_context.register_arc(shared.clone()).await.unwrap()
+        .map_as::<dyn GetStr>().await.unwrap();
+    assert!(Arc::ptr_eq(&(shared.clone() as Arc<dyn GetStr>), &root_context.resolve::<Arc<dyn GetStr>>().await.unwrap()));
+    assert!(Arc::ptr_eq(&shared, &root_context.resolve::<Arc<SharedComponent>>().await.unwrap()));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn register_arc_sync() {
+    let shared = Arc::new(SharedComponent { str: "shared".to_string() });
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_arc(shared.clone()).unwrap();
+
+    let resolved1 = root_context.blocking_resolve::<Arc<SharedComponent>>().unwrap();
+    let resolved2 = root_context.blocking_resolve::<Arc<SharedComponent>>().unwrap();
+
+    assert_eq!(resolved1.str, "shared".to_string());
+    assert!(Arc::ptr_eq(&shared, &resolved1));
+    assert!(Arc::ptr_eq(&shared, &resolved2));
+
+    // Delete and register again, registered Arc is not consumed
+    root_context.blocking_delete_component::<SharedComponent>().unwrap();
+    assert!(root_context.blocking_resolve::<Arc<SharedComponent>>().is_err());
+
+    root_context.blocking_register_arc(shared.clone()).unwrap();
+    assert!(Arc::ptr_eq(&shared, &root_context.blocking_resolve::<Arc<SharedComponent>>().unwrap()));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn register_service_instance_sync() {
+    let shared: Arc<dyn GetStr> = Arc::new(SharedComponent { str: "shared".to_string() });
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_service_instance::<dyn GetStr>(shared.clone()).unwrap();
+
+    let resolved = root_context.blocking_resolve::<Arc<dyn GetStr>>().unwrap();
+    assert_eq!(resolved.get(), "shared".to_string());
+    assert!(Arc::ptr_eq(&shared, &resolved));
+    assert!(Arc::ptr_eq(&shared, &root_context.blocking_resolve::<Arc<dyn GetStr>>().unwrap()));
+
+    root_context.blocking_delete_component::<Arc<dyn GetStr>>().unwrap();
+    root_context.blocking_register_service_instance::<dyn GetStr>(shared.clone()).unwrap();
+    assert!(Arc::ptr_eq(&shared, &root_context.blocking_resolve::<Arc<dyn GetStr>>().unwrap()));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn register_arc_delete_and_register_again_sync() {
+    let shared = Arc::new(SharedComponent { str: "shared".to_string() });
+    let other: Arc<dyn GetStr> = Arc::new(SharedComponent { str: "other".to_string() });
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_arc(shared.clone()).unwrap()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+    assert!(Arc::ptr_eq(&(shared.clone() as Arc<dyn GetStr>), &root_context.blocking_resolve::<Arc<dyn GetStr>>().unwrap()));
+
+    // Service registered by other strategy after delete
+    root_context.blocking_delete_component::<SharedComponent>().unwrap();
+    root_context.blocking_register_service_instance::<dyn GetStr>(other.clone()).unwrap();
+    assert!(Arc::ptr_eq(&other, &root_context.blocking_resolve::<Arc<dyn GetStr>>().unwrap()));
+
+    root_context.blocking_delete_component::<Arc<dyn GetStr>>().unwrap();
+    root_context.blocking_register_arc(shared.clone()).unwrap()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+    assert!(Arc::ptr_eq(&(shared.clone() as Arc<dyn GetStr>), &root_context.blocking_resolve::<Arc<dyn GetStr>>().unwrap()));
+    assert!(Arc::ptr_eq(&shared, &root_context.blocking_resolve::<Arc<SharedComponent>>().unwrap()));
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn register_arc_mapped() {
+    let shared = Arc::new(SharedComponent { str: "shared".to_string() });
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_arc(shared.clone()).unwrap()
+        .map_as::<dyn GetStr>().unwrap();
+
+    let resolved = root_context.resolve::<Arc<dyn GetStr>>().unwrap();
+    assert_eq!(resolved.get(), "shared".to_string());
+    assert!(Arc::ptr_eq(&(shared.clone() as Arc<dyn GetStr>), &resolved));
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn register_arc_singleton_clash() {
+    let shared = Arc::new(SharedComponent { str: "shared".to_string() });
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<SharedComponent>(LifeCycle::Singleton).unwrap();
+
+    assert_eq!(
+        root_context.register_arc(shared.clone()).err(),
+        Some(AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<SharedComponent>() })
+    );
+
+    // Transient Arc component has same cycled component, as singleton component
+    assert_eq!(
+        root_context.register_service_instance::<SharedComponent>(shared.clone()).err(),
+        Some(AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<Arc<SharedComponent>>() })
+    );
+
+    assert_eq!(root_context.resolve::<Arc<SharedComponent>>().unwrap().str, "constructed".to_string());
+
+    // Reverse order
+    let root_context = DependencyContext::new_root();
+    root_context.register_service_instance::<SharedComponent>(shared.clone()).unwrap();
+
+    assert_eq!(
+        root_context.register_type::<SharedComponent>(LifeCycle::Singleton).err(),
+        Some(AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<SharedComponent>() })
+    );
+
+    assert!(Arc::ptr_eq(&shared, &root_context.resolve::<Arc<SharedComponent>>().unwrap()));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn register_arc_mapped() {
+    let shared = Arc::new(SharedComponent { str: "shared".to_string() });
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_arc(shared.clone()).await.unwrap()
+        .map_as::<dyn GetStr>().await.unwrap();
+
+    let resolved = root_context.resolve::<Arc<dyn GetStr>>().await.unwrap();
+    assert_eq!(resolved.get(), "shared".to_string());
+    assert!(Arc::ptr_eq(&(shared.clone() as Arc<dyn GetStr>), &resolved));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn register_arc_singleton_clash() {
+    let shared = Arc::new(SharedComponent { str: "shared".to_string() });
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<SharedComponent>(LifeCycle::Singleton).await.unwrap();
+
+    assert_eq!(
+        root_context.register_arc(shared.clone()).await.err(),
+        Some(AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<SharedComponent>() })
+    );
+
+    // Transient Arc component has same cycled component, as singleton component
+    assert_eq!(
+        root_context.register_service_instance::<SharedComponent>(shared.clone()).await.err(),
+        Some(AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<Arc<SharedComponent>>() })
+    );
+
+    assert_eq!(root_context.resolve::<Arc<SharedComponent>>().await.unwrap().str, "constructed".to_string());
+
+    // Reverse order
+    let root_context = DependencyContext::new_root();
+    root_context.register_service_instance::<SharedComponent>(shared.clone()).await.unwrap();
+
+    assert_eq!(
+        root_context.register_type::<SharedComponent>(LifeCycle::Singleton).await.err(),
+        Some(AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<SharedComponent>() })
+    );
+
+    assert!(Arc::ptr_eq(&shared, &root_context.resolve::<Arc<SharedComponent>>().await.unwrap()));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn register_arc_mapped_sync() {
+    let shared = Arc::new(SharedComponent { str: "shared".to_string() });
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_arc(shared.clone()).unwrap()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+
+    let resolved = root_context.blocking_resolve::<Arc<dyn GetStr>>().unwrap();
+    assert_eq!(resolved.get(), "shared".to_string());
+    assert!(Arc::ptr_eq(&(shared.clone() as Arc<dyn GetStr>), &resolved));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn register_arc_singleton_clash_sync() {
+    let shared = Arc::new(SharedComponent { str: "shared".to_string() });
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<SharedComponent>(LifeCycle::Singleton).unwrap();
+
+    assert_eq!(
+        root_context.blocking_register_arc(shared.clone()).err(),
+        Some(AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<SharedComponent>() })
+    );
+
+    // Transient Arc component has same cycled component, as singleton component
+    assert_eq!(
+        root_context.blocking_register_service_instance::<SharedComponent>(shared.clone()).err(),
+        Some(AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<Arc<SharedComponent>>() })
+    );
+
+    assert_eq!(root_context.blocking_resolve::<Arc<SharedComponent>>().unwrap().str, "constructed".to_string());
+
+    // Reverse order
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_service_instance::<SharedComponent>(shared.clone()).unwrap();
+
+    assert_eq!(
+        root_context.blocking_register_type::<SharedComponent>(LifeCycle::Singleton).err(),
+        Some(AddDependencyError::DependencyExist { component_type_info: TypeInfo::from_type::<SharedComponent>() })
+    );
+
+    assert!(Arc::ptr_eq(&shared, &root_context.blocking_resolve::<Arc<SharedComponent>>().unwrap()));
+}
@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use crate::{
+    Constructor,
+    DependencyContext,
+    LifeCycle,
+    types::{
+        BuildDependencyError,
+        BuildDependencyResult,
+        TypeInfo,
+    },
+};
+
+struct TransientDependency1 {
+    pub str: String,
+}
+
+struct SingletonDependency1 {
+    pub str: String,
+}
+
+struct FnComponent {
+    pub transient: TransientDependency1,
+    pub singleton: Arc<SingletonDependency1>,
+}
+
+struct ManyArgsComponent {
+    pub count: usize,
+}
+
+trait MissingService: Sync + Send {}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for TransientDependency1 {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for TransientDependency1 {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn register_fn() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.register_fn(|| SingletonDependency1 { str: "singleton".to_string() }, LifeCycle::Singleton).unwrap();
+    root_context.register_fn(|transient: TransientDependency1, singleton: Arc<SingletonDependency1>| FnComponent { transient, singleton }, LifeCycle::Transient).unwrap();
+
+    let component = root_context.resolve::<FnComponent>().unwrap();
+    assert_eq!(component.transient.str, "test1".to_string());
+    assert_eq!(component.singleton.str, "singleton".to_string());
+    assert!(Arc::ptr_eq(&component.singleton, &root_context.resolve::<Arc<SingletonDependency1>>().unwrap()));
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn register_fn_many_args() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.register_fn(|
+        d0: TransientDependency1, d1: TransientDependency1, d2: TransientDependency1, d3: TransientDependency1,
+        d4: TransientDependency1, d5: TransientDependency1, d6: TransientDependency1, d7: TransientDependency1,
+        d8: TransientDependency1, d9: TransientDependency1, d10: TransientDependency1, d11: TransientDependency1
+    | ManyArgsComponent { count: [d0, d1, d2, d3, d4, d5, d6, d7, d8, d9, d10, d11].len() }, LifeCycle::Transient).unwrap();
+
+    assert_eq!(root_context.resolve::<ManyArgsComponent>().unwrap().count, 12);
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn register_fn_argument_error() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.register_fn(|_: TransientDependency1, _: Box<dyn MissingService>| ManyArgsComponent { count: 2 }, LifeCycle::Transient).unwrap();
+
+    assert_eq!(root_context.resolve::<ManyArgsComponent>().err(), Some(BuildDependencyError::ArgumentResolveError {
+        component_type_info: TypeInfo::from_type::<ManyArgsComponent>(),
+        argument_index: 1,
+        argument_type_info: TypeInfo::from_type::<Box<dyn MissingService>>(),
+        err: Box::new(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Box<dyn MissingService>>() }),
+    }));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn register_fn() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap();
+    root_context.register_fn(|| SingletonDependency1 { str: "singleton".to_string() }, LifeCycle::Singleton).await.unwrap();
+    root_context.register_fn(|transient: TransientDependency1, singleton: Arc<SingletonDependency1>| FnComponent { transient, singleton }, LifeCycle::Transient).await.unwrap();
+
+    let component = root_context.resolve::<FnComponent>().await.unwrap();
+    assert_eq!(component.transient.str, "test1".to_string());
+    assert_eq!(component.singleton.str, "singleton".to_string());
+    assert!(Arc::ptr_eq(&component.singleton, &root_context.resolve::<Arc<SingletonDependency1>>().await.unwrap()));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn register_fn_many_args() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap();
+    root_context.register_fn(|
+        d0: TransientDependency1, d1: TransientDependency1, d2: TransientDependency1, d3: TransientDependency1,
+        d4: TransientDependency1, d5: TransientDependency1, d6: TransientDependency1, d7: TransientDependency1,
+        d8: TransientDependency1, d9: TransientDependency1, d10: TransientDependency1, d11: TransientDependency1
+    | ManyArgsComponent { count: [d0, d1, d2, d3, d4, d5, d6, d7, d8, d9, d10, d11].len() }, LifeCycle::Transient).await.unwrap();
+
+    assert_eq!(root_context.resolve::<ManyArgsComponent>().await.unwrap().count, 12);
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn register_fn_argument_error() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap();
+    root_context.register_fn(|_: TransientDependency1, _: Box<dyn MissingService>| ManyArgsComponent { count: 2 }, LifeCycle::Transient).await.unwrap();
+
+    assert_eq!(root_context.resolve::<ManyArgsComponent>().await.err(), Some(BuildDependencyError::ArgumentResolveError {
+        component_type_info: TypeInfo::from_type::<ManyArgsComponent>(),
+        argument_index: 1,
+        argument_type_info: TypeInfo::from_type::<Box<dyn MissingService>>(),
+        err: Box::new(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Box<dyn MissingService>>() }),
+    }));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn register_fn_sync() {
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.blocking_register_fn(|| SingletonDependency1 { str: "singleton".to_string() }, LifeCycle::Singleton).unwrap();
+    root_context.blocking_register_fn(|transient: TransientDependency1, singleton: Arc<SingletonDependency1>| FnComponent { transient, singleton }, LifeCycle::Transient).unwrap();
+
+    let component = root_context.blocking_resolve::<FnComponent>().unwrap();
+    assert_eq!(component.transient.str, "test1".to_string());
+    assert_eq!(component.singleton.str, "singleton".to_string());
+    assert!(Arc::ptr_eq(&component.singleton, &root_context.blocking_resolve::<Arc<SingletonDependency1>>().unwrap()));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn register_fn_many_args_sync() {
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.blocking_register_fn(|
+        d0: TransientDependency1, d1: TransientDependency1, d2: TransientDependency1, d3: TransientDependency1,
+        d4: TransientDependency1, d5: TransientDependency1, d6: TransientDependency1, d7: TransientDependency1,
+        d8: TransientDependency1, d9: TransientDependency1, d10: TransientDependency1, d11: TransientDependency1
+    | ManyArgsComponent { count: [d0, d1, d2, d3, d4, d5, d6, d7, d8, d9, d10, d11].len() }, LifeCycle::Transient).unwrap();
+
+    assert_eq!(root_context.blocking_resolve::<ManyArgsComponent>().unwrap().count, 12);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn register_fn_argument_error_sync() {
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.blocking_register_fn(|_: TransientDependency1, _: Box<dyn MissingService>| ManyArgsComponent { count: 2 }, LifeCycle::Transient).unwrap();
+
+    assert_eq!(root_context.blocking_resolve::<ManyArgsComponent>().err(), Some(BuildDependencyError::ArgumentResolveError {
+        component_type_info: TypeInfo::from_type::<ManyArgsComponent>(),
+        argument_index: 1,
+        argument_type_info: TypeInfo::from_type::<Box<dyn MissingService>>(),
+        err: Box::new(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Box<dyn MissingService>>() }),
+    }));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn register_async_fn() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap();
+    root_context.register_async_fn(|| async { SingletonDependency1 { str: "singleton".to_string() } }, LifeCycle::Singleton).await.unwrap();
+    root_context.register_async_fn(|transient: TransientDependency1, singleton: Arc<SingletonDependency1>| async move { FnComponent { transient, singleton } }, LifeCycle::Transient).await.unwrap();
+
+    let component = root_context.resolve::<FnComponent>().await.unwrap();
+    assert_eq!(component.transient.str, "test1".to_string());
+    assert_eq!(component.singleton.str, "singleton".to_string());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn register_async_fn_sync() {
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.blocking_register_async_fn(|| async { SingletonDependency1 { str: "singleton".to_string() } }, LifeCycle::Singleton).unwrap();
+    root_context.blocking_register_async_fn(|transient: TransientDependency1, singleton: Arc<SingletonDependency1>| async move { FnComponent { transient, singleton } }, LifeCycle::Transient).unwrap();
+
+    let component = root_context.blocking_resolve::<FnComponent>().unwrap();
+    assert_eq!(component.transient.str, "test1".to_string());
+    assert_eq!(component.singleton.str, "singleton".to_string());
+}
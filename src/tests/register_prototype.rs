@@ -0,0 +1,71 @@
+use crate::DependencyContext;
+
+#[derive(Clone)]
+struct PrototypeComponent {
+    pub timeout: u32,
+    pub tags: Vec<String>,
+}
+
+trait Options: Sync + Send {
+    fn timeout(&self) -> u32;
+}
+
+impl Options for PrototypeComponent {
+    fn timeout(&self) -> u32 {
+        self.timeout
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn register_prototype() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_prototype(PrototypeComponent { timeout: 30, tags: vec!["default".to_string()] }).unwrap()
+        .map_as::<dyn Options>().unwrap();
+
+    let mut component1 = root_context.resolve::<PrototypeComponent>().unwrap();
+    component1.tags.push("changed".to_string());
+
+    // Every resolve return new template clone
+    let component2 = root_context.resolve::<PrototypeComponent>().unwrap();
+    assert_eq!(component2.timeout, 30);
+    assert_eq!(component2.tags, vec!["default".to_string()]);
+
+    assert_eq!(root_context.resolve::<Box<dyn Options>>().unwrap().timeout(), 30);
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn register_prototype() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_prototype(PrototypeComponent { timeout: 30, tags: vec!["default".to_string()] }).await.unwrap()
+        .map_as::<dyn Options>().await.unwrap();
+
+    let mut component1 = root_context.resolve::<PrototypeComponent>().await.unwrap();
+    component1.tags.push("changed".to_string());
+
+    // Every resolve return new template clone
+    let component2 = root_context.resolve::<PrototypeComponent>().await.unwrap();
+    assert_eq!(component2.timeout, 30);
+    assert_eq!(component2.tags, vec!["default".to_string()]);
+
+    assert_eq!(root_context.resolve::<Box<dyn Options>>().await.unwrap().timeout(), 30);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn register_prototype_sync() {
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_prototype(PrototypeComponent { timeout: 30, tags: vec!["default".to_string()] }).unwrap()
+        .blocking_map_as::<dyn Options>().unwrap();
+
+    let mut component1 = root_context.blocking_resolve::<PrototypeComponent>().unwrap();
+    component1.tags.push("changed".to_string());
+
+    // Every resolve return new template clone
+    let component2 = root_context.blocking_resolve::<PrototypeComponent>().unwrap();
+    assert_eq!(component2.timeout, 30);
+    assert_eq!(component2.tags, vec!["default".to_string()]);
+
+    assert_eq!(root_context.blocking_resolve::<Box<dyn Options>>().unwrap().timeout(), 30);
+}
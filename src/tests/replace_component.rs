@@ -0,0 +1,214 @@
+use std::sync::{Arc, Weak};
+
+use crate::{
+    Constructor,
+    types::BuildDependencyResult
+};
+
+#[allow(dead_code)]
+struct TransientDependency {
+    pub str: String,
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for TransientDependency {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test".to_string() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for TransientDependency {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test".to_string() })
+    }
+}
+
+trait GetStr: Sync + Send {
+    fn get(&self) -> String;
+}
+
+impl GetStr for TransientDependency {
+    fn get(&self) -> String {
+        self.str.clone()
+    }
+}
+
+#[allow(dead_code)]
+struct ParentDependency {
+    pub child: Option<Box<ChildDependency>>,
+}
+
+#[allow(dead_code)]
+struct ChildDependency {
+    pub parent: Option<Box<ParentDependency>>,
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn replace_component() {
+    use crate::{DependencyContext, LifeCycle};
+
+    let root_context = DependencyContext::new_root();
+
+    // Register if not exist
+    root_context.register_type_or_replace::<TransientDependency>(LifeCycle::Transient).unwrap()
+        .map_as::<dyn GetStr>().unwrap();
+
+    assert_eq!(root_context.resolve::<Box<dyn GetStr>>().unwrap().get(), "test".to_string());
+
+    // Same life cycle keep mappings
+    root_context.register_closure_or_replace(|_| Ok(TransientDependency { str: "fake".to_string() }), LifeCycle::Transient).unwrap();
+
+    assert_eq!(root_context.resolve::<Box<dyn GetStr>>().unwrap().get(), "fake".to_string());
+
+    // Other life cycle remove mappings
+    root_context.register_type_or_replace::<TransientDependency>(LifeCycle::Singleton).unwrap();
+
+    assert!(!root_context.is_service_exist::<Box<dyn GetStr>>());
+    assert!(!root_context.is_service_exist::<TransientDependency>());
+    assert_eq!(root_context.resolve::<Arc<TransientDependency>>().unwrap().str, "test".to_string());
+
+    // Singleton dropped on replace
+    root_context.register_instance_or_replace(TransientDependency { str: "instance".to_string() }).unwrap()
+        .map_as::<dyn GetStr>().unwrap();
+
+    assert_eq!(root_context.resolve::<Arc<TransientDependency>>().unwrap().str, "instance".to_string());
+    assert_eq!(root_context.resolve::<Arc<dyn GetStr>>().unwrap().get(), "instance".to_string());
+
+    root_context.register_type_or_replace::<TransientDependency>(LifeCycle::ContextDependent).unwrap();
+
+    assert_eq!(root_context.resolve::<Weak<TransientDependency>>().unwrap().upgrade().unwrap().str, "test".to_string());
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn replace_component() {
+    use crate::{DependencyContext, LifeCycle};
+
+    let root_context = DependencyContext::new_root();
+
+    // Register if not exist
+    root_context.register_type_or_replace::<TransientDependency>(LifeCycle::Transient).await.unwrap()
+        .map_as::<dyn GetStr>().await.unwrap();
+
+    assert_eq!(root_context.resolve::<Box<dyn GetStr>>().await.unwrap().get(), "test".to_string());
+
+    // Same life cycle keep mappings
+    root_context.register_closure_or_replace(|_| Ok(TransientDependency { str: "fake".to_string() }), LifeCycle::Transient).await.unwrap();
+
+    assert_eq!(root_context.resolve::<Box<dyn GetStr>>().await.unwrap().get(), "fake".to_string());
+
+    // Other life cycle remove mappings
+    root_context.register_type_or_replace::<TransientDependency>(LifeCycle::Singleton).await.unwrap();
+
+    assert!(!root_context.is_service_exist::<Box<dyn GetStr>>().await);
+    assert!(!root_context.is_service_exist::<TransientDependency>().await);
+    assert_eq!(root_context.resolve::<Arc<TransientDependency>>().await.unwrap().str, "test".to_string());
+
+    // Singleton dropped on replace
+    root_context.register_instance_or_replace(TransientDependency { str: "instance".to_string() }).await.unwrap()
+        .map_as::<dyn GetStr>().await.unwrap();
+
+    assert_eq!(root_context.resolve::<Arc<TransientDependency>>().await.unwrap().str, "instance".to_string());
+    assert_eq!(root_context.resolve::<Arc<dyn GetStr>>().await.unwrap().get(), "instance".to_string());
+
+    root_context.register_type_or_replace::<TransientDependency>(LifeCycle::ContextDependent).await.unwrap();
+
+    assert_eq!(root_context.resolve::<Weak<TransientDependency>>().await.unwrap().upgrade().unwrap().str, "test".to_string());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn replace_component_sync() {
+    use crate::{DependencyContext, LifeCycle};
+
+    let root_context = DependencyContext::new_root();
+
+    // Register if not exist
+    root_context.blocking_register_type_or_replace::<TransientDependency>(LifeCycle::Transient).unwrap()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+
+    assert_eq!(root_context.blocking_resolve::<Box<dyn GetStr>>().unwrap().get(), "test".to_string());
+
+    // Same life cycle keep mappings
+    root_context.blocking_register_closure_or_replace(|_| Ok(TransientDependency { str: "fake".to_string() }), LifeCycle::Transient).unwrap();
+
+    assert_eq!(root_context.blocking_resolve::<Box<dyn GetStr>>().unwrap().get(), "fake".to_string());
+
+    // Other life cycle remove mappings
+    root_context.blocking_register_type_or_replace::<TransientDependency>(LifeCycle::Singleton).unwrap();
+
+    assert!(!root_context.blocking_is_service_exist::<Box<dyn GetStr>>());
+    assert!(!root_context.blocking_is_service_exist::<TransientDependency>());
+    assert_eq!(root_context.blocking_resolve::<Arc<TransientDependency>>().unwrap().str, "test".to_string());
+
+    // Singleton dropped on replace
+    root_context.blocking_register_instance_or_replace(TransientDependency { str: "instance".to_string() }).unwrap()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+
+    assert_eq!(root_context.blocking_resolve::<Arc<TransientDependency>>().unwrap().str, "instance".to_string());
+    assert_eq!(root_context.blocking_resolve::<Arc<dyn GetStr>>().unwrap().get(), "instance".to_string());
+
+    root_context.blocking_register_type_or_replace::<TransientDependency>(LifeCycle::ContextDependent).unwrap();
+
+    assert_eq!(root_context.blocking_resolve::<Weak<TransientDependency>>().unwrap().upgrade().unwrap().str, "test".to_string());
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn replace_component_links() {
+    use crate::{DependencyContext, LifeCycle};
+
+    let root_context = DependencyContext::new_root();
+
+    root_context.register_closure(|ctx| Ok(ParentDependency { child: Some(Box::new(ctx.resolve()?)) }), LifeCycle::Transient).unwrap();
+    root_context.register_closure(|_| Ok(ChildDependency { parent: None }), LifeCycle::Transient).unwrap();
+
+    root_context.resolve::<ParentDependency>().unwrap();
+
+    // Replaced component links removed, so reverse dependency not cycled
+    root_context.register_closure_or_replace(|_| Ok(ParentDependency { child: None }), LifeCycle::Transient).unwrap();
+    root_context.register_closure_or_replace(|ctx| Ok(ChildDependency { parent: Some(Box::new(ctx.resolve()?)) }), LifeCycle::Transient).unwrap();
+
+    assert!(root_context.resolve::<ChildDependency>().unwrap().parent.is_some());
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn replace_component_links() {
+    use crate::{DependencyContext, LifeCycle};
+
+    let root_context = DependencyContext::new_root();
+
+    root_context.register_async_closure(|ctx: DependencyContext| async move { Ok(ParentDependency { child: Some(Box::new(ctx.resolve().await?)) }) }, LifeCycle::Transient).await.unwrap();
+    root_context.register_closure(|_| Ok(ChildDependency { parent: None }), LifeCycle::Transient).await.unwrap();
+
+    root_context.resolve::<ParentDependency>().await.unwrap();
+
+    // Replaced component links removed, so reverse dependency not cycled
+    root_context.register_closure_or_replace(|_| Ok(ParentDependency { child: None }), LifeCycle::Transient).await.unwrap();
+    root_context.register_async_closure_or_replace(|ctx: DependencyContext| async move { Ok(ChildDependency { parent: Some(Box::new(ctx.resolve().await?)) }) }, LifeCycle::Transient).await.unwrap();
+
+    assert!(root_context.resolve::<ChildDependency>().await.unwrap().parent.is_some());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn replace_component_links_sync() {
+    use crate::{DependencyContext, LifeCycle};
+
+    let root_context = DependencyContext::new_root();
+
+    root_context.blocking_register_async_closure(|ctx: DependencyContext| async move { Ok(ParentDependency { child: Some(Box::new(ctx.resolve().await?)) }) }, LifeCycle::Transient).unwrap();
+    root_context.blocking_register_closure(|_| Ok(ChildDependency { parent: None }), LifeCycle::Transient).unwrap();
+
+    root_context.blocking_resolve::<ParentDependency>().unwrap();
+
+    // Replaced component links removed, so reverse dependency not cycled
+    root_context.blocking_register_closure_or_replace(|_| Ok(ParentDependency { child: None }), LifeCycle::Transient).unwrap();
+    root_context.blocking_register_async_closure_or_replace(|ctx: DependencyContext| async move { Ok(ChildDependency { parent: Some(Box::new(ctx.resolve().await?)) }) }, LifeCycle::Transient).unwrap();
+
+    assert!(root_context.blocking_resolve::<ChildDependency>().unwrap().parent.is_some());
+}
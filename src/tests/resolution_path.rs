@@ -0,0 +1,115 @@
+use crate::{
+    Constructor,
+    types::{
+        BuildDependencyResult,
+        TypeInfo,
+    },
+};
+
+struct BillingService {
+    path: Vec<TypeInfo>,
+    logger: Logger,
+}
+
+struct Logger {
+    path: Vec<TypeInfo>,
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for BillingService {
+    fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { path: ctx.resolution_path().to_vec(), logger: ctx.resolve()? })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for BillingService {
+    async fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { path: ctx.resolution_path().to_vec(), logger: ctx.resolve().await? })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for Logger {
+    fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { path: ctx.resolution_path().to_vec() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for Logger {
+    async fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { path: ctx.resolution_path().to_vec() })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn resolution_path() {
+    use crate::{
+        DependencyContext,
+        LifeCycle
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<BillingService>(LifeCycle::Transient).unwrap();
+    root_context.register_type::<Logger>(LifeCycle::Transient).unwrap();
+
+    assert!(root_context.resolution_path().is_empty());
+
+    let billing_service = root_context.resolve::<BillingService>().unwrap();
+    assert_eq!(billing_service.path, vec![TypeInfo::from_type::<BillingService>()]);
+    assert_eq!(billing_service.logger.path, vec![TypeInfo::from_type::<BillingService>(), TypeInfo::from_type::<Logger>()]);
+
+    // Path started from first resolved component
+    let logger = root_context.resolve::<Logger>().unwrap();
+    assert_eq!(logger.path, vec![TypeInfo::from_type::<Logger>()]);
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn resolution_path() {
+    use crate::{
+        DependencyContext,
+        LifeCycle
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<BillingService>(LifeCycle::Transient).await.unwrap();
+    root_context.register_type::<Logger>(LifeCycle::Transient).await.unwrap();
+
+    assert!(root_context.resolution_path().is_empty());
+
+    let billing_service = root_context.resolve::<BillingService>().await.unwrap();
+    assert_eq!(billing_service.path, vec![TypeInfo::from_type::<BillingService>()]);
+    assert_eq!(billing_service.logger.path, vec![TypeInfo::from_type::<BillingService>(), TypeInfo::from_type::<Logger>()]);
+
+    // Path started from first resolved component
+    let logger = root_context.resolve::<Logger>().await.unwrap();
+    assert_eq!(logger.path, vec![TypeInfo::from_type::<Logger>()]);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn resolution_path_sync() {
+    use crate::{
+        DependencyContext,
+        LifeCycle
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<BillingService>(LifeCycle::Transient).unwrap();
+    root_context.blocking_register_type::<Logger>(LifeCycle::Transient).unwrap();
+
+    assert!(root_context.resolution_path().is_empty());
+
+    let billing_service = root_context.blocking_resolve::<BillingService>().unwrap();
+    assert_eq!(billing_service.path, vec![TypeInfo::from_type::<BillingService>()]);
+    assert_eq!(billing_service.logger.path, vec![TypeInfo::from_type::<BillingService>(), TypeInfo::from_type::<Logger>()]);
+
+    // Path started from first resolved component
+    let logger = root_context.blocking_resolve::<Logger>().unwrap();
+    assert_eq!(logger.path, vec![TypeInfo::from_type::<Logger>()]);
+}
@@ -0,0 +1,153 @@
+use std::{
+    any::TypeId,
+    sync::Arc,
+};
+
+use crate::{
+    DependencyContext,
+    ResolutionSource,
+    SourceResolution,
+    types::{
+        BuildDependencyResult,
+        TypeInfo,
+    },
+};
+
+struct DbSettings {
+    url: String,
+}
+
+struct Clock {
+    now: u64,
+}
+
+struct Unknown {}
+
+struct InvalidInstance {}
+
+struct TestSource {}
+
+#[cfg(not(feature = "async-mode"))]
+impl ResolutionSource for TestSource {
+    fn resolve(&self, ctx: &DependencyContext, service_type_info: &TypeInfo) -> BuildDependencyResult<SourceResolution> {
+        if service_type_info.type_id == TypeId::of::<Arc<DbSettings>>() {
+            ctx.register_arc(Arc::new(DbSettings { url: "env".to_string() })).unwrap();
+            return Ok(SourceResolution::Registered);
+        }
+
+        if service_type_info.type_id == TypeId::of::<Clock>() {
+            return Ok(SourceResolution::Instance(Box::new(Clock { now: 42 })));
+        }
+
+        if service_type_info.type_id == TypeId::of::<InvalidInstance>() {
+            return Ok(SourceResolution::Instance(Box::new(0u8)));
+        }
+
+        Ok(SourceResolution::NotFound)
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl ResolutionSource for TestSource {
+    async fn resolve(&self, ctx: &DependencyContext, service_type_info: &TypeInfo) -> BuildDependencyResult<SourceResolution> {
+        if service_type_info.type_id == TypeId::of::<Arc<DbSettings>>() {
+            ctx.register_arc(Arc::new(DbSettings { url: "env".to_string() })).await.unwrap();
+            return Ok(SourceResolution::Registered);
+        }
+
+        if service_type_info.type_id == TypeId::of::<Clock>() {
+            return Ok(SourceResolution::Instance(Box::new(Clock { now: 42 })));
+        }
+
+        if service_type_info.type_id == TypeId::of::<InvalidInstance>() {
+            return Ok(SourceResolution::Instance(Box::new(0u8)));
+        }
+
+        Ok(SourceResolution::NotFound)
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn resolution_source() {
+    use crate::types::BuildDependencyError;
+
+    let root_context = DependencyContext::new_root();
+    root_context.add_resolution_source(TestSource {});
+
+    // Registered by source and resolved from registry next time
+    assert!(!root_context.is_service_exist::<Arc<DbSettings>>());
+    assert_eq!(root_context.resolve::<Arc<DbSettings>>().unwrap().url, "env".to_string());
+    assert!(root_context.is_service_exist::<Arc<DbSettings>>());
+
+    // Instance produced by source on every resolve
+    assert_eq!(root_context.resolve::<Clock>().unwrap().now, 42);
+    assert!(!root_context.is_service_exist::<Clock>());
+
+    // Collection resolved from source as single service, named resolve don't ask sources
+    assert_eq!(root_context.resolve_collection::<Clock>().unwrap().iter().map(|clock| clock.now).collect::<Vec<_>>(), vec![42]);
+    assert_eq!(root_context.resolve_named::<Clock>("clock").err(), Some(BuildDependencyError::NamedNotFound { type_info: TypeInfo::from_type::<Clock>(), name: "clock".to_string() }));
+    assert_eq!(root_context.resolve_collection::<Unknown>().err(), Some(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Unknown>() }));
+
+    assert!(root_context.try_resolve::<Unknown>().unwrap().is_none());
+    assert_eq!(root_context.resolve::<Unknown>().err(), Some(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Unknown>() }));
+
+    assert_eq!(root_context.resolve::<InvalidInstance>().err(), Some(BuildDependencyError::InvalidSourceInstance { service_type_info: TypeInfo::from_type::<InvalidInstance>() }));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn resolution_source() {
+    use crate::types::BuildDependencyError;
+
+    let root_context = DependencyContext::new_root();
+    root_context.add_resolution_source(TestSource {}).await;
+
+    // Registered by source and resolved from registry next time
+    assert!(!root_context.is_service_exist::<Arc<DbSettings>>().await);
+    assert_eq!(root_context.resolve::<Arc<DbSettings>>().await.unwrap().url, "env".to_string());
+    assert!(root_context.is_service_exist::<Arc<DbSettings>>().await);
+
+    // Instance produced by source on every resolve
+    assert_eq!(root_context.resolve::<Clock>().await.unwrap().now, 42);
+    assert!(!root_context.is_service_exist::<Clock>().await);
+
+    // Collection resolved from source as single service, named resolve don't ask sources
+    assert_eq!(root_context.resolve_collection::<Clock>().await.unwrap().iter().map(|clock| clock.now).collect::<Vec<_>>(), vec![42]);
+    assert_eq!(root_context.resolve_named::<Clock>("clock").await.err(), Some(BuildDependencyError::NamedNotFound { type_info: TypeInfo::from_type::<Clock>(), name: "clock".to_string() }));
+    assert_eq!(root_context.resolve_collection::<Unknown>().await.err(), Some(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Unknown>() }));
+
+    assert!(root_context.try_resolve::<Unknown>().await.unwrap().is_none());
+    assert_eq!(root_context.resolve::<Unknown>().await.err(), Some(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Unknown>() }));
+
+    assert_eq!(root_context.resolve::<InvalidInstance>().await.err(), Some(BuildDependencyError::InvalidSourceInstance { service_type_info: TypeInfo::from_type::<InvalidInstance>() }));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn resolution_source_sync() {
+    use crate::types::BuildDependencyError;
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_add_resolution_source(TestSource {});
+
+    // Registered by source and resolved from registry next time
+    assert!(!root_context.blocking_is_service_exist::<Arc<DbSettings>>());
+    assert_eq!(root_context.blocking_resolve::<Arc<DbSettings>>().unwrap().url, "env".to_string());
+    assert!(root_context.blocking_is_service_exist::<Arc<DbSettings>>());
+
+    // Instance produced by source on every resolve
+    assert_eq!(root_context.blocking_resolve::<Clock>().unwrap().now, 42);
+    assert!(!root_context.blocking_is_service_exist::<Clock>());
+
+    // Collection resolved from source as single service, named resolve don't ask sources
+    assert_eq!(root_context.blocking_resolve_collection::<Clock>().unwrap().iter().map(|clock| clock.now).collect::<Vec<_>>(), vec![42]);
+    assert_eq!(root_context.blocking_resolve_named::<Clock>("clock").err(), Some(BuildDependencyError::NamedNotFound { type_info: TypeInfo::from_type::<Clock>(), name: "clock".to_string() }));
+    assert_eq!(root_context.blocking_resolve_collection::<Unknown>().err(), Some(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Unknown>() }));
+
+    assert!(root_context.blocking_try_resolve::<Unknown>().unwrap().is_none());
+    assert_eq!(root_context.blocking_resolve::<Unknown>().err(), Some(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Unknown>() }));
+
+    assert_eq!(root_context.blocking_resolve::<InvalidInstance>().err(), Some(BuildDependencyError::InvalidSourceInstance { service_type_info: TypeInfo::from_type::<InvalidInstance>() }));
+}
@@ -0,0 +1,664 @@
+use std::{
+    any::Any,
+    sync::{Arc, Mutex, Weak, atomic::{AtomicBool, Ordering}},
+};
+
+use crate::{
+    Constructor,
+    DependencyContext,
+    LifeCycle,
+    ResolveInfo,
+    ResolveInterceptor,
+    types::{
+        BuildDependencyError,
+        BuildDependencyResult,
+        TypeInfo,
+    },
+};
+
+struct TransientDependency1 {
+    pub str: String,
+}
+
+#[allow(dead_code)]
+struct SingletonDependency1 {
+    pub dependency: TransientDependency1,
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for TransientDependency1 {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for TransientDependency1 {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for SingletonDependency1 {
+    fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { dependency: ctx.resolve()? })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for SingletonDependency1 {
+    async fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { dependency: ctx.resolve().await? })
+    }
+}
+
+struct AuditInterceptor {
+    name: String,
+    log: Arc<Mutex<Vec<(String, ResolveInfo)>>>,
+}
+
+// Replace TransientDependency1 ctor
+struct SubstituteInterceptor {}
+
+// Forbid TransientDependency1 construction
+struct ErrorInterceptor {}
+
+// Return instance of other type
+struct InvalidInterceptor {}
+
+// Forbid first construction only
+#[derive(Default)]
+struct ErrorOnceInterceptor {
+    failed: AtomicBool,
+}
+
+impl AuditInterceptor {
+    fn write(&self, stage: &str, info: &ResolveInfo) {
+        self.log.lock().unwrap().push((format!("{} {stage}", self.name), info.clone()));
+    }
+}
+
+impl ErrorOnceInterceptor {
+    fn fail_once(&self) -> BuildDependencyResult<Option<Box<dyn Any + Sync + Send>>> {
+        if !self.failed.swap(true, Ordering::SeqCst) {
+            return Err(BuildDependencyError::Custom { err: anyhow::anyhow!("forbidden") });
+        }
+        Ok(None)
+    }
+}
+
+impl SubstituteInterceptor {
+    fn substitute(info: &ResolveInfo) -> BuildDependencyResult<Option<Box<dyn Any + Sync + Send>>> {
+        if info.component_type_info == TypeInfo::from_type::<TransientDependency1>() {
+            return Ok(Some(Box::new(TransientDependency1 { str: "substitute".to_string() })));
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl ResolveInterceptor for AuditInterceptor {
+    fn before_resolve(&self, info: &ResolveInfo) -> BuildDependencyResult<Option<Box<dyn Any + Sync + Send>>> {
+        self.write("before", info);
+        Ok(None)
+    }
+
+    fn after_resolve(&self, info: &ResolveInfo, result: BuildDependencyResult<Box<dyn Any + Sync + Send>>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        self.write("after", info);
+        result
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl ResolveInterceptor for SubstituteInterceptor {
+    fn before_resolve(&self, info: &ResolveInfo) -> BuildDependencyResult<Option<Box<dyn Any + Sync + Send>>> {
+        Self::substitute(info)
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl ResolveInterceptor for ErrorInterceptor {
+    fn before_resolve(&self, _: &ResolveInfo) -> BuildDependencyResult<Option<Box<dyn Any + Sync + Send>>> {
+        Err(BuildDependencyError::Custom { err: anyhow::anyhow!("forbidden") })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl ResolveInterceptor for InvalidInterceptor {
+    fn after_resolve(&self, _: &ResolveInfo, _: BuildDependencyResult<Box<dyn Any + Sync + Send>>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        Ok(Box::new("invalid".to_string()))
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl ResolveInterceptor for ErrorOnceInterceptor {
+    fn before_resolve(&self, _: &ResolveInfo) -> BuildDependencyResult<Option<Box<dyn Any + Sync + Send>>> {
+        self.fail_once()
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl ResolveInterceptor for AuditInterceptor {
+    async fn before_resolve(&self, info: &ResolveInfo) -> BuildDependencyResult<Option<Box<dyn Any + Sync + Send>>> {
+        self.write("before", info);
+        Ok(None)
+    }
+
+    async fn after_resolve(&self, info: &ResolveInfo, result: BuildDependencyResult<Box<dyn Any + Sync + Send>>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        self.write("after", info);
+        result
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl ResolveInterceptor for SubstituteInterceptor {
+    async fn before_resolve(&self, info: &ResolveInfo) -> BuildDependencyResult<Option<Box<dyn Any + Sync + Send>>> {
+        Self::substitute(info)
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl ResolveInterceptor for ErrorInterceptor {
+    async fn before_resolve(&self, _: &ResolveInfo) -> BuildDependencyResult<Option<Box<dyn Any + Sync + Send>>> {
+        Err(BuildDependencyError::Custom { err: anyhow::anyhow!("forbidden") })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl ResolveInterceptor for InvalidInterceptor {
+    async fn after_resolve(&self, _: &ResolveInfo, _: BuildDependencyResult<Box<dyn Any + Sync + Send>>) -> BuildDependencyResult<Box<dyn Any + Sync + Send>> {
+        Ok(Box::new("invalid".to_string()))
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl ResolveInterceptor for ErrorOnceInterceptor {
+    async fn before_resolve(&self, _: &ResolveInfo) -> BuildDependencyResult<Option<Box<dyn Any + Sync + Send>>> {
+        self.fail_once()
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn resolve_interceptor() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.register_type::<SingletonDependency1>(LifeCycle::Singleton).unwrap();
+
+    root_context.add_interceptor(AuditInterceptor { name: "first".to_string(), log: log.clone() });
+    root_context.add_interceptor(AuditInterceptor { name: "second".to_string(), log: log.clone() });
+
+    root_context.resolve::<Arc<SingletonDependency1>>().unwrap();
+
+    let singleton_info = ResolveInfo {
+        component_type_info: TypeInfo::from_type::<SingletonDependency1>(),
+        life_cycle: LifeCycle::Singleton,
+        parent_type_info: None,
+    };
+
+    let transient_info = ResolveInfo {
+        component_type_info: TypeInfo::from_type::<TransientDependency1>(),
+        life_cycle: LifeCycle::Transient,
+        parent_type_info: Some(TypeInfo::from_type::<SingletonDependency1>()),
+    };
+
+    assert_eq!(*log.lock().unwrap(), vec![
+        ("first before".to_string(), singleton_info.clone()),
+        ("second before".to_string(), singleton_info.clone()),
+        ("first before".to_string(), transient_info.clone()),
+        ("second before".to_string(), transient_info.clone()),
+        ("second after".to_string(), transient_info.clone()),
+        ("first after".to_string(), transient_info),
+        ("second after".to_string(), singleton_info.clone()),
+        ("first after".to_string(), singleton_info),
+    ]);
+
+    // Singleton already constructed, ctor and interceptors not called
+    log.lock().unwrap().clear();
+    root_context.resolve::<Arc<SingletonDependency1>>().unwrap();
+    assert!(log.lock().unwrap().is_empty());
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn resolve_interceptor_substitute() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.register_type::<SingletonDependency1>(LifeCycle::Singleton).unwrap();
+
+    root_context.add_interceptor(SubstituteInterceptor {});
+
+    assert_eq!(root_context.resolve::<TransientDependency1>().unwrap().str, "substitute".to_string());
+    assert_eq!(root_context.resolve::<Arc<SingletonDependency1>>().unwrap().dependency.str, "substitute".to_string());
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn resolve_interceptor_error() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+
+    root_context.add_interceptor(ErrorInterceptor {});
+
+    assert_eq!(root_context.resolve::<TransientDependency1>().err(), Some(BuildDependencyError::Custom { err: anyhow::anyhow!("forbidden") }));
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn resolve_interceptor_error_retry() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.register_type::<SingletonDependency1>(LifeCycle::Singleton).unwrap();
+
+    root_context.add_interceptor(ErrorOnceInterceptor::default());
+
+    // Failed construction not cache singleton
+    assert_eq!(root_context.resolve::<Arc<SingletonDependency1>>().err(), Some(BuildDependencyError::Custom { err: anyhow::anyhow!("forbidden") }));
+    let singleton = root_context.resolve::<Arc<SingletonDependency1>>().unwrap();
+    assert!(Arc::ptr_eq(&singleton, &root_context.resolve::<Arc<SingletonDependency1>>().unwrap()));
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::ContextDependent).unwrap();
+
+    root_context.add_interceptor(ErrorOnceInterceptor::default());
+
+    assert_eq!(root_context.resolve::<Weak<TransientDependency1>>().err(), Some(BuildDependencyError::Custom { err: anyhow::anyhow!("forbidden") }));
+    let context_dependent = root_context.resolve::<Weak<TransientDependency1>>().unwrap();
+    assert!(Weak::ptr_eq(&context_dependent, &root_context.resolve::<Weak<TransientDependency1>>().unwrap()));
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn resolve_interceptor_invalid_instance() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+
+    root_context.add_interceptor(InvalidInterceptor {});
+
+    assert_eq!(root_context.resolve::<TransientDependency1>().err(), Some(BuildDependencyError::InvalidInterceptedInstance {
+        component_type_info: TypeInfo::from_type::<TransientDependency1>(),
+    }));
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn resolve_interceptor_short_circuit() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+
+    root_context.add_interceptor(AuditInterceptor { name: "first".to_string(), log: log.clone() });
+    root_context.add_interceptor(SubstituteInterceptor {});
+    root_context.add_interceptor(AuditInterceptor { name: "second".to_string(), log: log.clone() });
+
+    assert_eq!(root_context.resolve::<TransientDependency1>().unwrap().str, "substitute".to_string());
+
+    let transient_info = ResolveInfo {
+        component_type_info: TypeInfo::from_type::<TransientDependency1>(),
+        life_cycle: LifeCycle::Transient,
+        parent_type_info: None,
+    };
+
+    // Interceptors after substitute not called
+    assert_eq!(*log.lock().unwrap(), vec![
+        ("first before".to_string(), transient_info.clone()),
+        ("first after".to_string(), transient_info),
+    ]);
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn resolve_interceptor_with_args() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_closure_with_args(|_, str: String| Ok(TransientDependency1 { str })).unwrap();
+
+    root_context.add_interceptor(AuditInterceptor { name: "first".to_string(), log: log.clone() });
+
+    assert_eq!(root_context.resolve_with::<TransientDependency1, String>("args".to_string()).unwrap().str, "args".to_string());
+
+    let transient_info = ResolveInfo {
+        component_type_info: TypeInfo::from_type::<TransientDependency1>(),
+        life_cycle: LifeCycle::Transient,
+        parent_type_info: None,
+    };
+
+    assert_eq!(*log.lock().unwrap(), vec![
+        ("first before".to_string(), transient_info.clone()),
+        ("first after".to_string(), transient_info),
+    ]);
+
+    // Substitute interceptor replace ctor with args too
+    root_context.add_interceptor(SubstituteInterceptor {});
+    assert_eq!(root_context.resolve_with::<TransientDependency1, String>("args".to_string()).unwrap().str, "substitute".to_string());
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn resolve_interceptor() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap();
+    root_context.register_type::<SingletonDependency1>(LifeCycle::Singleton).await.unwrap();
+
+    root_context.add_interceptor(AuditInterceptor { name: "first".to_string(), log: log.clone() }).await;
+    root_context.add_interceptor(AuditInterceptor { name: "second".to_string(), log: log.clone() }).await;
+
+    root_context.resolve::<Arc<SingletonDependency1>>().await.unwrap();
+
+    let singleton_info = ResolveInfo {
+        component_type_info: TypeInfo::from_type::<SingletonDependency1>(),
+        life_cycle: LifeCycle::Singleton,
+        parent_type_info: None,
+    };
+
+    let transient_info = ResolveInfo {
+        component_type_info: TypeInfo::from_type::<TransientDependency1>(),
+        life_cycle: LifeCycle::Transient,
+        parent_type_info: Some(TypeInfo::from_type::<SingletonDependency1>()),
+    };
+
+    assert_eq!(*log.lock().unwrap(), vec![
+        ("first before".to_string(), singleton_info.clone()),
+        ("second before".to_string(), singleton_info.clone()),
+        ("first before".to_string(), transient_info.clone()),
+        ("second before".to_string(), transient_info.clone()),
+        ("second after".to_string(), transient_info.clone()),
+        ("first after".to_string(), transient_info),
+        ("second after".to_string(), singleton_info.clone()),
+        ("first after".to_string(), singleton_info),
+    ]);
+
+    // Singleton already constructed, ctor and interceptors not called
+    log.lock().unwrap().clear();
+    root_context.resolve::<Arc<SingletonDependency1>>().await.unwrap();
+    assert!(log.lock().unwrap().is_empty());
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn resolve_interceptor_substitute() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap();
+    root_context.register_type::<SingletonDependency1>(LifeCycle::Singleton).await.unwrap();
+
+    root_context.add_interceptor(SubstituteInterceptor {}).await;
+
+    assert_eq!(root_context.resolve::<TransientDependency1>().await.unwrap().str, "substitute".to_string());
+    assert_eq!(root_context.resolve::<Arc<SingletonDependency1>>().await.unwrap().dependency.str, "substitute".to_string());
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn resolve_interceptor_error() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap();
+
+    root_context.add_interceptor(ErrorInterceptor {}).await;
+
+    assert_eq!(root_context.resolve::<TransientDependency1>().await.err(), Some(BuildDependencyError::Custom { err: anyhow::anyhow!("forbidden") }));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn resolve_interceptor_error_retry() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap();
+    root_context.register_type::<SingletonDependency1>(LifeCycle::Singleton).await.unwrap();
+
+    root_context.add_interceptor(ErrorOnceInterceptor::default()).await;
+
+    // Failed construction not cache singleton
+    assert_eq!(root_context.resolve::<Arc<SingletonDependency1>>().await.err(), Some(BuildDependencyError::Custom { err: anyhow::anyhow!("forbidden") }));
+    let singleton = root_context.resolve::<Arc<SingletonDependency1>>().await.unwrap();
+    assert!(Arc::ptr_eq(&singleton, &root_context.resolve::<Arc<SingletonDependency1>>().await.unwrap()));
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::ContextDependent).await.unwrap();
+
+    root_context.add_interceptor(ErrorOnceInterceptor::default()).await;
+
+    assert_eq!(root_context.resolve::<Weak<TransientDependency1>>().await.err(), Some(BuildDependencyError::Custom { err: anyhow::anyhow!("forbidden") }));
+    let context_dependent = root_context.resolve::<Weak<TransientDependency1>>().await.unwrap();
+    assert!(Weak::ptr_eq(&context_dependent, &root_context.resolve::<Weak<TransientDependency1>>().await.unwrap()));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn resolve_interceptor_invalid_instance() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap();
+
+    root_context.add_interceptor(InvalidInterceptor {}).await;
+
+    assert_eq!(root_context.resolve::<TransientDependency1>().await.err(), Some(BuildDependencyError::InvalidInterceptedInstance {
+        component_type_info: TypeInfo::from_type::<TransientDependency1>(),
+    }));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn resolve_interceptor_short_circuit() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap();
+
+    root_context.add_interceptor(AuditInterceptor { name: "first".to_string(), log: log.clone() }).await;
+    root_context.add_interceptor(SubstituteInterceptor {}).await;
+    root_context.add_interceptor(AuditInterceptor { name: "second".to_string(), log: log.clone() }).await;
+
+    assert_eq!(root_context.resolve::<TransientDependency1>().await.unwrap().str, "substitute".to_string());
+
+    let transient_info = ResolveInfo {
+        component_type_info: TypeInfo::from_type::<TransientDependency1>(),
+        life_cycle: LifeCycle::Transient,
+        parent_type_info: None,
+    };
+
+    // Interceptors after substitute not called
+    assert_eq!(*log.lock().unwrap(), vec![
+        ("first before".to_string(), transient_info.clone()),
+        ("first after".to_string(), transient_info),
+    ]);
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn resolve_interceptor_with_args() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_closure_with_args(|_, str: String| Ok(TransientDependency1 { str })).await.unwrap();
+
+    root_context.add_interceptor(AuditInterceptor { name: "first".to_string(), log: log.clone() }).await;
+
+    assert_eq!(root_context.resolve_with::<TransientDependency1, String>("args".to_string()).await.unwrap().str, "args".to_string());
+
+    let transient_info = ResolveInfo {
+        component_type_info: TypeInfo::from_type::<TransientDependency1>(),
+        life_cycle: LifeCycle::Transient,
+        parent_type_info: None,
+    };
+
+    assert_eq!(*log.lock().unwrap(), vec![
+        ("first before".to_string(), transient_info.clone()),
+        ("first after".to_string(), transient_info),
+    ]);
+
+    // Substitute interceptor replace ctor with args too
+    root_context.add_interceptor(SubstituteInterceptor {}).await;
+    assert_eq!(root_context.resolve_with::<TransientDependency1, String>("args".to_string()).await.unwrap().str, "substitute".to_string());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn resolve_interceptor_sync() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.blocking_register_type::<SingletonDependency1>(LifeCycle::Singleton).unwrap();
+
+    root_context.blocking_add_interceptor(AuditInterceptor { name: "first".to_string(), log: log.clone() });
+    root_context.blocking_add_interceptor(AuditInterceptor { name: "second".to_string(), log: log.clone() });
+
+    root_context.blocking_resolve::<Arc<SingletonDependency1>>().unwrap();
+
+    let singleton_info = ResolveInfo {
+        component_type_info: TypeInfo::from_type::<SingletonDependency1>(),
+        life_cycle: LifeCycle::Singleton,
+        parent_type_info: None,
+    };
+
+    let transient_info = ResolveInfo {
+        component_type_info: TypeInfo::from_type::<TransientDependency1>(),
+        life_cycle: LifeCycle::Transient,
+        parent_type_info: Some(TypeInfo::from_type::<SingletonDependency1>()),
+    };
+
+    assert_eq!(*log.lock().unwrap(), vec![
+        ("first before".to_string(), singleton_info.clone()),
+        ("second before".to_string(), singleton_info.clone()),
+        ("first before".to_string(), transient_info.clone()),
+        ("second before".to_string(), transient_info.clone()),
+        ("second after".to_string(), transient_info.clone()),
+        ("first after".to_string(), transient_info),
+        ("second after".to_string(), singleton_info.clone()),
+        ("first after".to_string(), singleton_info),
+    ]);
+
+    // Singleton already constructed, ctor and interceptors not called
+    log.lock().unwrap().clear();
+    root_context.blocking_resolve::<Arc<SingletonDependency1>>().unwrap();
+    assert!(log.lock().unwrap().is_empty());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn resolve_interceptor_substitute_sync() {
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.blocking_register_type::<SingletonDependency1>(LifeCycle::Singleton).unwrap();
+
+    root_context.blocking_add_interceptor(SubstituteInterceptor {});
+
+    assert_eq!(root_context.blocking_resolve::<TransientDependency1>().unwrap().str, "substitute".to_string());
+    assert_eq!(root_context.blocking_resolve::<Arc<SingletonDependency1>>().unwrap().dependency.str, "substitute".to_string());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn resolve_interceptor_error_sync() {
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+
+    root_context.blocking_add_interceptor(ErrorInterceptor {});
+
+    assert_eq!(root_context.blocking_resolve::<TransientDependency1>().err(), Some(BuildDependencyError::Custom { err: anyhow::anyhow!("forbidden") }));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn resolve_interceptor_error_retry_sync() {
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.blocking_register_type::<SingletonDependency1>(LifeCycle::Singleton).unwrap();
+
+    root_context.blocking_add_interceptor(ErrorOnceInterceptor::default());
+
+    // Failed construction not cache singleton
+    assert_eq!(root_context.blocking_resolve::<Arc<SingletonDependency1>>().err(), Some(BuildDependencyError::Custom { err: anyhow::anyhow!("forbidden") }));
+    let singleton = root_context.blocking_resolve::<Arc<SingletonDependency1>>().unwrap();
+    assert!(Arc::ptr_eq(&singleton, &root_context.blocking_resolve::<Arc<SingletonDependency1>>().unwrap()));
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::ContextDependent).unwrap();
+
+    root_context.blocking_add_interceptor(ErrorOnceInterceptor::default());
+
+    assert_eq!(root_context.blocking_resolve::<Weak<TransientDependency1>>().err(), Some(BuildDependencyError::Custom { err: anyhow::anyhow!("forbidden") }));
+    let context_dependent = root_context.blocking_resolve::<Weak<TransientDependency1>>().unwrap();
+    assert!(Weak::ptr_eq(&context_dependent, &root_context.blocking_resolve::<Weak<TransientDependency1>>().unwrap()));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn resolve_interceptor_invalid_instance_sync() {
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+
+    root_context.blocking_add_interceptor(InvalidInterceptor {});
+
+    assert_eq!(root_context.blocking_resolve::<TransientDependency1>().err(), Some(BuildDependencyError::InvalidInterceptedInstance {
+        component_type_info: TypeInfo::from_type::<TransientDependency1>(),
+    }));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn resolve_interceptor_short_circuit_sync() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+
+    root_context.blocking_add_interceptor(AuditInterceptor { name: "first".to_string(), log: log.clone() });
+    root_context.blocking_add_interceptor(SubstituteInterceptor {});
+    root_context.blocking_add_interceptor(AuditInterceptor { name: "second".to_string(), log: log.clone() });
+
+    assert_eq!(root_context.blocking_resolve::<TransientDependency1>().unwrap().str, "substitute".to_string());
+
+    let transient_info = ResolveInfo {
+        component_type_info: TypeInfo::from_type::<TransientDependency1>(),
+        life_cycle: LifeCycle::Transient,
+        parent_type_info: None,
+    };
+
+    // Interceptors after substitute not called
+    assert_eq!(*log.lock().unwrap(), vec![
+        ("first before".to_string(), transient_info.clone()),
+        ("first after".to_string(), transient_info),
+    ]);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn resolve_interceptor_with_args_sync() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_closure_with_args(|_, str: String| Ok(TransientDependency1 { str })).unwrap();
+
+    root_context.blocking_add_interceptor(AuditInterceptor { name: "first".to_string(), log: log.clone() });
+
+    assert_eq!(root_context.blocking_resolve_with::<TransientDependency1, String>("args".to_string()).unwrap().str, "args".to_string());
+
+    let transient_info = ResolveInfo {
+        component_type_info: TypeInfo::from_type::<TransientDependency1>(),
+        life_cycle: LifeCycle::Transient,
+        parent_type_info: None,
+    };
+
+    assert_eq!(*log.lock().unwrap(), vec![
+        ("first before".to_string(), transient_info.clone()),
+        ("first after".to_string(), transient_info),
+    ]);
+
+    // Substitute interceptor replace ctor with args too
+    root_context.blocking_add_interceptor(SubstituteInterceptor {});
+    assert_eq!(root_context.blocking_resolve_with::<TransientDependency1, String>("args".to_string()).unwrap().str, "substitute".to_string());
+}
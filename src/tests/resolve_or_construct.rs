@@ -0,0 +1,238 @@
+use std::sync::Arc;
+
+use crate::{
+    Constructor,
+    types::BuildDependencyResult
+};
+
+struct Leaf {}
+
+struct SharedLeaf {}
+
+#[allow(dead_code)]
+struct Root {
+    leaf: Leaf,
+    shared_leaf: Arc<SharedLeaf>,
+}
+
+#[allow(dead_code)]
+struct PlainRoot {
+    leaf: Leaf,
+    shared_leaf: Arc<SharedLeaf>,
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for Leaf {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for Leaf {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for SharedLeaf {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for SharedLeaf {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for Root {
+    fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { leaf: ctx.resolve_or_construct()?, shared_leaf: ctx.resolve_or_construct()? })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for Root {
+    async fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { leaf: ctx.resolve_or_construct().await?, shared_leaf: ctx.resolve_or_construct().await? })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for PlainRoot {
+    fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { leaf: ctx.resolve()?, shared_leaf: ctx.resolve()? })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for PlainRoot {
+    async fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { leaf: ctx.resolve().await?, shared_leaf: ctx.resolve().await? })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn resolve_or_construct() {
+    use crate::DependencyContext;
+
+    let root_context = DependencyContext::new_root();
+
+    // Root and nested components registered on demand
+    let root = root_context.resolve_or_construct::<Root>().unwrap();
+    assert!(root_context.is_component_exist::<Root>());
+    assert!(root_context.is_component_exist::<Leaf>());
+
+    // Life cycle chosen by service type
+    let shared_leaf = root_context.resolve::<Arc<SharedLeaf>>().unwrap();
+    assert!(Arc::ptr_eq(&shared_leaf, &root.shared_leaf));
+    assert!(Arc::ptr_eq(&shared_leaf, &root_context.resolve_or_construct::<Arc<SharedLeaf>>().unwrap()));
+
+    // Registered component resolved as usual
+    assert!(root_context.resolve_or_construct::<Leaf>().is_ok());
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn resolve_or_construct() {
+    use crate::DependencyContext;
+
+    let root_context = DependencyContext::new_root();
+
+    // Root and nested components registered on demand
+    let root = root_context.resolve_or_construct::<Root>().await.unwrap();
+    assert!(root_context.is_component_exist::<Root>().await);
+    assert!(root_context.is_component_exist::<Leaf>().await);
+
+    // Life cycle chosen by service type
+    let shared_leaf = root_context.resolve::<Arc<SharedLeaf>>().await.unwrap();
+    assert!(Arc::ptr_eq(&shared_leaf, &root.shared_leaf));
+    assert!(Arc::ptr_eq(&shared_leaf, &root_context.resolve_or_construct::<Arc<SharedLeaf>>().await.unwrap()));
+
+    // Registered component resolved as usual
+    assert!(root_context.resolve_or_construct::<Leaf>().await.is_ok());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn resolve_or_construct_sync() {
+    use crate::DependencyContext;
+
+    let root_context = DependencyContext::new_root();
+
+    // Root and nested components registered on demand
+    let root = root_context.blocking_resolve_or_construct::<Root>().unwrap();
+    assert!(root_context.blocking_is_component_exist::<Root>());
+    assert!(root_context.blocking_is_component_exist::<Leaf>());
+
+    // Life cycle chosen by service type
+    let shared_leaf = root_context.blocking_resolve::<Arc<SharedLeaf>>().unwrap();
+    assert!(Arc::ptr_eq(&shared_leaf, &root.shared_leaf));
+    assert!(Arc::ptr_eq(&shared_leaf, &root_context.blocking_resolve_or_construct::<Arc<SharedLeaf>>().unwrap()));
+
+    // Registered component resolved as usual
+    assert!(root_context.blocking_resolve_or_construct::<Leaf>().is_ok());
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn auto_wired_life_cycle() {
+    use crate::{
+        ContextSettings,
+        DependencyContext,
+        LifeCycle,
+        types::{AddDependencyError, BuildDependencyError, TypeInfo},
+    };
+
+    // Plain resolve don't register components on demand
+    let root_context = DependencyContext::new_root();
+    let result = root_context.resolve_or_construct::<PlainRoot>();
+    assert_eq!(result.err(), Some(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Leaf>() }));
+    assert!(root_context.try_resolve::<Leaf>().unwrap().is_none());
+    assert!(!root_context.is_component_exist::<Leaf>());
+
+    // Life cycle restricted by settings
+    let root_context = DependencyContext::new_root_with_settings(ContextSettings { auto_wired_life_cycle: Some(LifeCycle::Singleton), ..Default::default() });
+    let leaf = root_context.resolve_or_construct::<Arc<Leaf>>().unwrap();
+    assert!(Arc::ptr_eq(&leaf, &root_context.resolve::<Arc<Leaf>>().unwrap()));
+
+    let result = root_context.resolve_or_construct::<SharedLeaf>();
+    assert_eq!(result.err(), Some(BuildDependencyError::AddDependencyError { err: AddDependencyError::LifeCycleMismatch {
+        component_type_info: TypeInfo::from_type::<SharedLeaf>(),
+        expected_life_cycle: LifeCycle::Singleton,
+        found_life_cycle: LifeCycle::Transient,
+    } }));
+    assert!(!root_context.is_component_exist::<SharedLeaf>());
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn auto_wired_life_cycle() {
+    use crate::{
+        ContextSettings,
+        DependencyContext,
+        LifeCycle,
+        types::{AddDependencyError, BuildDependencyError, TypeInfo},
+    };
+
+    // Plain resolve don't register components on demand
+    let root_context = DependencyContext::new_root();
+    let result = root_context.resolve_or_construct::<PlainRoot>().await;
+    assert_eq!(result.err(), Some(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Leaf>() }));
+    assert!(root_context.try_resolve::<Leaf>().await.unwrap().is_none());
+    assert!(!root_context.is_component_exist::<Leaf>().await);
+
+    // Life cycle restricted by settings
+    let root_context = DependencyContext::new_root_with_settings(ContextSettings { auto_wired_life_cycle: Some(LifeCycle::Singleton), ..Default::default() });
+    let leaf = root_context.resolve_or_construct::<Arc<Leaf>>().await.unwrap();
+    assert!(Arc::ptr_eq(&leaf, &root_context.resolve::<Arc<Leaf>>().await.unwrap()));
+
+    let result = root_context.resolve_or_construct::<SharedLeaf>().await;
+    assert_eq!(result.err(), Some(BuildDependencyError::AddDependencyError { err: AddDependencyError::LifeCycleMismatch {
+        component_type_info: TypeInfo::from_type::<SharedLeaf>(),
+        expected_life_cycle: LifeCycle::Singleton,
+        found_life_cycle: LifeCycle::Transient,
+    } }));
+    assert!(!root_context.is_component_exist::<SharedLeaf>().await);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn auto_wired_life_cycle_sync() {
+    use crate::{
+        ContextSettings,
+        DependencyContext,
+        LifeCycle,
+        types::{AddDependencyError, BuildDependencyError, TypeInfo},
+    };
+
+    // Plain resolve don't register components on demand
+    let root_context = DependencyContext::new_root();
+    let result = root_context.blocking_resolve_or_construct::<PlainRoot>();
+    assert_eq!(result.err(), Some(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Leaf>() }));
+    assert!(root_context.blocking_try_resolve::<Leaf>().unwrap().is_none());
+    assert!(!root_context.blocking_is_component_exist::<Leaf>());
+
+    // Life cycle restricted by settings
+    let root_context = DependencyContext::new_root_with_settings(ContextSettings { auto_wired_life_cycle: Some(LifeCycle::Singleton), ..Default::default() });
+    let leaf = root_context.blocking_resolve_or_construct::<Arc<Leaf>>().unwrap();
+    assert!(Arc::ptr_eq(&leaf, &root_context.blocking_resolve::<Arc<Leaf>>().unwrap()));
+
+    let result = root_context.blocking_resolve_or_construct::<SharedLeaf>();
+    assert_eq!(result.err(), Some(BuildDependencyError::AddDependencyError { err: AddDependencyError::LifeCycleMismatch {
+        component_type_info: TypeInfo::from_type::<SharedLeaf>(),
+        expected_life_cycle: LifeCycle::Singleton,
+        found_life_cycle: LifeCycle::Transient,
+    } }));
+    assert!(!root_context.blocking_is_component_exist::<SharedLeaf>());
+}
@@ -0,0 +1,403 @@
+use crate::{
+    Constructor,
+    ConstructorWith,
+    types::BuildDependencyResult
+};
+
+#[allow(dead_code)]
+struct TransientDependency {
+    pub str: String,
+}
+
+#[allow(dead_code)]
+struct TenantDependency {
+    pub tenant_id: u64,
+}
+
+#[allow(dead_code)]
+struct ReportDependency {
+    pub str: String,
+}
+
+struct AuditReportDependency {
+    pub str: String,
+}
+
+// Resolve tenant without args from ctor
+#[allow(dead_code)]
+struct TenantOwner {
+    pub tenant: TenantDependency,
+}
+
+// Resolve report with args from ctor
+struct ReportOwner {
+    pub report: Box<dyn GetStr>,
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for TransientDependency {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test".to_string() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for TransientDependency {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test".to_string() })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl ConstructorWith<u64> for TenantDependency {
+    fn ctor(_: crate::DependencyContext, tenant_id: u64) ->  BuildDependencyResult<Self> {
+        Ok(Self { tenant_id })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl ConstructorWith<u64> for TenantDependency {
+    async fn ctor(_: crate::DependencyContext, tenant_id: u64) ->  BuildDependencyResult<Self> {
+        Ok(Self { tenant_id })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for TenantOwner {
+    fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { tenant: ctx.resolve()? })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for TenantOwner {
+    async fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { tenant: ctx.resolve().await? })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for ReportOwner {
+    fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { report: ctx.resolve_with((3u64, "2023".to_string()))? })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for ReportOwner {
+    async fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { report: ctx.resolve_with((3u64, "2023".to_string())).await? })
+    }
+}
+
+trait GetStr: Sync + Send {
+    fn get(&self) -> String;
+}
+
+impl GetStr for ReportDependency {
+    fn get(&self) -> String {
+        self.str.clone()
+    }
+}
+
+impl GetStr for AuditReportDependency {
+    fn get(&self) -> String {
+        self.str.clone()
+    }
+}
+
+trait NamedReport: GetStr {}
+
+impl NamedReport for ReportDependency {}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn resolve_with_args() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{BuildDependencyError, TypeInfo},
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency>(LifeCycle::Transient).unwrap();
+    root_context.register_type_with_args::<TenantDependency, u64>().unwrap();
+    root_context.register_closure_with_args(|_ctx, (tenant_id, period): (u64, String)| Ok(ReportDependency { str: format!("{tenant_id} {period}") })).unwrap()
+        .map_as::<dyn GetStr>().unwrap();
+
+    assert_eq!(root_context.resolve_with::<TenantDependency, u64>(1).unwrap().tenant_id, 1);
+    assert_eq!(root_context.resolve_with::<Box<dyn GetStr>, (u64, String)>((2, "2022".to_string())).unwrap().get(), "2 2022".to_string());
+
+    let result = root_context.resolve_with::<TenantDependency, u32>(1);
+    assert_eq!(result.err(), Some(BuildDependencyError::ArgsTypeMismatch {
+        component_type_info: TypeInfo::from_type::<TenantDependency>(),
+        expected_args_type_info: Some(TypeInfo::from_type::<u64>()),
+        found_args_type_info: Some(TypeInfo::from_type::<u32>()),
+    }));
+
+    let result = root_context.resolve::<TenantDependency>();
+    assert_eq!(result.err(), Some(BuildDependencyError::ArgsTypeMismatch {
+        component_type_info: TypeInfo::from_type::<TenantDependency>(),
+        expected_args_type_info: Some(TypeInfo::from_type::<u64>()),
+        found_args_type_info: None,
+    }));
+
+    let result = root_context.resolve_with::<TransientDependency, u64>(1);
+    assert_eq!(result.err(), Some(BuildDependencyError::ArgsTypeMismatch {
+        component_type_info: TypeInfo::from_type::<TransientDependency>(),
+        expected_args_type_info: None,
+        found_args_type_info: Some(TypeInfo::from_type::<u64>()),
+    }));
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn resolve_with_args_mismatch_in_singleton() {
+    use std::sync::Arc;
+
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{BuildDependencyError, TypeInfo},
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type_with_args::<TenantDependency, u64>().unwrap();
+    root_context.register_type::<TenantOwner>(LifeCycle::Singleton).unwrap();
+
+    let expected_err = Some(BuildDependencyError::ArgsTypeMismatch {
+        component_type_info: TypeInfo::from_type::<TenantDependency>(),
+        expected_args_type_info: Some(TypeInfo::from_type::<u64>()),
+        found_args_type_info: None,
+    });
+
+    // Failed singleton construction not cached, so second resolve fails the same way
+    assert_eq!(root_context.resolve::<Arc<TenantOwner>>().err(), expected_err);
+    assert_eq!(root_context.resolve::<Arc<TenantOwner>>().err(), expected_err);
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn resolve_with_args_alias_and_binding() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_closure_with_args(|_ctx, (tenant_id, period): (u64, String)| Ok(ReportDependency { str: format!("{tenant_id} {period}") })).unwrap()
+        .map_as::<dyn GetStr>().unwrap();
+    root_context.register_closure_with_args(|_ctx, (tenant_id, period): (u64, String)| Ok(AuditReportDependency { str: format!("audit {tenant_id} {period}") })).unwrap()
+        .map_as::<dyn GetStr>().unwrap();
+    root_context.register_type::<ReportOwner>(LifeCycle::Transient).unwrap();
+    root_context.when_injected_into::<ReportOwner>()
+        .map::<dyn GetStr, AuditReportDependency>();
+
+    // Contextual binding choose component for parent
+    assert_eq!(root_context.resolve_with::<Box<dyn GetStr>, (u64, String)>((2, "2022".to_string())).unwrap().get(), "2 2022".to_string());
+    assert_eq!(root_context.resolve::<ReportOwner>().unwrap().report.get(), "audit 3 2023".to_string());
+
+    // Alias resolve target with same args
+    let root_context = DependencyContext::new_root();
+    root_context.register_closure_with_args(|_ctx, (tenant_id, period): (u64, String)| Ok(ReportDependency { str: format!("{tenant_id} {period}") })).unwrap()
+        .map_as::<dyn NamedReport>().unwrap();
+    root_context.alias::<Box<dyn GetStr>, Box<dyn NamedReport>>().unwrap();
+
+    assert_eq!(root_context.resolve_with::<Box<dyn GetStr>, (u64, String)>((4, "2024".to_string())).unwrap().get(), "4 2024".to_string());
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn resolve_with_args() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{BuildDependencyError, TypeInfo},
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<TransientDependency>(LifeCycle::Transient).await.unwrap();
+    root_context.register_type_with_args::<TenantDependency, u64>().await.unwrap();
+    root_context.register_closure_with_args(|_ctx, (tenant_id, period): (u64, String)| Ok(ReportDependency { str: format!("{tenant_id} {period}") })).await.unwrap()
+        .map_as::<dyn GetStr>().await.unwrap();
+
+    assert_eq!(root_context.resolve_with::<TenantDependency, u64>(1).await.unwrap().tenant_id, 1);
+    assert_eq!(root_context.resolve_with::<Box<dyn GetStr>, (u64, String)>((2, "2022".to_string())).await.unwrap().get(), "2 2022".to_string());
+
+    let result = root_context.resolve_with::<TenantDependency, u32>(1).await;
+    assert_eq!(result.err(), Some(BuildDependencyError::ArgsTypeMismatch {
+        component_type_info: TypeInfo::from_type::<TenantDependency>(),
+        expected_args_type_info: Some(TypeInfo::from_type::<u64>()),
+        found_args_type_info: Some(TypeInfo::from_type::<u32>()),
+    }));
+
+    let result = root_context.resolve::<TenantDependency>().await;
+    assert_eq!(result.err(), Some(BuildDependencyError::ArgsTypeMismatch {
+        component_type_info: TypeInfo::from_type::<TenantDependency>(),
+        expected_args_type_info: Some(TypeInfo::from_type::<u64>()),
+        found_args_type_info: None,
+    }));
+
+    let result = root_context.resolve_with::<TransientDependency, u64>(1).await;
+    assert_eq!(result.err(), Some(BuildDependencyError::ArgsTypeMismatch {
+        component_type_info: TypeInfo::from_type::<TransientDependency>(),
+        expected_args_type_info: None,
+        found_args_type_info: Some(TypeInfo::from_type::<u64>()),
+    }));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn resolve_with_args_mismatch_in_singleton() {
+    use std::sync::Arc;
+
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{BuildDependencyError, TypeInfo},
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_type_with_args::<TenantDependency, u64>().await.unwrap();
+    root_context.register_type::<TenantOwner>(LifeCycle::Singleton).await.unwrap();
+
+    let expected_err = Some(BuildDependencyError::ArgsTypeMismatch {
+        component_type_info: TypeInfo::from_type::<TenantDependency>(),
+        expected_args_type_info: Some(TypeInfo::from_type::<u64>()),
+        found_args_type_info: None,
+    });
+
+    // Failed singleton construction not cached, so second resolve fails the same way
+    assert_eq!(root_context.resolve::<Arc<TenantOwner>>().await.err(), expected_err);
+    assert_eq!(root_context.resolve::<Arc<TenantOwner>>().await.err(), expected_err);
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn resolve_with_args_alias_and_binding() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.register_closure_with_args(|_ctx, (tenant_id, period): (u64, String)| Ok(ReportDependency { str: format!("{tenant_id} {period}") })).await.unwrap()
+        .map_as::<dyn GetStr>().await.unwrap();
+    root_context.register_closure_with_args(|_ctx, (tenant_id, period): (u64, String)| Ok(AuditReportDependency { str: format!("audit {tenant_id} {period}") })).await.unwrap()
+        .map_as::<dyn GetStr>().await.unwrap();
+    root_context.register_type::<ReportOwner>(LifeCycle::Transient).await.unwrap();
+    root_context.when_injected_into::<ReportOwner>()
+        .map::<dyn GetStr, AuditReportDependency>().await;
+
+    // Contextual binding choose component for parent
+    assert_eq!(root_context.resolve_with::<Box<dyn GetStr>, (u64, String)>((2, "2022".to_string())).await.unwrap().get(), "2 2022".to_string());
+    assert_eq!(root_context.resolve::<ReportOwner>().await.unwrap().report.get(), "audit 3 2023".to_string());
+
+    // Alias resolve target with same args
+    let root_context = DependencyContext::new_root();
+    root_context.register_closure_with_args(|_ctx, (tenant_id, period): (u64, String)| Ok(ReportDependency { str: format!("{tenant_id} {period}") })).await.unwrap()
+        .map_as::<dyn NamedReport>().await.unwrap();
+    root_context.alias::<Box<dyn GetStr>, Box<dyn NamedReport>>().await.unwrap();
+
+    assert_eq!(root_context.resolve_with::<Box<dyn GetStr>, (u64, String)>((4, "2024".to_string())).await.unwrap().get(), "4 2024".to_string());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn resolve_with_args_sync() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{BuildDependencyError, TypeInfo},
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<TransientDependency>(LifeCycle::Transient).unwrap();
+    root_context.blocking_register_type_with_args::<TenantDependency, u64>().unwrap();
+    root_context.blocking_register_closure_with_args(|_ctx, (tenant_id, period): (u64, String)| Ok(ReportDependency { str: format!("{tenant_id} {period}") })).unwrap()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+
+    assert_eq!(root_context.blocking_resolve_with::<TenantDependency, u64>(1).unwrap().tenant_id, 1);
+    assert_eq!(root_context.blocking_resolve_with::<Box<dyn GetStr>, (u64, String)>((2, "2022".to_string())).unwrap().get(), "2 2022".to_string());
+
+    let result = root_context.blocking_resolve_with::<TenantDependency, u32>(1);
+    assert_eq!(result.err(), Some(BuildDependencyError::ArgsTypeMismatch {
+        component_type_info: TypeInfo::from_type::<TenantDependency>(),
+        expected_args_type_info: Some(TypeInfo::from_type::<u64>()),
+        found_args_type_info: Some(TypeInfo::from_type::<u32>()),
+    }));
+
+    let result = root_context.blocking_resolve::<TenantDependency>();
+    assert_eq!(result.err(), Some(BuildDependencyError::ArgsTypeMismatch {
+        component_type_info: TypeInfo::from_type::<TenantDependency>(),
+        expected_args_type_info: Some(TypeInfo::from_type::<u64>()),
+        found_args_type_info: None,
+    }));
+
+    let result = root_context.blocking_resolve_with::<TransientDependency, u64>(1);
+    assert_eq!(result.err(), Some(BuildDependencyError::ArgsTypeMismatch {
+        component_type_info: TypeInfo::from_type::<TransientDependency>(),
+        expected_args_type_info: None,
+        found_args_type_info: Some(TypeInfo::from_type::<u64>()),
+    }));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn resolve_with_args_mismatch_in_singleton_sync() {
+    use std::sync::Arc;
+
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{BuildDependencyError, TypeInfo},
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type_with_args::<TenantDependency, u64>().unwrap();
+    root_context.blocking_register_type::<TenantOwner>(LifeCycle::Singleton).unwrap();
+
+    let expected_err = Some(BuildDependencyError::ArgsTypeMismatch {
+        component_type_info: TypeInfo::from_type::<TenantDependency>(),
+        expected_args_type_info: Some(TypeInfo::from_type::<u64>()),
+        found_args_type_info: None,
+    });
+
+    // Failed singleton construction not cached, so second resolve fails the same way
+    assert_eq!(root_context.blocking_resolve::<Arc<TenantOwner>>().err(), expected_err);
+    assert_eq!(root_context.blocking_resolve::<Arc<TenantOwner>>().err(), expected_err);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn resolve_with_args_alias_and_binding_sync() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_closure_with_args(|_ctx, (tenant_id, period): (u64, String)| Ok(ReportDependency { str: format!("{tenant_id} {period}") })).unwrap()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+    root_context.blocking_register_closure_with_args(|_ctx, (tenant_id, period): (u64, String)| Ok(AuditReportDependency { str: format!("audit {tenant_id} {period}") })).unwrap()
+        .blocking_map_as::<dyn GetStr>().unwrap();
+    root_context.blocking_register_type::<ReportOwner>(LifeCycle::Transient).unwrap();
+    root_context.when_injected_into::<ReportOwner>()
+        .blocking_map::<dyn GetStr, AuditReportDependency>();
+
+    // Contextual binding choose component for parent
+    assert_eq!(root_context.blocking_resolve_with::<Box<dyn GetStr>, (u64, String)>((2, "2022".to_string())).unwrap().get(), "2 2022".to_string());
+    assert_eq!(root_context.blocking_resolve::<ReportOwner>().unwrap().report.get(), "audit 3 2023".to_string());
+
+    // Alias resolve target with same args
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_closure_with_args(|_ctx, (tenant_id, period): (u64, String)| Ok(ReportDependency { str: format!("{tenant_id} {period}") })).unwrap()
+        .blocking_map_as::<dyn NamedReport>().unwrap();
+    root_context.blocking_alias::<Box<dyn GetStr>, Box<dyn NamedReport>>().unwrap();
+
+    assert_eq!(root_context.blocking_resolve_with::<Box<dyn GetStr>, (u64, String)>((4, "2024".to_string())).unwrap().get(), "4 2024".to_string());
+}
@@ -0,0 +1,175 @@
+use std::sync::Arc;
+
+use crate::{
+    Constructor,
+    types::BuildDependencyResult
+};
+
+struct PostgresStore {}
+
+struct MemoryStore {}
+
+trait LegacyStore: Sync + Send {
+    fn legacy_name(&self) -> String;
+}
+
+trait Store: LegacyStore {
+    fn name(&self) -> String;
+}
+
+impl LegacyStore for PostgresStore {
+    fn legacy_name(&self) -> String { self.name() }
+}
+
+impl Store for PostgresStore {
+    fn name(&self) -> String { "postgres".to_string() }
+}
+
+impl LegacyStore for MemoryStore {
+    fn legacy_name(&self) -> String { self.name() }
+}
+
+impl Store for MemoryStore {
+    fn name(&self) -> String { "memory".to_string() }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for PostgresStore {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for PostgresStore {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for MemoryStore {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for MemoryStore {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn service_alias() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{
+            AddDependencyError,
+            TypeInfo,
+        },
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.alias::<Arc<dyn LegacyStore>, Arc<dyn Store>>().unwrap();
+
+    // Alias exist only with target
+    assert!(!root_context.is_service_exist::<Arc<dyn LegacyStore>>());
+    assert!(root_context.try_resolve::<Arc<dyn LegacyStore>>().unwrap().is_none());
+
+    root_context.register_type::<PostgresStore>(LifeCycle::Singleton).unwrap()
+        .map_as::<dyn Store>().unwrap();
+
+    assert!(root_context.is_service_exist::<Arc<dyn LegacyStore>>());
+    assert_eq!(root_context.resolve::<Arc<dyn LegacyStore>>().unwrap().legacy_name(), "postgres".to_string());
+    assert_eq!(root_context.resolve_collection::<Arc<dyn LegacyStore>>().unwrap().len(), 1);
+
+    // Alias follow replaced target
+    root_context.delete_component::<PostgresStore>().unwrap();
+    root_context.register_type::<MemoryStore>(LifeCycle::Singleton).unwrap()
+        .map_as::<dyn Store>().unwrap();
+
+    assert_eq!(root_context.resolve::<Arc<dyn LegacyStore>>().unwrap().legacy_name(), "memory".to_string());
+
+    let result = root_context.alias::<Arc<dyn LegacyStore>, Arc<dyn Store>>();
+    assert_eq!(result.err(), Some(AddDependencyError::AliasExist { alias_type_info: TypeInfo::from_type::<Arc<dyn LegacyStore>>() }));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn service_alias() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{
+            AddDependencyError,
+            TypeInfo,
+        },
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.alias::<Arc<dyn LegacyStore>, Arc<dyn Store>>().await.unwrap();
+
+    // Alias exist only with target
+    assert!(!root_context.is_service_exist::<Arc<dyn LegacyStore>>().await);
+    assert!(root_context.try_resolve::<Arc<dyn LegacyStore>>().await.unwrap().is_none());
+
+    root_context.register_type::<PostgresStore>(LifeCycle::Singleton).await.unwrap()
+        .map_as::<dyn Store>().await.unwrap();
+
+    assert!(root_context.is_service_exist::<Arc<dyn LegacyStore>>().await);
+    assert_eq!(root_context.resolve::<Arc<dyn LegacyStore>>().await.unwrap().legacy_name(), "postgres".to_string());
+    assert_eq!(root_context.resolve_collection::<Arc<dyn LegacyStore>>().await.unwrap().len(), 1);
+
+    // Alias follow replaced target
+    root_context.delete_component::<PostgresStore>().await.unwrap();
+    root_context.register_type::<MemoryStore>(LifeCycle::Singleton).await.unwrap()
+        .map_as::<dyn Store>().await.unwrap();
+
+    assert_eq!(root_context.resolve::<Arc<dyn LegacyStore>>().await.unwrap().legacy_name(), "memory".to_string());
+
+    let result = root_context.alias::<Arc<dyn LegacyStore>, Arc<dyn Store>>().await;
+    assert_eq!(result.err(), Some(AddDependencyError::AliasExist { alias_type_info: TypeInfo::from_type::<Arc<dyn LegacyStore>>() }));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn service_alias_sync() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{
+            AddDependencyError,
+            TypeInfo,
+        },
+    };
+
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_alias::<Arc<dyn LegacyStore>, Arc<dyn Store>>().unwrap();
+
+    // Alias exist only with target
+    assert!(!root_context.blocking_is_service_exist::<Arc<dyn LegacyStore>>());
+    assert!(root_context.blocking_try_resolve::<Arc<dyn LegacyStore>>().unwrap().is_none());
+
+    root_context.blocking_register_type::<PostgresStore>(LifeCycle::Singleton).unwrap()
+        .blocking_map_as::<dyn Store>().unwrap();
+
+    assert!(root_context.blocking_is_service_exist::<Arc<dyn LegacyStore>>());
+    assert_eq!(root_context.blocking_resolve::<Arc<dyn LegacyStore>>().unwrap().legacy_name(), "postgres".to_string());
+    assert_eq!(root_context.blocking_resolve_collection::<Arc<dyn LegacyStore>>().unwrap().len(), 1);
+
+    // Alias follow replaced target
+    root_context.blocking_delete_component::<PostgresStore>().unwrap();
+    root_context.blocking_register_type::<MemoryStore>(LifeCycle::Singleton).unwrap()
+        .blocking_map_as::<dyn Store>().unwrap();
+
+    assert_eq!(root_context.blocking_resolve::<Arc<dyn LegacyStore>>().unwrap().legacy_name(), "memory".to_string());
+
+    let result = root_context.blocking_alias::<Arc<dyn LegacyStore>, Arc<dyn Store>>();
+    assert_eq!(result.err(), Some(AddDependencyError::AliasExist { alias_type_info: TypeInfo::from_type::<Arc<dyn LegacyStore>>() }));
+}
@@ -0,0 +1,380 @@
+use crate::{
+    Constructor,
+    DependencyContext,
+    LifeCycle,
+    ServiceInfo,
+    types::{
+        BuildDependencyResult,
+        DeleteComponentError,
+        TypeInfo,
+    },
+};
+
+struct HttpHandler1 {}
+
+struct HttpHandler2 {}
+
+struct QueueHandler {}
+
+// Resolve filtered handlers from ctor
+struct HandlerOwner {
+    pub http_handlers: Vec<Box<dyn Handler>>,
+    pub queue_handlers: Vec<Box<dyn Handler>>,
+}
+
+trait LegacyHandler: Sync + Send {}
+
+trait Handler: LegacyHandler {
+    fn name(&self) -> String;
+}
+
+impl LegacyHandler for HttpHandler1 {}
+
+impl LegacyHandler for HttpHandler2 {}
+
+impl LegacyHandler for QueueHandler {}
+
+impl Handler for HttpHandler1 {
+    fn name(&self) -> String { "http1".to_string() }
+}
+
+impl Handler for HttpHandler2 {
+    fn name(&self) -> String { "http2".to_string() }
+}
+
+impl Handler for QueueHandler {
+    fn name(&self) -> String { "queue".to_string() }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for HttpHandler1 {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for HttpHandler1 {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for HttpHandler2 {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for HttpHandler2 {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for QueueHandler {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for QueueHandler {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for HandlerOwner {
+    fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {
+            http_handlers: ctx.resolve_collection_where(|metadata| metadata.tag("http"))?,
+            queue_handlers: ctx.resolve_collection_where(|metadata| metadata.tag("queue"))?,
+        })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for HandlerOwner {
+    async fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self {
+            http_handlers: ctx.resolve_collection_where(|metadata| metadata.tag("http")).await?,
+            queue_handlers: ctx.resolve_collection_where(|metadata| metadata.tag("queue")).await?,
+        })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn service_metadata() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<HttpHandler1>(LifeCycle::Transient).unwrap()
+        .with_tag("http")
+        .with_metadata("route", "/users")
+        .map_as::<dyn Handler>().unwrap();
+    root_context.register_type::<HttpHandler2>(LifeCycle::Singleton).unwrap()
+        .with_tag("http")
+        .with_metadata("route", "/orders")
+        .map_as::<dyn Handler>().unwrap();
+    root_context.register_type::<QueueHandler>(LifeCycle::Transient).unwrap()
+        .with_tag("queue")
+        .map_as::<dyn Handler>().unwrap();
+
+    let http_handlers = root_context.resolve_collection_where::<Box<dyn Handler>, _>(|metadata| metadata.tag("http")).unwrap();
+    assert_eq!(http_handlers.iter().map(|handler| handler.name()).collect::<Vec<_>>(), vec!["http1".to_string()]);
+
+    let http_handlers = root_context.resolve_collection_where::<std::sync::Arc<dyn Handler>, _>(|metadata| metadata.value("route") == Some("/orders")).unwrap();
+    assert_eq!(http_handlers.iter().map(|handler| handler.name()).collect::<Vec<_>>(), vec!["http2".to_string()]);
+
+    assert!(root_context.resolve_collection_where::<Box<dyn Handler>, _>(|metadata| metadata.tag("grpc")).unwrap().is_empty());
+
+    let handlers_info = root_context.get_collection_metadata::<Box<dyn Handler>>();
+    assert_eq!(handlers_info.len(), 2);
+    assert_eq!(handlers_info[0].component_type_info, TypeInfo::from_type::<HttpHandler1>());
+    assert_eq!(handlers_info[0].metadata.tags(), &["http".to_string()]);
+    assert_eq!(handlers_info[0].metadata.value("route"), Some("/users"));
+    assert_eq!(handlers_info[1].component_type_info, TypeInfo::from_type::<QueueHandler>());
+    assert!(handlers_info[1].metadata.tag("queue"));
+    assert_eq!(handlers_info[1].metadata.value("route"), None);
+
+    assert_eq!(root_context.get_collection_metadata::<Box<dyn std::any::Any + Sync + Send>>(), Vec::<ServiceInfo>::new());
+
+    let mut deleted_components = root_context.delete_components_with_tag("http").unwrap();
+    deleted_components.sort_by(|l, r| l.type_name.cmp(&r.type_name));
+    assert_eq!(deleted_components, vec![TypeInfo::from_type::<HttpHandler1>(), TypeInfo::from_type::<HttpHandler2>()]);
+
+    let handlers = root_context.resolve_collection::<Box<dyn Handler>>().unwrap();
+    assert_eq!(handlers.iter().map(|handler| handler.name()).collect::<Vec<_>>(), vec!["queue".to_string()]);
+    assert!(root_context.resolve::<HttpHandler1>().is_err());
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn service_metadata_delete_not_supported() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<HttpHandler1>(LifeCycle::Transient).unwrap()
+        .with_tag("http")
+        .map_as::<dyn Handler>().unwrap();
+    root_context.register_type::<HttpHandler2>(LifeCycle::ContextDependent).unwrap()
+        .with_tag("http")
+        .map_as::<dyn Handler>().unwrap();
+
+    assert_eq!(root_context.delete_components_with_tag("http"), Err(DeleteComponentError::NotSupportedLifeCycle {
+        component_type_info: TypeInfo::from_type::<HttpHandler2>(),
+        life_cycle: LifeCycle::ContextDependent,
+    }));
+
+    // Nothing deleted
+    assert_eq!(root_context.resolve_collection::<Box<dyn Handler>>().unwrap().len(), 1);
+}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn service_metadata_alias_and_binding() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<HttpHandler1>(LifeCycle::Transient).unwrap()
+        .with_tag("http")
+        .map_as::<dyn Handler>().unwrap();
+    root_context.register_type::<QueueHandler>(LifeCycle::Transient).unwrap()
+        .with_tag("queue")
+        .map_as::<dyn Handler>().unwrap();
+    root_context.register_type::<HandlerOwner>(LifeCycle::Transient).unwrap();
+    root_context.alias::<Box<dyn LegacyHandler>, Box<dyn Handler>>().unwrap();
+    root_context.when_injected_into::<HandlerOwner>()
+        .map::<dyn Handler, QueueHandler>();
+
+    // Alias filtered by target metadata
+    assert_eq!(root_context.resolve_collection_where::<Box<dyn LegacyHandler>, _>(|metadata| metadata.tag("http")).unwrap().len(), 1);
+    assert!(root_context.resolve_collection_where::<Box<dyn LegacyHandler>, _>(|metadata| metadata.tag("grpc")).unwrap().is_empty());
+
+    // Contextual binding leave only bound component for parent
+    let owner = root_context.resolve::<HandlerOwner>().unwrap();
+    assert!(owner.http_handlers.is_empty());
+    assert_eq!(owner.queue_handlers.iter().map(|handler| handler.name()).collect::<Vec<_>>(), vec!["queue".to_string()]);
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn service_metadata() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<HttpHandler1>(LifeCycle::Transient).await.unwrap()
+        .with_tag("http")
+        .with_metadata("route", "/users")
+        .map_as::<dyn Handler>().await.unwrap();
+    root_context.register_type::<HttpHandler2>(LifeCycle::Singleton).await.unwrap()
+        .with_tag("http")
+        .with_metadata("route", "/orders")
+        .map_as::<dyn Handler>().await.unwrap();
+    root_context.register_type::<QueueHandler>(LifeCycle::Transient).await.unwrap()
+        .with_tag("queue")
+        .map_as::<dyn Handler>().await.unwrap();
+
+    let http_handlers = root_context.resolve_collection_where::<Box<dyn Handler>, _>(|metadata| metadata.tag("http")).await.unwrap();
+    assert_eq!(http_handlers.iter().map(|handler| handler.name()).collect::<Vec<_>>(), vec!["http1".to_string()]);
+
+    let http_handlers = root_context.resolve_collection_where::<std::sync::Arc<dyn Handler>, _>(|metadata| metadata.value("route") == Some("/orders")).await.unwrap();
+    assert_eq!(http_handlers.iter().map(|handler| handler.name()).collect::<Vec<_>>(), vec!["http2".to_string()]);
+
+    assert!(root_context.resolve_collection_where::<Box<dyn Handler>, _>(|metadata| metadata.tag("grpc")).await.unwrap().is_empty());
+
+    let handlers_info = root_context.get_collection_metadata::<Box<dyn Handler>>().await;
+    assert_eq!(handlers_info.len(), 2);
+    assert_eq!(handlers_info[0].component_type_info, TypeInfo::from_type::<HttpHandler1>());
+    assert_eq!(handlers_info[0].metadata.tags(), &["http".to_string()]);
+    assert_eq!(handlers_info[0].metadata.value("route"), Some("/users"));
+    assert_eq!(handlers_info[1].component_type_info, TypeInfo::from_type::<QueueHandler>());
+    assert!(handlers_info[1].metadata.tag("queue"));
+    assert_eq!(handlers_info[1].metadata.value("route"), None);
+
+    assert_eq!(root_context.get_collection_metadata::<Box<dyn std::any::Any + Sync + Send>>().await, Vec::<ServiceInfo>::new());
+
+    let mut deleted_components = root_context.delete_components_with_tag("http").await.unwrap();
+    deleted_components.sort_by(|l, r| l.type_name.cmp(&r.type_name));
+    assert_eq!(deleted_components, vec![TypeInfo::from_type::<HttpHandler1>(), TypeInfo::from_type::<HttpHandler2>()]);
+
+    let handlers = root_context.resolve_collection::<Box<dyn Handler>>().await.unwrap();
+    assert_eq!(handlers.iter().map(|handler| handler.name()).collect::<Vec<_>>(), vec!["queue".to_string()]);
+    assert!(root_context.resolve::<HttpHandler1>().await.is_err());
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn service_metadata_delete_not_supported() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<HttpHandler1>(LifeCycle::Transient).await.unwrap()
+        .with_tag("http")
+        .map_as::<dyn Handler>().await.unwrap();
+    root_context.register_type::<HttpHandler2>(LifeCycle::ContextDependent).await.unwrap()
+        .with_tag("http")
+        .map_as::<dyn Handler>().await.unwrap();
+
+    assert_eq!(root_context.delete_components_with_tag("http").await, Err(DeleteComponentError::NotSupportedLifeCycle {
+        component_type_info: TypeInfo::from_type::<HttpHandler2>(),
+        life_cycle: LifeCycle::ContextDependent,
+    }));
+
+    // Nothing deleted
+    assert_eq!(root_context.resolve_collection::<Box<dyn Handler>>().await.unwrap().len(), 1);
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn service_metadata_alias_and_binding() {
+    let root_context = DependencyContext::new_root();
+    root_context.register_type::<HttpHandler1>(LifeCycle::Transient).await.unwrap()
+        .with_tag("http")
+        .map_as::<dyn Handler>().await.unwrap();
+    root_context.register_type::<QueueHandler>(LifeCycle::Transient).await.unwrap()
+        .with_tag("queue")
+        .map_as::<dyn Handler>().await.unwrap();
+    root_context.register_type::<HandlerOwner>(LifeCycle::Transient).await.unwrap();
+    root_context.alias::<Box<dyn LegacyHandler>, Box<dyn Handler>>().await.unwrap();
+    root_context.when_injected_into::<HandlerOwner>()
+        .map::<dyn Handler, QueueHandler>().await;
+
+    // Alias filtered by target metadata
+    assert_eq!(root_context.resolve_collection_where::<Box<dyn LegacyHandler>, _>(|metadata| metadata.tag("http")).await.unwrap().len(), 1);
+    assert!(root_context.resolve_collection_where::<Box<dyn LegacyHandler>, _>(|metadata| metadata.tag("grpc")).await.unwrap().is_empty());
+
+    // Contextual binding leave only bound component for parent
+    let owner = root_context.resolve::<HandlerOwner>().await.unwrap();
+    assert!(owner.http_handlers.is_empty());
+    assert_eq!(owner.queue_handlers.iter().map(|handler| handler.name()).collect::<Vec<_>>(), vec!["queue".to_string()]);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn service_metadata_sync() {
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<HttpHandler1>(LifeCycle::Transient).unwrap()
+        .with_tag("http")
+        .with_metadata("route", "/users")
+        .blocking_map_as::<dyn Handler>().unwrap();
+    root_context.blocking_register_type::<HttpHandler2>(LifeCycle::Singleton).unwrap()
+        .with_tag("http")
+        .with_metadata("route", "/orders")
+        .blocking_map_as::<dyn Handler>().unwrap();
+    root_context.blocking_register_type::<QueueHandler>(LifeCycle::Transient).unwrap()
+        .with_tag("queue")
+        .blocking_map_as::<dyn Handler>().unwrap();
+
+    let http_handlers = root_context.blocking_resolve_collection_where::<Box<dyn Handler>, _>(|metadata| metadata.tag("http")).unwrap();
+    assert_eq!(http_handlers.iter().map(|handler| handler.name()).collect::<Vec<_>>(), vec!["http1".to_string()]);
+
+    let http_handlers = root_context.blocking_resolve_collection_where::<std::sync::Arc<dyn Handler>, _>(|metadata| metadata.value("route") == Some("/orders")).unwrap();
+    assert_eq!(http_handlers.iter().map(|handler| handler.name()).collect::<Vec<_>>(), vec!["http2".to_string()]);
+
+    assert!(root_context.blocking_resolve_collection_where::<Box<dyn Handler>, _>(|metadata| metadata.tag("grpc")).unwrap().is_empty());
+
+    let handlers_info = root_context.blocking_get_collection_metadata::<Box<dyn Handler>>();
+    assert_eq!(handlers_info.len(), 2);
+    assert_eq!(handlers_info[0].component_type_info, TypeInfo::from_type::<HttpHandler1>());
+    assert_eq!(handlers_info[0].metadata.tags(), &["http".to_string()]);
+    assert_eq!(handlers_info[0].metadata.value("route"), Some("/users"));
+    assert_eq!(handlers_info[1].component_type_info, TypeInfo::from_type::<QueueHandler>());
+    assert!(handlers_info[1].metadata.tag("queue"));
+    assert_eq!(handlers_info[1].metadata.value("route"), None);
+
+    assert_eq!(root_context.blocking_get_collection_metadata::<Box<dyn std::any::Any + Sync + Send>>(), Vec::<ServiceInfo>::new());
+
+    let mut deleted_components = root_context.blocking_delete_components_with_tag("http").unwrap();
+    deleted_components.sort_by(|l, r| l.type_name.cmp(&r.type_name));
+    assert_eq!(deleted_components, vec![TypeInfo::from_type::<HttpHandler1>(), TypeInfo::from_type::<HttpHandler2>()]);
+
+    let handlers = root_context.blocking_resolve_collection::<Box<dyn Handler>>().unwrap();
+    assert_eq!(handlers.iter().map(|handler| handler.name()).collect::<Vec<_>>(), vec!["queue".to_string()]);
+    assert!(root_context.blocking_resolve::<HttpHandler1>().is_err());
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn service_metadata_delete_not_supported_sync() {
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<HttpHandler1>(LifeCycle::Transient).unwrap()
+        .with_tag("http")
+        .blocking_map_as::<dyn Handler>().unwrap();
+    root_context.blocking_register_type::<HttpHandler2>(LifeCycle::ContextDependent).unwrap()
+        .with_tag("http")
+        .blocking_map_as::<dyn Handler>().unwrap();
+
+    assert_eq!(root_context.blocking_delete_components_with_tag("http"), Err(DeleteComponentError::NotSupportedLifeCycle {
+        component_type_info: TypeInfo::from_type::<HttpHandler2>(),
+        life_cycle: LifeCycle::ContextDependent,
+    }));
+
+    // Nothing deleted
+    assert_eq!(root_context.blocking_resolve_collection::<Box<dyn Handler>>().unwrap().len(), 1);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn service_metadata_alias_and_binding_sync() {
+    let root_context = DependencyContext::new_root();
+    root_context.blocking_register_type::<HttpHandler1>(LifeCycle::Transient).unwrap()
+        .with_tag("http")
+        .blocking_map_as::<dyn Handler>().unwrap();
+    root_context.blocking_register_type::<QueueHandler>(LifeCycle::Transient).unwrap()
+        .with_tag("queue")
+        .blocking_map_as::<dyn Handler>().unwrap();
+    root_context.blocking_register_type::<HandlerOwner>(LifeCycle::Transient).unwrap();
+    root_context.blocking_alias::<Box<dyn LegacyHandler>, Box<dyn Handler>>().unwrap();
+    root_context.when_injected_into::<HandlerOwner>()
+        .blocking_map::<dyn Handler, QueueHandler>();
+
+    // Alias filtered by target metadata
+    assert_eq!(root_context.blocking_resolve_collection_where::<Box<dyn LegacyHandler>, _>(|metadata| metadata.tag("http")).unwrap().len(), 1);
+    assert!(root_context.blocking_resolve_collection_where::<Box<dyn LegacyHandler>, _>(|metadata| metadata.tag("grpc")).unwrap().is_empty());
+
+    // Contextual binding leave only bound component for parent
+    let owner = root_context.blocking_resolve::<HandlerOwner>().unwrap();
+    assert!(owner.http_handlers.is_empty());
+    assert_eq!(owner.queue_handlers.iter().map(|handler| handler.name()).collect::<Vec<_>>(), vec!["queue".to_string()]);
+}
@@ -0,0 +1,117 @@
+use crate::{
+    Constructor,
+    types::BuildDependencyResult
+};
+
+#[allow(dead_code)]
+struct TransientDependency1 {
+    pub str: String,
+}
+
+// Ctor failed, because dependency not registered
+#[allow(dead_code)]
+struct TransientDependency2 {
+    pub dependency: Box<dyn GetStr>,
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for TransientDependency1 {
+    fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for TransientDependency1 {
+    async fn ctor(_: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { str: "test1".to_string() })
+    }
+}
+
+#[cfg(not(feature = "async-mode"))]
+impl Constructor for TransientDependency2 {
+    fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { dependency: ctx.resolve()? })
+    }
+}
+
+#[cfg(feature = "async-mode")]
+#[async_trait_with_sync::async_trait(Sync)]
+impl Constructor for TransientDependency2 {
+    async fn ctor(ctx: crate::DependencyContext) ->  BuildDependencyResult<Self> {
+        Ok(Self { dependency: ctx.resolve().await? })
+    }
+}
+
+// Never registered, only requested by TransientDependency2
+trait GetStr: Sync + Send {}
+
+#[cfg(not(feature = "async-mode"))]
+#[test]
+fn try_resolve() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{BuildDependencyError, TypeInfo},
+    };
+
+    let root_context = DependencyContext::new_root();
+
+    assert!(root_context.try_resolve::<Box<dyn GetStr>>().unwrap().is_none());
+
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.register_type::<TransientDependency2>(LifeCycle::Transient).unwrap();
+
+    assert_eq!(root_context.try_resolve::<TransientDependency1>().unwrap().unwrap().str, "test1".to_string());
+
+    // Not registered dependency in ctor is construction error
+    let result = root_context.try_resolve::<TransientDependency2>();
+    assert_eq!(result.err(), Some(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Box<dyn GetStr>>() }));
+}
+
+#[cfg(feature = "async-mode")]
+#[tokio::test]
+async fn try_resolve() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{BuildDependencyError, TypeInfo},
+    };
+
+    let root_context = DependencyContext::new_root();
+
+    assert!(root_context.try_resolve::<Box<dyn GetStr>>().await.unwrap().is_none());
+
+    root_context.register_type::<TransientDependency1>(LifeCycle::Transient).await.unwrap();
+    root_context.register_type::<TransientDependency2>(LifeCycle::Transient).await.unwrap();
+
+    assert_eq!(root_context.try_resolve::<TransientDependency1>().await.unwrap().unwrap().str, "test1".to_string());
+
+    // Not registered dependency in ctor is construction error
+    let result = root_context.try_resolve::<TransientDependency2>().await;
+    assert_eq!(result.err(), Some(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Box<dyn GetStr>>() }));
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn try_resolve_sync() {
+    use crate::{
+        DependencyContext,
+        LifeCycle,
+        types::{BuildDependencyError, TypeInfo},
+    };
+
+    let root_context = DependencyContext::new_root();
+
+    assert!(root_context.blocking_try_resolve::<Box<dyn GetStr>>().unwrap().is_none());
+
+    root_context.blocking_register_type::<TransientDependency1>(LifeCycle::Transient).unwrap();
+    root_context.blocking_register_type::<TransientDependency2>(LifeCycle::Transient).unwrap();
+
+    assert_eq!(root_context.blocking_try_resolve::<TransientDependency1>().unwrap().unwrap().str, "test1".to_string());
+
+    // Not registered dependency in ctor is construction error
+    let result = root_context.blocking_try_resolve::<TransientDependency2>();
+    assert_eq!(result.err(), Some(BuildDependencyError::NotFound { type_info: TypeInfo::from_type::<Box<dyn GetStr>>() }));
+}
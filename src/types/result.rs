@@ -10,8 +10,22 @@ pub type BuildDependencyResult<T> = Result<T, BuildDependencyError>;
 pub enum BuildDependencyError {
     #[error("Service not found {type_info:?}")]
     NotFound { type_info: TypeInfo },
+    #[error("Service not found {type_info:?} with name [{name}]")]
+    NamedNotFound { type_info: TypeInfo, name: String },
+    #[error("Service [{service:?}] has several components {candidates:?}. Mark one of them as default, or disable strict resolve")]
+    Ambiguous { service: TypeInfo, candidates: Vec<TypeInfo> },
     #[error("Service [{child_type_info:?}] resolve service [{parent_type_info:?}], which before resolve this service. If you app required cycled reference, remove loop-check feature")]
     CyclicReference { child_type_info: TypeInfo, parent_type_info: TypeInfo },
+    #[error("Component [{component_type_info:?}] expect args [{expected_args_type_info:?}], but resolved with args [{found_args_type_info:?}]")]
+    ArgsTypeMismatch { component_type_info: TypeInfo, expected_args_type_info: Option<TypeInfo>, found_args_type_info: Option<TypeInfo> },
+    #[error("Resolve interceptor return instance of other type, expected component [{component_type_info:?}]")]
+    InvalidInterceptedInstance { component_type_info: TypeInfo },
+    #[error("Resolution source return instance of other type, expected service [{service_type_info:?}]")]
+    InvalidSourceInstance { service_type_info: TypeInfo },
+    #[error("Decorator return service of other type, expected service [{service_type_info:?}]")]
+    InvalidDecoratedService { service_type_info: TypeInfo },
+    #[error("Component [{component_type_info:?}] argument [{argument_index}] with type [{argument_type_info:?}] not resolved [{err:?}]")]
+    ArgumentResolveError { component_type_info: TypeInfo, argument_index: usize, argument_type_info: TypeInfo, err: Box<BuildDependencyError> },
     #[error("Map component error. Idk how [{err:?}]")]
     MapComponentError { err: MapComponentError },
     #[error("Add component error. Probably you add service from ctr twice, or in other space and ctr second. Check service ctr [{err:?}]")]
@@ -25,7 +39,14 @@ impl PartialEq for BuildDependencyError {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::NotFound { type_info: l_type_info }, Self::NotFound { type_info: r_type_info }) => l_type_info == r_type_info,
+            (Self::NamedNotFound { type_info: l_type_info, name: l_name }, Self::NamedNotFound { type_info: r_type_info, name: r_name }) => l_type_info == r_type_info && l_name == r_name,
+            (Self::Ambiguous { service: l_service, candidates: l_candidates }, Self::Ambiguous { service: r_service, candidates: r_candidates }) => l_service == r_service && l_candidates == r_candidates,
             (Self::CyclicReference { child_type_info: l_child_type_info, parent_type_info: l_parent_type_info }, Self::CyclicReference { child_type_info: r_child_type_info, parent_type_info: r_parent_type_info }) => l_child_type_info == r_child_type_info && l_parent_type_info == r_parent_type_info,
+            (Self::ArgsTypeMismatch { component_type_info: l_component_type_info, expected_args_type_info: l_expected_args_type_info, found_args_type_info: l_found_args_type_info }, Self::ArgsTypeMismatch { component_type_info: r_component_type_info, expected_args_type_info: r_expected_args_type_info, found_args_type_info: r_found_args_type_info }) => l_component_type_info == r_component_type_info && l_expected_args_type_info == r_expected_args_type_info && l_found_args_type_info == r_found_args_type_info,
+            (Self::InvalidInterceptedInstance { component_type_info: l_component_type_info }, Self::InvalidInterceptedInstance { component_type_info: r_component_type_info }) => l_component_type_info == r_component_type_info,
+            (Self::InvalidSourceInstance { service_type_info: l_service_type_info }, Self::InvalidSourceInstance { service_type_info: r_service_type_info }) => l_service_type_info == r_service_type_info,
+            (Self::InvalidDecoratedService { service_type_info: l_service_type_info }, Self::InvalidDecoratedService { service_type_info: r_service_type_info }) => l_service_type_info == r_service_type_info,
+            (Self::ArgumentResolveError { component_type_info: l_component_type_info, argument_index: l_argument_index, argument_type_info: l_argument_type_info, err: l_err }, Self::ArgumentResolveError { component_type_info: r_component_type_info, argument_index: r_argument_index, argument_type_info: r_argument_type_info, err: r_err }) => l_component_type_info == r_component_type_info && l_argument_index == r_argument_index && l_argument_type_info == r_argument_type_info && l_err == r_err,
             (Self::MapComponentError { err: l_err }, Self::MapComponentError { err: r_err }) => l_err == r_err,
             (Self::AddDependencyError { err: l_err }, Self::AddDependencyError { err: r_err }) => l_err == r_err,
             (Self::Custom { err: _ }, Self::Custom { err: _ }) => true,
@@ -40,6 +61,10 @@ pub type AddDependencyResult<T> = Result<T, AddDependencyError>;
 pub enum AddDependencyError {
     #[error("Add component [{component_type_info:?}] error, component exist")]
     DependencyExist { component_type_info: TypeInfo, },   
+    #[error("Add alias [{alias_type_info:?}] error, alias exist")]
+    AliasExist { alias_type_info: TypeInfo },
+    #[error("Add component [{component_type_info:?}] error, expected life cycle [{expected_life_cycle:?}], found [{found_life_cycle:?}]")]
+    LifeCycleMismatch { component_type_info: TypeInfo, expected_life_cycle: LifeCycle, found_life_cycle: LifeCycle },
 }
 
 pub type MapComponentResult<T> = Result<T, MapComponentError>;
@@ -48,6 +73,8 @@ pub type MapComponentResult<T> = Result<T, MapComponentError>;
 pub enum MapComponentError {
     #[error("Map component [{component_type_info:?}] to service [{service_type_info:?}] error, component not found")]
     ComponentNotFound { component_type_info: TypeInfo, service_type_info: TypeInfo },
+    #[error("Map component [{component_type_info:?}] to service [{service_type_info:?}] with name [{name}] error, name already used")]
+    NameExist { component_type_info: TypeInfo, service_type_info: TypeInfo, name: String },
 }
 
 pub type DeleteComponentResult<T> = Result<T, DeleteComponentError>;
@@ -58,4 +85,84 @@ pub enum DeleteComponentError {
     ComponentNotFound { component_type_info: TypeInfo },
     #[error("Delete component [{component_type_info:?}] with life cycle [{life_cycle:?}] error, life cycle not support delete")]
     NotSupportedLifeCycle { component_type_info: TypeInfo, life_cycle: LifeCycle },
+}
+
+pub type RegisterDiscoveredResult<T> = Result<T, RegisterDiscoveredError>;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum RegisterDiscoveredError {
+    #[error("Register discovered component error [{err:?}]")]
+    AddDependencyError { err: AddDependencyError },
+    #[error("Map discovered component error [{err:?}]")]
+    MapComponentError { err: MapComponentError },
+}
+
+pub type RegisterCompositeResult<T> = Result<T, RegisterCompositeError>;
+
+#[derive(Debug, PartialEq, Error)]
+pub enum RegisterCompositeError {
+    #[error("Register composite component error [{err:?}]")]
+    AddDependencyError { err: AddDependencyError },
+    #[error("Map composite component error [{err:?}]")]
+    MapComponentError { err: MapComponentError },
+}
+
+pub type InstallModuleResult<T> = Result<T, InstallModuleError>;
+
+#[derive(Debug, Error)]
+pub enum InstallModuleError {
+    #[error("Install module [{module_type_info:?}] error, module already installed")]
+    ModuleExist { module_type_info: TypeInfo },
+    #[error("Install module [{module_type_info:?}] error, required module [{dependency_type_info:?}] not installed")]
+    DependencyNotInstalled { module_type_info: TypeInfo, dependency_type_info: TypeInfo },
+    #[error("Install module add component error [{err:?}]")]
+    AddDependencyError { err: AddDependencyError },
+    #[error("Install module map component error [{err:?}]")]
+    MapComponentError { err: MapComponentError },
+    #[error("Install module [{module_type_info:?}] error, component [{component_type_info:?}] with life cycle [{life_cycle:?}] can't be deleted on uninstall")]
+    NotSupportedLifeCycle { module_type_info: TypeInfo, component_type_info: TypeInfo, life_cycle: LifeCycle },
+    #[error("{err:?}")]
+    Custom { err: anyhow::Error }
+}
+
+// Required for anyhow::Error compare
+impl PartialEq for InstallModuleError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::ModuleExist { module_type_info: l_module_type_info }, Self::ModuleExist { module_type_info: r_module_type_info }) => l_module_type_info == r_module_type_info,
+            (Self::DependencyNotInstalled { module_type_info: l_module_type_info, dependency_type_info: l_dependency_type_info }, Self::DependencyNotInstalled { module_type_info: r_module_type_info, dependency_type_info: r_dependency_type_info }) => l_module_type_info == r_module_type_info && l_dependency_type_info == r_dependency_type_info,
+            (Self::AddDependencyError { err: l_err }, Self::AddDependencyError { err: r_err }) => l_err == r_err,
+            (Self::MapComponentError { err: l_err }, Self::MapComponentError { err: r_err }) => l_err == r_err,
+            (Self::NotSupportedLifeCycle { module_type_info: l_module_type_info, component_type_info: l_component_type_info, life_cycle: l_life_cycle }, Self::NotSupportedLifeCycle { module_type_info: r_module_type_info, component_type_info: r_component_type_info, life_cycle: r_life_cycle }) => l_module_type_info == r_module_type_info && l_component_type_info == r_component_type_info && l_life_cycle == r_life_cycle,
+            (Self::Custom { err: _ }, Self::Custom { err: _ }) => true,
+            _ => false,
+        }
+    }
+}
+
+pub type UninstallModuleResult<T> = Result<T, UninstallModuleError>;
+
+#[derive(Debug, Error)]
+pub enum UninstallModuleError {
+    #[error("Uninstall module [{module_type_info:?}] error, module not installed")]
+    ModuleNotFound { module_type_info: TypeInfo },
+    #[error("Uninstall module [{module_type_info:?}] error, module required by installed module [{dependent_module_type_info:?}]")]
+    ModuleRequired { module_type_info: TypeInfo, dependent_module_type_info: TypeInfo },
+    #[error("Uninstall module delete component error [{err:?}]")]
+    DeleteComponentError { err: DeleteComponentError },
+    #[error("{err:?}")]
+    Custom { err: anyhow::Error }
+}
+
+// Required for anyhow::Error compare
+impl PartialEq for UninstallModuleError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::ModuleNotFound { module_type_info: l_module_type_info }, Self::ModuleNotFound { module_type_info: r_module_type_info }) => l_module_type_info == r_module_type_info,
+            (Self::ModuleRequired { module_type_info: l_module_type_info, dependent_module_type_info: l_dependent_module_type_info }, Self::ModuleRequired { module_type_info: r_module_type_info, dependent_module_type_info: r_dependent_module_type_info }) => l_module_type_info == r_module_type_info && l_dependent_module_type_info == r_dependent_module_type_info,
+            (Self::DeleteComponentError { err: l_err }, Self::DeleteComponentError { err: r_err }) => l_err == r_err,
+            (Self::Custom { err: _ }, Self::Custom { err: _ }) => true,
+            _ => false,
+        }
+    }
 }
\ No newline at end of file